@@ -19,16 +19,107 @@
 //! Runtime API definition for assets.
 
 use codec::Codec;
+use frame_support::traits::tokens::{DepositConsequence, WithdrawConsequence};
+use pallet_assets::{
+	AccountBalanceSummary, AssetDetailsSummary, AssetMetadata, AssetStatistics, ExistenceReason,
+};
 use sp_std::vec::Vec;
 
+/// The most holders [`AssetsApi::holders`] returns in one page.
+pub const MAX_HOLDERS_PAGE_SIZE: u32 = 1_000;
+
 sp_api::decl_runtime_apis! {
-	pub trait AssetsApi<AccountId, AssetBalance, AssetId>
+	#[api_version(7)]
+	pub trait AssetsApi<AccountId, AssetBalance, AssetId, DepositBalance>
 	where
 		AccountId: Codec,
 		AssetBalance: Codec,
 		AssetId: Codec,
+		DepositBalance: Codec,
 	{
 		/// Returns the list of `AssetId`s and corresponding balance that an `AccountId` has.
 		fn account_balances(account: AccountId) -> Vec<(AssetId, AssetBalance)>;
+
+		/// Returns the balance of up to `limit` asset accounts of `account`, together with whether
+		/// it is frozen and how much of it can be withdrawn, starting after the asset
+		/// `start_after`. This goes through every asset, so it is heavy. The id of the last asset
+		/// returned is the cursor of the next page.
+		fn account_balances_info(
+			account: AccountId,
+			start_after: Option<AssetId>,
+			limit: u32,
+		) -> Vec<(AssetId, AccountBalanceSummary<AssetBalance>)>;
+
+		/// Returns the balances of up to `limit` holders of the asset `asset`, starting after the
+		/// account `start_after`. At most [`MAX_HOLDERS_PAGE_SIZE`] holders are returned, whatever
+		/// `limit` is. Holders are ordered by the hash of their account id, not by balance, and
+		/// the last account returned is the cursor of the next page.
+		fn holders(
+			asset: AssetId,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, AssetBalance)>;
+
+		/// Returns the reason for which `account` holds an account of asset `asset`, or `None` if
+		/// it doesn't hold one.
+		fn existence_reason(
+			asset: AssetId,
+			account: AccountId,
+		) -> Option<ExistenceReason<DepositBalance, AccountId>>;
+
+		/// Returns the consequence of transferring `amount` of the asset `asset` to `account`.
+		fn can_deposit(
+			asset: AssetId,
+			account: AccountId,
+			amount: AssetBalance,
+		) -> DepositConsequence;
+
+		/// Returns the consequence of transferring `amount` of the asset `asset` out of
+		/// `account`. If `keep_alive` is `true`, the account may not be reduced to zero.
+		fn can_withdraw(
+			asset: AssetId,
+			account: AccountId,
+			amount: AssetBalance,
+			keep_alive: bool,
+		) -> WithdrawConsequence<AssetBalance>;
+
+		/// Returns the amounts `owner` has approved for transfer, with their assets and delegates,
+		/// either for the asset `asset` or, if it is `None`, for every asset, which is heavy.
+		fn allowances(
+			owner: AccountId,
+			asset: Option<AssetId>,
+		) -> Vec<(AssetId, AccountId, AssetBalance)>;
+
+		/// Returns the amounts `delegate` has been approved to transfer, with their assets and
+		/// owners.
+		fn delegated_allowances(delegate: AccountId) -> Vec<(AssetId, AccountId, AssetBalance)>;
+
+		/// Returns the number of assets in existence, including those being destroyed.
+		fn asset_count() -> u32;
+
+		/// Returns the metadata and a summary of the details of up to `limit` assets, starting
+		/// after the asset `start_after`, or from the first asset if it is `None`. The id of the
+		/// last asset returned is the cursor of the next page.
+		fn assets(
+			start_after: Option<AssetId>,
+			limit: u32,
+		) -> Vec<(
+			AssetId,
+			AssetMetadata<DepositBalance, Vec<u8>>,
+			AssetDetailsSummary<AssetBalance>,
+		)>;
+
+		/// Returns the metadata and a summary of the details of `asset`, or `None` if it doesn't
+		/// exist.
+		fn asset(
+			asset: AssetId,
+		) -> Option<(AssetMetadata<DepositBalance, Vec<u8>>, AssetDetailsSummary<AssetBalance>)>;
+
+		/// Returns the number of transfers, mints and burns of `asset` and the volume transferred.
+		/// They are all zero if the runtime doesn't track them.
+		fn asset_stats(asset: AssetId) -> AssetStatistics<AssetBalance>;
+
+		/// Returns the assets of which `owner` is the owner, whether or not it holds any of them.
+		fn assets_owned_by(owner: AccountId) -> Vec<AssetId>;
 	}
 }
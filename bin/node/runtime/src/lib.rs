@@ -47,7 +47,7 @@ use frame_support::{
 		},
 		ConstantMultiplier, IdentityFee, Weight,
 	},
-	BoundedVec, PalletId,
+	Blake2_128Concat, BoundedVec, PalletId,
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
@@ -1569,17 +1569,39 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = Nothing;
 	type AssetDeposit = AssetDeposit;
 	type AssetAccountDeposit = ConstU128<DOLLARS>;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Runtime, Instance1>;
 	type ApprovalDeposit = ApprovalDeposit;
+	type MaxApprovalsPerOwner = ConstU32<100>;
+	type MaxApprovalsPerDelegate = ConstU32<100>;
+	type MaxOwnedAssets = ConstU32<100>;
+	type MaxHoldingsPerAccount = ConstU32<256>;
 	type StringLimit = StringLimit;
+	type MetadataPolicy = pallet_assets::ReadableMetadata;
+	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Freezer = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Freezer = pallet_assets::BenchmarkFreezer<Runtime, Instance1, 2>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ConstU32<10>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = ConstBool<true>;
+	type AccountMigration = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1596,17 +1618,36 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, AccountId>>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = Nothing;
 	type AssetDeposit = AssetDeposit;
 	type AssetAccountDeposit = ConstU128<DOLLARS>;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Runtime, Instance2>;
 	type ApprovalDeposit = ApprovalDeposit;
+	type MaxApprovalsPerOwner = ConstU32<100>;
+	type MaxApprovalsPerDelegate = ConstU32<100>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<256>;
 	type StringLimit = StringLimit;
+	type MetadataPolicy = pallet_assets::ReadableMetadata;
 	type Freezer = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ConstU32<10>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = ConstBool<false>;
+	type AccountMigration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -2137,6 +2178,13 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_assets::migration::v2::MigrateToV2<Runtime>,
+	pallet_assets::migration::v3::MigrateToV3<Runtime>,
+	pallet_assets::migration::v4::MigrateToV4<Runtime>,
+	pallet_assets::migration::v5::MigrateToV5<Runtime>,
+	pallet_assets::migration::v6::MigrateToV6<Runtime>,
+	pallet_assets::migration::v7::MigrateToV7<Runtime>,
+	pallet_assets::migration::v8::MigrateToV8<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2414,11 +2462,92 @@ impl_runtime_apis! {
 		AccountId,
 		Balance,
 		u32,
+		Balance,
 	> for Runtime
 	{
 		fn account_balances(account: AccountId) -> Vec<(u32, Balance)> {
 			Assets::account_balances(account)
 		}
+
+		fn account_balances_info(
+			account: AccountId,
+			start_after: Option<u32>,
+			limit: u32,
+		) -> Vec<(u32, pallet_assets::AccountBalanceSummary<Balance>)> {
+			Assets::account_balances_info(account, start_after, limit)
+		}
+
+		fn holders(
+			asset: u32,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, Balance)> {
+			Assets::holders(asset, start_after, limit.min(assets_api::MAX_HOLDERS_PAGE_SIZE))
+		}
+
+		fn existence_reason(
+			asset: u32,
+			account: AccountId,
+		) -> Option<pallet_assets::ExistenceReason<Balance, AccountId>> {
+			Assets::existence_reason(asset, account)
+		}
+
+		fn can_deposit(
+			asset: u32,
+			account: AccountId,
+			amount: Balance,
+		) -> frame_support::traits::tokens::DepositConsequence {
+			Assets::deposit_consequence(asset, &account, amount)
+		}
+
+		fn can_withdraw(
+			asset: u32,
+			account: AccountId,
+			amount: Balance,
+			keep_alive: bool,
+		) -> frame_support::traits::tokens::WithdrawConsequence<Balance> {
+			Assets::withdraw_consequence(asset, &account, amount, keep_alive)
+		}
+
+		fn allowances(owner: AccountId, asset: Option<u32>) -> Vec<(u32, AccountId, Balance)> {
+			Assets::allowances(owner, asset)
+		}
+
+		fn delegated_allowances(delegate: AccountId) -> Vec<(u32, AccountId, Balance)> {
+			Assets::approvals_for_delegate(&delegate)
+		}
+
+		fn asset_count() -> u32 {
+			Assets::asset_count()
+		}
+
+		fn assets(
+			start_after: Option<u32>,
+			limit: u32,
+		) -> Vec<(
+			u32,
+			pallet_assets::AssetMetadata<Balance, Vec<u8>>,
+			pallet_assets::AssetDetailsSummary<Balance>,
+		)> {
+			Assets::assets_info(start_after, limit)
+		}
+
+		fn asset(
+			asset: u32,
+		) -> Option<(
+			pallet_assets::AssetMetadata<Balance, Vec<u8>>,
+			pallet_assets::AssetDetailsSummary<Balance>,
+		)> {
+			Assets::asset_info(asset)
+		}
+
+		fn asset_stats(asset: u32) -> pallet_assets::AssetStatistics<Balance> {
+			Assets::stats(asset)
+		}
+
+		fn assets_owned_by(owner: AccountId) -> Vec<u32> {
+			Assets::assets_owned_by(&owner)
+		}
 	}
 
 	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord> for Runtime
@@ -25,7 +25,7 @@ use frame_support::{
 	instances::{Instance1, Instance2},
 	ord_parameter_types, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
-	PalletId,
+	Blake2_128Concat, PalletId,
 };
 use frame_system::{EnsureSigned, EnsureSignedBy};
 use sp_arithmetic::Permill;
@@ -93,22 +93,41 @@ impl pallet_balances::Config for Test {
 impl pallet_assets::Config<Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = frame_support::traits::Nothing;
 	type AssetDeposit = ConstU128<1>;
 	type AssetAccountDeposit = ConstU128<10>;
 	type MetadataDepositBase = ConstU128<1>;
 	type MetadataDepositPerByte = ConstU128<1>;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Test, Instance1>;
 	type ApprovalDeposit = ConstU128<1>;
+	type MaxApprovalsPerOwner = ConstU32<10>;
+	type MaxApprovalsPerDelegate = ConstU32<10>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<0>;
 	type StringLimit = ConstU32<50>;
+	type MetadataPolicy = ();
 	type Freezer = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = frame_support::traits::ConstBool<false>;
+	type AccountMigration = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
@@ -117,23 +136,42 @@ impl pallet_assets::Config<Instance1> for Test {
 impl pallet_assets::Config<Instance2> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
 	type CreateOrigin =
 		AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = frame_support::traits::Nothing;
 	type AssetDeposit = ConstU128<0>;
 	type AssetAccountDeposit = ConstU128<0>;
 	type MetadataDepositBase = ConstU128<0>;
 	type MetadataDepositPerByte = ConstU128<0>;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Test, Instance2>;
 	type ApprovalDeposit = ConstU128<0>;
+	type MaxApprovalsPerOwner = ConstU32<10>;
+	type MaxApprovalsPerDelegate = ConstU32<10>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<0>;
 	type StringLimit = ConstU32<50>;
+	type MetadataPolicy = ();
 	type Freezer = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = frame_support::traits::ConstBool<false>;
+	type AccountMigration = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
@@ -20,15 +20,16 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use codec::Decode;
 use frame_benchmarking::v1::{
 	account, benchmarks_instance_pallet, whitelist_account, whitelisted_caller, BenchmarkError,
 };
 use frame_support::{
 	dispatch::UnfilteredDispatchable,
-	traits::{EnsureOrigin, Get},
+	traits::{fungibles::MutateHold, EnsureOrigin, Get},
 };
 use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, TrailingZeroInput};
 use sp_std::prelude::*;
 
 use crate::Pallet as Assets;
@@ -103,25 +104,64 @@ fn add_sufficients<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 	swap_is_sufficient::<T, I>(&mut s);
 }
 
+/// The deposit reserved from `depositor` for an account of `who` of the default asset.
+fn account_deposit<T: Config<I>, I: 'static>(
+	who: &T::AccountId,
+	depositor: &T::AccountId,
+) -> DepositBalanceOf<T, I> {
+	let footprint = Assets::<T, I>::new_account_footprint(who, depositor);
+	T::DepositPolicy::account_deposit(&default_asset_id::<T, I>().into(), footprint)
+}
+
+/// The deposit reserved for an approval of the default asset.
+fn approval_deposit<T: Config<I>, I: 'static>() -> DepositBalanceOf<T, I> {
+	let footprint = Assets::<T, I>::approval_footprint();
+	T::DepositPolicy::approval_deposit(&default_asset_id::<T, I>().into(), footprint)
+}
+
+/// Add `n` accounts of the default asset which exist because of a deposit they placed
+/// themselves, each holding some balance.
+fn add_deposit_accounts<T: Config<I>, I: 'static>(
+	minter: T::AccountId,
+	n: u32,
+) -> DepositBalanceOf<T, I> {
+	let asset_id = default_asset_id::<T, I>();
+	// Every account pays for itself, so all of them are priced the same.
+	let deposit = account_deposit::<T, I>(&minter, &minter);
+	let origin = SystemOrigin::Signed(minter);
+	for i in 0..n {
+		let target: T::AccountId = account("deposit", i, SEED);
+		T::Currency::make_free_balance_be(&target, deposit + T::Currency::minimum_balance());
+		assert!(
+			Assets::<T, I>::touch(SystemOrigin::Signed(target.clone()).into(), asset_id).is_ok()
+		);
+		assert!(Assets::<T, I>::mint(
+			origin.clone().into(),
+			asset_id,
+			T::Lookup::unlookup(target),
+			100u32.into()
+		)
+		.is_ok());
+	}
+	deposit * n.into()
+}
+
 fn add_approvals<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 	let asset_id = default_asset_id::<T, I>();
-	T::Currency::deposit_creating(
-		&minter,
-		T::ApprovalDeposit::get() * n.into() + T::Currency::minimum_balance(),
-	);
 	let minter_lookup = T::Lookup::unlookup(minter.clone());
 	let origin = SystemOrigin::Signed(minter);
-	Assets::<T, I>::mint(origin.clone().into(), asset_id, minter_lookup, (100 * (n + 1)).into())
-		.unwrap();
-	let enough = T::Currency::minimum_balance();
+	Assets::<T, I>::mint(origin.into(), asset_id, minter_lookup, (100 * (n + 1)).into()).unwrap();
+	// Each approval is between a different owner and delegate so that neither
+	// `MaxApprovalsPerOwner` nor `MaxApprovalsPerDelegate` is hit.
+	let enough = approval_deposit::<T, I>() + T::Currency::minimum_balance();
 	for i in 0..n {
-		let target = account("approval", i, SEED);
-		T::Currency::make_free_balance_be(&target, enough);
-		let target_lookup = T::Lookup::unlookup(target);
+		let owner = account("approval", i, SEED);
+		let delegate: T::AccountId = account("delegate", i, SEED);
+		T::Currency::make_free_balance_be(&owner, enough);
 		Assets::<T, I>::approve_transfer(
-			origin.clone().into(),
+			SystemOrigin::Signed(owner).into(),
 			asset_id,
-			target_lookup,
+			T::Lookup::unlookup(delegate),
 			100u32.into(),
 		)
 		.unwrap();
@@ -146,7 +186,8 @@ benchmarks_instance_pallet! {
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 	}: _<T::RuntimeOrigin>(origin, asset_id, caller_lookup, 1u32.into())
 	verify {
-		assert_last_event::<T, I>(Event::Created { asset_id: asset_id.into(), creator: caller.clone(), owner: caller }.into());
+		assert_eq!(Assets::<T, I>::assets_owned_by(&caller), vec![asset_id.into()]);
+		assert_last_event::<T, I>(Event::Created { asset_id: asset_id.into(), creator: caller.clone(), owner: caller, deposit: T::DepositPolicy::asset_deposit(&asset_id.into()) }.into());
 	}
 
 	force_create {
@@ -155,7 +196,7 @@ benchmarks_instance_pallet! {
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
 	}: _(SystemOrigin::Root, asset_id, caller_lookup, true, 1u32.into())
 	verify {
-		assert_last_event::<T, I>(Event::ForceCreated { asset_id: asset_id.into(), owner: caller }.into());
+		assert_last_event::<T, I>(Event::ForceCreated { asset_id: asset_id.into(), owner: caller, deposit: Zero::zero() }.into());
 	}
 
 	start_destroy {
@@ -170,25 +211,32 @@ benchmarks_instance_pallet! {
 	}
 
 	destroy_accounts {
-		let c in 0 .. T::RemoveItemsLimit::get();
+		let c in 0 .. T::RemoveAccountsLimit::get();
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
-		add_sufficients::<T, I>(caller.clone(), c);
+		// Both the accounts releasing a deposit and those dropping a sufficient reference are
+		// removed, each also going through `Freezer::died`.
+		add_sufficients::<T, I>(caller.clone(), c / 2);
+		let deposit_returned = add_deposit_accounts::<T, I>(caller.clone(), c - c / 2);
 		Assets::<T, I>::freeze_asset(
 			SystemOrigin::Signed(caller.clone()).into(),
 			asset_id,
 		)?;
 		Assets::<T,I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), asset_id)?;
-	}:_(SystemOrigin::Signed(caller), asset_id)
+	}:_(SystemOrigin::Signed(caller), asset_id, c)
 	verify {
 		assert_last_event::<T, I>(Event::AccountsDestroyed {
 			asset_id: asset_id.into(),
 			accounts_destroyed: c,
 			accounts_remaining: 0,
+			deposit_returned,
 		}.into());
+		let details = Asset::<T, I>::get(&asset_id.into()).unwrap();
+		assert!(details.accounts.is_zero());
+		assert!(details.sufficients.is_zero());
 	}
 
 	destroy_approvals {
-		let a in 0 .. T::RemoveItemsLimit::get();
+		let a in 0 .. T::RemoveApprovalsLimit::get();
 		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
 		add_approvals::<T, I>(caller.clone(), a);
 		Assets::<T, I>::freeze_asset(
@@ -196,17 +244,31 @@ benchmarks_instance_pallet! {
 			asset_id,
 		)?;
 		Assets::<T,I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), asset_id)?;
-	}:_(SystemOrigin::Signed(caller), asset_id)
+	}:_(SystemOrigin::Signed(caller), asset_id, a)
 	verify {
 		assert_last_event::<T, I>(Event::ApprovalsDestroyed {
 			asset_id: asset_id.into(),
 			approvals_destroyed: a,
 			approvals_remaining: 0,
+			deposit_returned: approval_deposit::<T, I>() * a.into(),
 		}.into());
+		assert!(Asset::<T, I>::get(&asset_id.into()).unwrap().approvals.is_zero());
+		assert!(ApprovalCount::<T, I>::iter_prefix(&asset_id.into()).next().is_none());
 	}
 
 	finish_destroy {
 		let (asset_id, caller, caller_lookup) = create_default_asset::<T, I>(true);
+		// The metadata and its deposit are removed as well.
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let limit = T::StringLimit::get() as usize;
+		Assets::<T, I>::set_metadata(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			vec![b'A'; limit],
+			vec![b'A'; limit],
+			12,
+		)?;
+		let metadata_deposit = Metadata::<T, I>::get(&asset_id.into()).unwrap().deposit;
 		Assets::<T, I>::freeze_asset(
 			SystemOrigin::Signed(caller.clone()).into(),
 			asset_id,
@@ -216,8 +278,33 @@ benchmarks_instance_pallet! {
 	verify {
 		assert_last_event::<T, I>(Event::Destroyed {
 			asset_id: asset_id.into(),
+			owner: caller,
+			deposit_returned: metadata_deposit,
 		}.into()
 		);
+		assert!(!Metadata::<T, I>::contains_key(&asset_id.into()));
+	}
+
+	destroy_metadata {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let limit = T::StringLimit::get() as usize;
+		Assets::<T, I>::set_metadata(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			vec![b'A'; limit],
+			vec![b'A'; limit],
+			12,
+		)?;
+		Assets::<T, I>::freeze_asset(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+		)?;
+		Assets::<T,I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), asset_id)?;
+	}:_(SystemOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T, I>(Event::MetadataCleared { asset_id: asset_id.into() }.into());
+		assert!(!Metadata::<T, I>::contains_key(&asset_id.into()));
 	}
 
 	mint {
@@ -226,6 +313,7 @@ benchmarks_instance_pallet! {
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Issued { asset_id: asset_id.into(), owner: caller, amount }.into());
+		assert_eq!(Assets::<T, I>::stats(asset_id.into()).mints, T::TrackStats::get() as u64);
 	}
 
 	burn {
@@ -234,6 +322,7 @@ benchmarks_instance_pallet! {
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Burned { asset_id: asset_id.into(), owner: caller, balance: amount }.into());
+		assert_eq!(Assets::<T, I>::stats(asset_id.into()).burns, T::TrackStats::get() as u64);
 	}
 
 	transfer {
@@ -244,12 +333,18 @@ benchmarks_instance_pallet! {
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
+		assert_eq!(Assets::<T, I>::stats(asset_id.into()).transfers, T::TrackStats::get() as u64);
 	}
 
 	transfer_keep_alive {
 		let mint_amount = T::Balance::from(200u32);
 		let amount = T::Balance::from(100u32);
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, mint_amount);
+		let reason = T::RuntimeHoldReason::decode(&mut TrailingZeroInput::zeroes())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		// A hold makes the `Holds` lookup on the transfer path hit an existing entry.
+		<Assets<T, I> as MutateHold<T::AccountId>>::hold(asset_id.into(), &reason, &caller, 50u32.into())
+			.map_err(|_| BenchmarkError::Weightless)?;
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, amount)
@@ -313,6 +408,8 @@ benchmarks_instance_pallet! {
 		let target_lookup = T::Lookup::unlookup(target.clone());
 	}: _(SystemOrigin::Signed(caller), asset_id, target_lookup)
 	verify {
+		assert_eq!(<Assets<T, I> as fungibles::roles::Inspect<_>>::owner(asset_id.into()), Some(target.clone()));
+		assert_eq!(Assets::<T, I>::assets_owned_by(&target), vec![asset_id.into()]);
 		assert_last_event::<T, I>(Event::OwnerChanged { asset_id: asset_id.into(), owner: target }.into());
 	}
 
@@ -335,21 +432,23 @@ benchmarks_instance_pallet! {
 		let n in 0 .. T::StringLimit::get();
 		let s in 0 .. T::StringLimit::get();
 
-		let name = vec![0u8; n as usize];
-		let symbol = vec![0u8; s as usize];
+		let name = vec![b'A'; n as usize];
+		let symbol = vec![b'A'; s as usize];
 		let decimals = 12;
 
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 	}: _(SystemOrigin::Signed(caller), asset_id, name.clone(), symbol.clone(), decimals)
 	verify {
+		let name = name.try_into().unwrap();
+		let symbol = symbol.try_into().unwrap();
 		assert_last_event::<T, I>(Event::MetadataSet { asset_id: asset_id.into(), name, symbol, decimals, is_frozen: false }.into());
 	}
 
 	clear_metadata {
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
-		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let dummy = vec![b'A'; T::StringLimit::get() as usize];
 		let origin = SystemOrigin::Signed(caller.clone()).into();
 		Assets::<T, I>::set_metadata(origin, asset_id, dummy.clone(), dummy, 12)?;
 	}: _(SystemOrigin::Signed(caller), asset_id)
@@ -361,11 +460,15 @@ benchmarks_instance_pallet! {
 		let n in 0 .. T::StringLimit::get();
 		let s in 0 .. T::StringLimit::get();
 
-		let name = vec![0u8; n as usize];
-		let symbol = vec![0u8; s as usize];
+		let name = vec![b'A'; n as usize];
+		let symbol = vec![b'A'; s as usize];
 		let decimals = 12;
 
-		let (asset_id, _, _) = create_default_asset::<T, I>(true);
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		// Existing metadata with a deposit, so that the forced call must adjust it.
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let origin = SystemOrigin::Signed(caller).into();
+		Assets::<T, I>::set_metadata(origin, asset_id, vec![], vec![], decimals)?;
 
 		let origin =
 			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -378,13 +481,15 @@ benchmarks_instance_pallet! {
 		};
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
+		let name = name.try_into().unwrap();
+		let symbol = symbol.try_into().unwrap();
 		assert_last_event::<T, I>(Event::MetadataSet { asset_id: asset_id.into(), name, symbol, decimals, is_frozen: false }.into());
 	}
 
 	force_clear_metadata {
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
-		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let dummy = vec![b'A'; T::StringLimit::get() as usize];
 		let origin = SystemOrigin::Signed(caller).into();
 		Assets::<T, I>::set_metadata(origin, asset_id, dummy.clone(), dummy, 12)?;
 
@@ -398,12 +503,14 @@ benchmarks_instance_pallet! {
 
 	force_asset_status {
 		let (asset_id, caller, caller_lookup) = create_default_asset::<T, I>(true);
+		// Changing the owner moves the asset in the `OwnedAssets` index.
+		let target: T::AccountId = account("target", 0, SEED);
 
 		let origin =
 			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let call = Call::<T, I>::force_asset_status {
 			id: asset_id,
-			owner: caller_lookup.clone(),
+			owner: T::Lookup::unlookup(target.clone()),
 			issuer: caller_lookup.clone(),
 			admin: caller_lookup.clone(),
 			freezer: caller_lookup,
@@ -413,6 +520,7 @@ benchmarks_instance_pallet! {
 		};
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
+		assert_eq!(Assets::<T, I>::assets_owned_by(&target), vec![asset_id.into()]);
 		assert_last_event::<T, I>(Event::AssetStatusChanged { asset_id: asset_id.into() }.into());
 	}
 
@@ -425,7 +533,8 @@ benchmarks_instance_pallet! {
 		let amount = 100u32.into();
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, delegate_lookup, amount)
 	verify {
-		assert_last_event::<T, I>(Event::ApprovedTransfer { asset_id: asset_id.into(), source: caller, delegate, amount }.into());
+		let deposit = approval_deposit::<T, I>();
+		assert_last_event::<T, I>(Event::ApprovedTransfer { asset_id: asset_id.into(), source: caller, delegate, amount, deposit }.into());
 	}
 
 	transfer_approved {
@@ -441,10 +550,19 @@ benchmarks_instance_pallet! {
 
 		let dest: T::AccountId = account("dest", 0, SEED);
 		let dest_lookup = T::Lookup::unlookup(dest.clone());
+		// Spending the whole allowance is the heavier path, which also unreserves the deposit.
 	}: _(SystemOrigin::Signed(delegate.clone()), asset_id, owner_lookup, dest_lookup, amount)
 	verify {
 		assert!(T::Currency::reserved_balance(&owner).is_zero());
-		assert_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: owner, to: dest, amount }.into());
+		assert_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: owner.clone(), to: dest.clone(), amount }.into());
+		assert_event::<T, I>(Event::TransferredApproved {
+			asset_id: asset_id.into(),
+			owner,
+			delegate,
+			destination: dest,
+			amount,
+			remaining: Zero::zero(),
+		}.into());
 	}
 
 	cancel_approval {
@@ -458,7 +576,7 @@ benchmarks_instance_pallet! {
 		Assets::<T, I>::approve_transfer(origin, asset_id, delegate_lookup.clone(), amount)?;
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, delegate_lookup)
 	verify {
-		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: asset_id.into(), owner: caller, delegate }.into());
+		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: asset_id.into(), owner: caller, delegate, by_delegate: false }.into());
 	}
 
 	force_cancel_approval {
@@ -472,7 +590,94 @@ benchmarks_instance_pallet! {
 		Assets::<T, I>::approve_transfer(origin, asset_id, delegate_lookup.clone(), amount)?;
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, delegate_lookup)
 	verify {
-		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: asset_id.into(), owner: caller, delegate }.into());
+		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: asset_id.into(), owner: caller, delegate, by_delegate: false }.into());
+	}
+
+	reject_approval {
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, asset_id, delegate_lookup, amount)?;
+	}: _(SystemOrigin::Signed(delegate.clone()), asset_id, caller_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: asset_id.into(), owner: caller, delegate, by_delegate: true }.into());
+	}
+
+	force_cancel_approvals_of {
+		let a in 0 .. T::MaxApprovalsPerOwner::get();
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		for i in 0..a {
+			let delegate: T::AccountId = account("delegate", i, SEED);
+			let origin = SystemOrigin::Signed(caller.clone()).into();
+			Assets::<T, I>::approve_transfer(origin, asset_id, T::Lookup::unlookup(delegate), 100u32.into())?;
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, a)
+	verify {
+		assert!(ApprovalCount::<T, I>::get(&asset_id.into(), &caller).is_zero());
+		assert_eq!(Asset::<T, I>::get(&asset_id.into()).unwrap().approvals, 0);
+	}
+
+	refresh_asset_deposit {
+		let asset_id = default_asset_id::<T, I>();
+		let origin = T::CreateOrigin::try_successful_origin(&asset_id.into())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::CreateOrigin::ensure_origin(origin.clone(), &asset_id.into()).unwrap();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		Assets::<T, I>::create(origin, asset_id, caller_lookup, 1u32.into())?;
+		let limit = T::StringLimit::get() as usize;
+		Assets::<T, I>::set_metadata(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			vec![b'A'; limit],
+			vec![b'A'; limit],
+			12,
+		)?;
+		// Pretend both deposits were reserved under larger constants, so both get refunded.
+		let extra: DepositBalanceOf<T, I> = 1u32.into();
+		T::Currency::reserve(&caller, extra + extra)?;
+		Asset::<T, I>::mutate(&asset_id.into(), |maybe_details| {
+			let details = maybe_details.as_mut().unwrap();
+			details.deposit += extra;
+			details.metadata_deposit += extra;
+		});
+		Metadata::<T, I>::mutate(&asset_id.into(), |maybe_metadata| {
+			maybe_metadata.as_mut().unwrap().deposit += extra;
+		});
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id)
+	verify {
+		let details = Asset::<T, I>::get(&asset_id.into()).unwrap();
+		assert_eq!(details.deposit, T::DepositPolicy::asset_deposit(&asset_id.into()));
+	}
+
+	poke_account_deposit {
+		let (asset_id, _, _) = create_default_asset::<T, I>(false);
+		let holder: T::AccountId = account("holder", 1, SEED);
+		let holder_lookup = T::Lookup::unlookup(holder.clone());
+		T::Currency::make_free_balance_be(&holder, DepositBalanceOf::<T, I>::max_value());
+		Assets::<T, I>::touch(SystemOrigin::Signed(holder.clone()).into(), asset_id)?;
+		// Pretend the deposit was reserved under a larger constant, so it gets refunded.
+		let extra: DepositBalanceOf<T, I> = 1u32.into();
+		T::Currency::reserve(&holder, extra)?;
+		Account::<T, I>::mutate(&asset_id.into(), &holder, |maybe_account| {
+			if let Some(ExistenceReason::DepositHeld(deposit)) =
+				maybe_account.as_mut().map(|account| &mut account.reason)
+			{
+				*deposit += extra;
+			}
+		});
+	}: _(SystemOrigin::Signed(holder.clone()), asset_id, holder_lookup)
+	verify {
+		let account = Account::<T, I>::get(&asset_id.into(), &holder).unwrap();
+		assert_eq!(
+			account.reason,
+			ExistenceReason::DepositHeld(account_deposit::<T, I>(&holder, &holder)),
+		);
 	}
 
 	set_min_balance {
@@ -514,14 +719,22 @@ benchmarks_instance_pallet! {
 			SystemOrigin::Signed(new_account.clone()).into(),
 			asset_id
 		).is_ok());
-		// `touch` should reserve balance of the caller according to the `AssetAccountDeposit` amount...
-		assert_eq!(T::Currency::reserved_balance(&new_account), T::AssetAccountDeposit::get());
+		// `touch` should reserve balance of the caller according to the `DepositPolicy`...
+		assert_eq!(T::Currency::reserved_balance(&new_account), account_deposit::<T, I>(&new_account, &new_account));
 		// ...and also create an `Account` entry.
 		assert!(Account::<T, I>::contains_key(asset_id.into(), &new_account));
+		// The worst case burns the remaining balance as well.
+		assert!(Assets::<T, I>::mint(
+			SystemOrigin::Signed(asset_owner.clone()).into(),
+			asset_id,
+			T::Lookup::unlookup(new_account.clone()),
+			100u32.into(),
+		).is_ok());
 	}: _(SystemOrigin::Signed(new_account.clone()), asset_id, true)
 	verify {
 		// `refund`ing should of course repatriate the reserve
 		assert!(T::Currency::reserved_balance(&new_account).is_zero());
+		assert!(!Account::<T, I>::contains_key(asset_id.into(), &new_account));
 	}
 
 	refund_other {
@@ -535,8 +748,8 @@ benchmarks_instance_pallet! {
 			asset_id,
 			new_account_lookup.clone()
 		).is_ok());
-		// `touch` should reserve balance of the caller according to the `AssetAccountDeposit` amount...
-		assert_eq!(T::Currency::reserved_balance(&asset_owner), T::AssetAccountDeposit::get());
+		// `touch` should reserve balance of the caller according to the `DepositPolicy`...
+		assert_eq!(T::Currency::reserved_balance(&asset_owner), account_deposit::<T, I>(&new_account, &asset_owner));
 		assert!(Account::<T, I>::contains_key(asset_id.into(), &new_account));
 	}: _(SystemOrigin::Signed(asset_owner.clone()), asset_id, new_account_lookup.clone())
 	verify {
@@ -551,5 +764,114 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Blocked { asset_id: asset_id.into(), who: caller }.into());
 	}
 
+	set_max_holders {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+	}: _(SystemOrigin::Signed(caller), asset_id, Some(100))
+	verify {
+		assert_last_event::<T, I>(Event::MaxHoldersSet { asset_id: asset_id.into(), max_holders: Some(100) }.into());
+	}
+
+	set_allow_mint_when_frozen {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+	}: _(SystemOrigin::Signed(caller), asset_id, false)
+	verify {
+		assert_last_event::<T, I>(Event::AllowMintWhenFrozenSet { asset_id: asset_id.into(), allow: false }.into());
+	}
+
+	set_allow_admin_burn_when_frozen {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+	}: _(SystemOrigin::Signed(caller), asset_id, false)
+	verify {
+		assert_last_event::<T, I>(Event::AllowAdminBurnWhenFrozenSet { asset_id: asset_id.into(), allow: false }.into());
+	}
+
+	owned_assets {
+		let n in 1 .. 1_000;
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		for i in 0..n {
+			let asset_id = T::BenchmarkHelper::create_asset_id_parameter(i);
+			Assets::<T, I>::force_create(
+				SystemOrigin::Root.into(),
+				asset_id,
+				caller_lookup.clone(),
+				true,
+				1u32.into(),
+			)?;
+			Assets::<T, I>::mint(
+				SystemOrigin::Signed(caller.clone()).into(),
+				asset_id,
+				caller_lookup.clone(),
+				100u32.into(),
+			)?;
+		}
+	}: {
+		let _ = Assets::<T, I>::owned_assets(caller.clone()).count();
+	}
+	verify {
+		assert_eq!(Assets::<T, I>::owned_assets(caller).count(), n as usize);
+	}
+
+	continue_migration {
+		let n in 0 .. 1_000;
+		let (_, caller, _) = create_default_asset::<T, I>(true);
+		// The entries are in the current encoding, so they are read but not rewritten.
+		add_sufficients::<T, I>(caller.clone(), n);
+		AccountMigrationCursor::<T, I>::put(MigrationCursor::Start);
+	}: _(SystemOrigin::Signed(caller), n)
+	verify {
+		// Only visiting past the last entry ends the migration.
+		assert!(AccountMigrationCursor::<T, I>::exists());
+	}
+
+	repair_refs {
+		let n in 0 .. 1_000;
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		add_sufficients::<T, I>(caller, n);
+		// Every account has lost its reference, so every one of them is restored.
+		for who in Account::<T, I>::iter_key_prefix(&asset_id.into()) {
+			frame_system::Account::<T>::mutate(&who, |account| account.sufficients = 0);
+		}
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Call::<T, I>::repair_refs { id: asset_id, max_accounts: n };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		// Only visiting past the last entry ends the repair.
+		assert!(RefRepairs::<T, I>::contains_key(&asset_id.into()));
+		for who in Account::<T, I>::iter_key_prefix(&asset_id.into()) {
+			assert_eq!(frame_system::Pallet::<T>::sufficients(&who), 1);
+		}
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
+
+/// The same benchmarks, run against the instance of the mock identifying its assets by a
+/// `Location`.
+#[cfg(test)]
+mod location_assets {
+	use super::*;
+	use frame_support::instances::Instance2;
+
+	type LocationAssets<T> = Assets<T, Instance2>;
+
+	frame_benchmarking::v1::impl_benchmark_test_suite!(
+		LocationAssets,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	);
+
+	#[test]
+	fn benchmarks_should_work_with_location_ids() {
+		use crate::mock::{Location, Test};
+
+		crate::mock::new_test_ext().execute_with(|| {
+			// the ids come from `LocationHelper`, not from a `u32`.
+			let (asset_id, ..) = create_default_asset::<Test, Instance2>(true);
+			let location = Location { parents: 1, index: 0 };
+			assert_eq!(asset_id, location.clone().into());
+			assert!(Asset::<Test, Instance2>::contains_key(location));
+		});
+	}
+}
@@ -18,14 +18,33 @@
 //! Functions for the Assets pallet.
 
 use super::*;
-use frame_support::{defensive, traits::Get, BoundedVec};
-
+use codec::DecodeAll;
+use frame_support::{
+	defensive,
+	storage::{unhashed, with_storage_layer},
+	traits::Get,
+	weights::Weight,
+	BoundedVec,
+};
+use migration::lazy::TranslateAccount;
+use sp_runtime::traits::SaturatedConversion;
+
+/// The number of accounts of an asset whose balances are added up by `do_try_state`.
+#[cfg(any(test, feature = "try-runtime"))]
+const TRY_STATE_SUPPLY_SAMPLE: usize = 1_000;
+
+/// What becomes of an asset account whose balance fell below the minimum balance.
 #[must_use]
-pub(super) enum DeadConsequence {
-	Remove,
+pub(super) enum DeadConsequence<R> {
+	/// The account must be removed from storage, and then finished off by `account_died` with
+	/// the reason it existed for.
+	Remove(R),
+	/// The account is kept alive by a deposit.
 	Keep,
 }
 
+pub(super) type DeadConsequenceOf<T, I> = DeadConsequence<ExistenceReasonOf<T, I>>;
+
 use DeadConsequence::*;
 
 // The main implementation block for the module.
@@ -50,7 +69,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		id: T::AssetId,
 		who: impl sp_std::borrow::Borrow<T::AccountId>,
 	) -> Option<T::Balance> {
-		Account::<T, I>::get(id, who.borrow()).map(|a| a.balance)
+		Self::account(&id, who.borrow()).map(|a| a.balance)
+	}
+
+	/// Get the reason for which the asset-account of `who` for asset `id` exists, or `None` if it
+	/// doesn't exist.
+	pub fn existence_reason(
+		id: T::AssetId,
+		who: impl sp_std::borrow::Borrow<T::AccountId>,
+	) -> Option<ExistenceReasonOf<T, I>> {
+		Self::account(&id, who.borrow()).map(|a| a.reason)
 	}
 
 	/// Get the total supply of an asset `id`.
@@ -63,6 +91,256 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Asset::<T, I>::get(id).map(|x| x.supply)
 	}
 
+	/// Get the status of an asset `id`, if the asset exists.
+	pub fn asset_status(id: T::AssetId) -> Option<AssetStatus> {
+		Asset::<T, I>::get(id).map(|x| x.status)
+	}
+
+	/// Whether the asset `id` exists and is not being destroyed.
+	///
+	/// An asset which is being destroyed accepts no deposits or withdrawals, so it is reported as
+	/// unknown just like it is by `can_deposit` and `can_withdraw`.
+	pub fn asset_exists(id: T::AssetId) -> bool {
+		matches!(Self::asset_status(id), Some(AssetStatus::Live | AssetStatus::Frozen))
+	}
+
+	/// Get the consequence of depositing `amount` of the asset `id` into the account `who`, as
+	/// done by a transfer.
+	pub fn deposit_consequence(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DepositConsequence {
+		Self::can_increase(id, who, amount, false)
+	}
+
+	/// Get the consequence of withdrawing `amount` of the asset `id` from the account `who`, as
+	/// done by a transfer. If `keep_alive` is `true`, the account may not be reduced to zero.
+	pub fn withdraw_consequence(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> WithdrawConsequence<T::Balance> {
+		Self::can_decrease(id, who, amount, keep_alive)
+	}
+
+	/// Get the Owner of an asset `id`, if the asset exists.
+	pub fn owner(id: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(id).map(|x| x.owner)
+	}
+
+	/// Get the Issuer of an asset `id`, if the asset exists.
+	pub fn issuer(id: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(id).map(|x| x.issuer)
+	}
+
+	/// Get the Admin of an asset `id`, if the asset exists.
+	pub fn admin(id: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(id).map(|x| x.admin)
+	}
+
+	/// Get the Freezer of an asset `id`, if the asset exists.
+	pub fn freezer(id: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(id).map(|x| x.freezer)
+	}
+
+	/// Turn the error `e` of a call into one which only charges for a single storage read, if it
+	/// is one of those raised right after reading the asset details: `Unknown` or `AssetNotLive`.
+	///
+	/// Only the execution time is refunded. The asset details are still part of the proof, and
+	/// the benchmarked proof size is the only bound we have on them, so it is charged in full.
+	pub(super) fn with_early_exit_weight(e: DispatchError) -> DispatchErrorWithPostInfo {
+		if e == Error::<T, I>::Unknown.into() || e == Error::<T, I>::AssetNotLive.into() {
+			// The actual weight is capped by the declared one in each dimension.
+			e.with_weight(T::DbWeight::get().reads(1).set_proof_size(u64::MAX))
+		} else {
+			e.into()
+		}
+	}
+
+	/// Returns the account as which `origin` acts in the `role` of the asset `id`.
+	///
+	/// An origin passing `T::RoleOrigin` acts as the account holding `role`, and so passes the
+	/// checks made against it. Any other origin must be signed, and acts as the signer.
+	pub(super) fn ensure_role(
+		origin: T::RuntimeOrigin,
+		id: &T::AssetId,
+		role: Role,
+	) -> Result<T::AccountId, DispatchError> {
+		match T::RoleOrigin::try_origin(origin, &(id.clone(), role)) {
+			Ok(_) => {
+				let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+				Ok(match role {
+					Role::Owner => d.owner,
+					Role::Issuer => d.issuer,
+					Role::Admin => d.admin,
+					Role::Freezer => d.freezer,
+				})
+			},
+			Err(origin) => Ok(frame_system::ensure_signed(origin)?),
+		}
+	}
+
+	/// Get the `Account` entry of `who` for the asset `id`, whether it is in the current encoding
+	/// or still in the previous one of a lazy migration, see [`migration::lazy`].
+	pub(super) fn account(id: &T::AssetId, who: &T::AccountId) -> Option<AssetAccountOf<T, I>> {
+		let raw = unhashed::get_raw(&Account::<T, I>::hashed_key_for(id, who))?;
+		AssetAccountOf::<T, I>::decode_all(&mut &raw[..]).ok().or_else(|| {
+			let old =
+				<T::AccountMigration as TranslateAccount<T, I>>::Old::decode_all(&mut &raw[..])
+					.ok()?;
+			Some(T::AccountMigration::translate(id, who, old))
+		})
+	}
+
+	/// Rewrite the `Account` entry of `who` for the asset `id` in the current encoding, if it is
+	/// still in the previous one of a lazy migration. This must be done before the entry is
+	/// mutated in place.
+	pub(super) fn migrate_account(id: &T::AssetId, who: &T::AccountId) {
+		let Some(raw) = unhashed::get_raw(&Account::<T, I>::hashed_key_for(id, who)) else {
+			return
+		};
+		if AssetAccountOf::<T, I>::decode_all(&mut &raw[..]).is_ok() {
+			return
+		}
+		match <T::AccountMigration as TranslateAccount<T, I>>::Old::decode_all(&mut &raw[..]) {
+			Ok(old) =>
+				Account::<T, I>::insert(id, who, T::AccountMigration::translate(id, who, old)),
+			Err(_) => defensive!("asset account in neither the current nor the previous encoding"),
+		}
+	}
+
+	/// Visit up to `limit` more `Account` entries of the ongoing lazy migration, rewriting those
+	/// still in the previous encoding. Ends the migration once all the entries have been
+	/// visited. Returns the number of entries visited.
+	pub(super) fn do_migrate_accounts(limit: u32) -> u32 {
+		let Some(cursor) = AccountMigrationCursor::<T, I>::get() else { return 0 };
+		if limit.is_zero() {
+			return 0
+		}
+		let mut keys = match cursor {
+			MigrationCursor::Start => Account::<T, I>::iter_keys(),
+			MigrationCursor::After(key) => Account::<T, I>::iter_keys_from(key.into_inner()),
+		};
+		let mut visited = 0;
+		while visited < limit {
+			let Some((id, who)) = keys.next() else {
+				AccountMigrationCursor::<T, I>::kill();
+				Self::deposit_event(Event::AccountMigrationCompleted);
+				return visited
+			};
+			Self::migrate_account(&id, &who);
+			visited.saturating_inc();
+		}
+		match BoundedVec::try_from(keys.last_raw_key().to_vec()) {
+			Ok(key) => AccountMigrationCursor::<T, I>::put(MigrationCursor::After(key)),
+			Err(_) => defensive!("asset account key longer than `MAX_CURSOR_KEY_LEN`"),
+		}
+		visited
+	}
+
+	/// Count up to `max_accounts` more `Account` entries of the asset `id` for its ongoing repair,
+	/// restoring the missing references of these accounts on the system, and return the number
+	/// of entries visited.
+	///
+	/// Once all of them were counted, the `accounts` and `sufficients` counters of the asset are
+	/// set to the totals and the repair is over.
+	///
+	/// Emits `RefsRepaired`.
+	pub(super) fn do_repair_refs(id: T::AssetId, max_accounts: u32) -> Result<u32, DispatchError> {
+		ensure!(Asset::<T, I>::contains_key(&id), Error::<T, I>::Unknown);
+		let mut repair = RefRepairs::<T, I>::get(&id).unwrap_or_default();
+		let mut keys = match &repair.cursor {
+			MigrationCursor::Start => Account::<T, I>::iter_key_prefix(&id),
+			MigrationCursor::After(key) =>
+				Account::<T, I>::iter_key_prefix_from(&id, key.clone().into_inner()),
+		};
+		let mut visited = 0;
+		let mut restored = 0u32;
+		let mut finished = false;
+		while visited < max_accounts {
+			let Some(who) = keys.next() else {
+				finished = true;
+				break
+			};
+			visited.saturating_inc();
+			let Some(account) = Self::account(&id, &who) else {
+				defensive!("asset account in neither the current nor the previous encoding");
+				continue
+			};
+			repair.accounts.saturating_inc();
+			// Only a reference which is missing altogether is surely missing for this account.
+			match account.reason {
+				ExistenceReason::Sufficient => {
+					repair.sufficients.saturating_inc();
+					if frame_system::Pallet::<T>::sufficients(&who).is_zero() {
+						frame_system::Pallet::<T>::inc_sufficients(&who);
+						restored.saturating_inc();
+					}
+				},
+				ExistenceReason::Consumer =>
+					if frame_system::Pallet::<T>::consumers(&who).is_zero() &&
+						frame_system::Pallet::<T>::inc_consumers_without_limit(&who).is_ok()
+					{
+						restored.saturating_inc();
+					},
+				ExistenceReason::DepositHeld(_) |
+				ExistenceReason::DepositFrom(..) |
+				ExistenceReason::DepositRefunded => {},
+			}
+		}
+
+		if finished {
+			RefRepairs::<T, I>::remove(&id);
+			Asset::<T, I>::mutate(&id, |maybe_details| {
+				if let Some(details) = maybe_details {
+					details.accounts = repair.accounts;
+					details.sufficients = repair.sufficients;
+				}
+			});
+		} else {
+			match BoundedVec::try_from(keys.last_raw_key().to_vec()) {
+				Ok(key) => {
+					repair.cursor = MigrationCursor::After(key);
+					RefRepairs::<T, I>::insert(&id, &repair);
+				},
+				Err(_) => defensive!("asset account key longer than `MAX_CURSOR_KEY_LEN`"),
+			}
+		}
+		Self::deposit_event(Event::RefsRepaired {
+			asset_id: id,
+			visited,
+			restored,
+			accounts: repair.accounts,
+			sufficients: repair.sufficients,
+			finished,
+		});
+		Ok(visited)
+	}
+
+	/// Visit as many `Account` entries of the ongoing lazy migration as `weight` allows, and
+	/// return the weight used.
+	pub(super) fn migrate_accounts_within(weight: Weight) -> Weight {
+		let check = T::DbWeight::get().reads(1);
+		let base = T::WeightInfo::continue_migration(0);
+		let per_account = T::WeightInfo::continue_migration(1).saturating_sub(base);
+		let Some(available) = weight.checked_sub(&check.saturating_add(base)) else {
+			return Weight::zero()
+		};
+		let limit = (available.ref_time() / per_account.ref_time().max(1))
+			.min(available.proof_size() / per_account.proof_size().max(1))
+			.saturated_into::<u32>();
+		if limit.is_zero() {
+			return Weight::zero()
+		}
+		if !AccountMigrationCursor::<T, I>::exists() {
+			return check
+		}
+		let visited = Self::do_migrate_accounts(limit);
+		check.saturating_add(T::WeightInfo::continue_migration(visited))
+	}
+
 	pub(super) fn new_account(
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
@@ -91,28 +369,79 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ExistenceReason::Consumer
 		};
 		d.accounts = accounts;
+		HoldingCount::<T, I>::mutate(who, |count| count.saturating_inc());
 		Ok(reason)
 	}
 
+	/// Ensure that one more account may hold asset `id` without exceeding its `MaxHolders`.
+	pub(super) fn ensure_can_add_holder(
+		id: &T::AssetId,
+		d: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> DispatchResult {
+		if let Some(max_holders) = MaxHolders::<T, I>::get(id) {
+			ensure!(d.accounts < max_holders, Error::<T, I>::TooManyHolders);
+		}
+		Ok(())
+	}
+
+	/// Ensure that `who` may hold one more asset without exceeding `MaxHoldingsPerAccount`.
+	pub(super) fn ensure_can_add_holding(who: &T::AccountId) -> DispatchResult {
+		let max_holdings = T::MaxHoldingsPerAccount::get();
+		ensure!(
+			max_holdings.is_zero() || HoldingCount::<T, I>::get(who) < max_holdings,
+			Error::<T, I>::TooManyHoldings
+		);
+		Ok(())
+	}
+
+	/// Release the counters of the asset `d` and of `who` for an account of `who` which is about
+	/// to be removed, unless a deposit which was not `force`d out keeps it alive.
+	///
+	/// This only touches the storage of this pallet, so that it may be called while the asset or
+	/// the account is being mutated. The reference kept on `who` in `frame_system` is released by
+	/// `account_died`, which must be called with the returned reason once the account is gone.
 	pub(super) fn dead_account(
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
 		reason: &ExistenceReasonOf<T, I>,
 		force: bool,
-	) -> DeadConsequence {
+	) -> DeadConsequenceOf<T, I> {
 		use ExistenceReason::*;
 		match *reason {
-			Consumer => frame_system::Pallet::<T>::dec_consumers(who),
-			Sufficient => {
-				d.sufficients = d.sufficients.saturating_sub(1);
-				frame_system::Pallet::<T>::dec_sufficients(who);
-			},
-			DepositRefunded => {},
+			Consumer | DepositRefunded => {},
+			Sufficient => d.sufficients = d.sufficients.saturating_sub(1),
 			DepositHeld(_) | DepositFrom(..) if !force => return Keep,
 			DepositHeld(_) | DepositFrom(..) => {},
 		}
 		d.accounts = d.accounts.saturating_sub(1);
-		Remove
+		HoldingCount::<T, I>::mutate_exists(who, |maybe_count| {
+			*maybe_count = maybe_count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0);
+		});
+		Remove(reason.clone())
+	}
+
+	/// Finish off the account of `who` for the asset `id`, which `dead_account` released and
+	/// which was removed from storage, whichever way it died.
+	///
+	/// The `Freezer` is told first. The reference which kept `who` on-chain for this account is
+	/// released last, since it may reap `who` from `frame_system` when it was the only one.
+	///
+	/// Executing the hook here is only safe outside of a `mutate`.
+	pub(super) fn account_died(
+		id: T::AssetId,
+		who: &T::AccountId,
+		reason: &ExistenceReasonOf<T, I>,
+	) {
+		T::Freezer::died(id, who);
+		match reason {
+			ExistenceReason::Consumer => frame_system::Pallet::<T>::dec_consumers(who),
+			ExistenceReason::Sufficient => {
+				frame_system::Pallet::<T>::dec_sufficients(who);
+			},
+			ExistenceReason::DepositHeld(_) |
+			ExistenceReason::DepositFrom(..) |
+			ExistenceReason::DepositRefunded => {},
+		}
 	}
 
 	/// Returns `true` when the balance of `account` can be increased by `amount`.
@@ -132,10 +461,29 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(details) => details,
 			None => return DepositConsequence::UnknownAsset,
 		};
+		let maybe_account = Self::account(&id, who);
+		Self::increase_consequence(&details, maybe_account.as_ref(), who, amount, increase_supply)
+	}
+
+	/// Same as `can_increase`, but for already loaded asset `details` and the account of `who`, if
+	/// it exists.
+	fn increase_consequence(
+		details: &AssetDetailsOf<T, I>,
+		maybe_account: Option<&AssetAccountOf<T, I>>,
+		who: &T::AccountId,
+		amount: T::Balance,
+		increase_supply: bool,
+	) -> DepositConsequence {
+		match details.status {
+			AssetStatus::Live => {},
+			// Nothing can be credited while the asset is frozen.
+			AssetStatus::Frozen => return DepositConsequence::Blocked,
+			AssetStatus::Destroying => return DepositConsequence::UnknownAsset,
+		}
 		if increase_supply && details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
-		if let Some(account) = Account::<T, I>::get(id, who) {
+		if let Some(account) = maybe_account {
 			if account.status.is_blocked() {
 				return DepositConsequence::Blocked
 			}
@@ -158,58 +506,90 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Return the consequence of a withdraw.
+	///
+	/// When several causes prevent the withdrawal, the first of these is reported: the asset not
+	/// being live, the account being frozen or blocked, the balance being too low, the freezer
+	/// holding some of the balance and finally the account being unable to die.
 	pub(super) fn can_decrease(
 		id: T::AssetId,
 		who: &T::AccountId,
 		amount: T::Balance,
 		keep_alive: bool,
 	) -> WithdrawConsequence<T::Balance> {
-		use WithdrawConsequence::*;
 		let details = match Asset::<T, I>::get(&id) {
 			Some(details) => details,
-			None => return UnknownAsset,
+			None => return WithdrawConsequence::UnknownAsset,
 		};
-		if details.supply.checked_sub(&amount).is_none() {
-			return Underflow
-		}
-		if details.status == AssetStatus::Frozen {
-			return Frozen
+		let maybe_account = Self::account(&id, who);
+		Self::decrease_consequence(id, who, &details, maybe_account.as_ref(), amount, keep_alive)
+	}
+
+	/// Same as `can_decrease`, but for already loaded asset `details` and the account of `who`, if
+	/// it exists.
+	fn decrease_consequence(
+		id: T::AssetId,
+		who: &T::AccountId,
+		details: &AssetDetailsOf<T, I>,
+		maybe_account: Option<&AssetAccountOf<T, I>>,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> WithdrawConsequence<T::Balance> {
+		use WithdrawConsequence::*;
+		match details.status {
+			AssetStatus::Live => {},
+			AssetStatus::Frozen => return Frozen,
+			AssetStatus::Destroying => return UnknownAsset,
 		}
 		if amount.is_zero() {
 			return Success
 		}
-		let account = match Account::<T, I>::get(&id, who) {
+		let account = match maybe_account {
 			Some(a) => a,
 			None => return BalanceLow,
 		};
 		if account.status.is_frozen() {
 			return Frozen
 		}
-		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id.clone(), who) {
-				match frozen.checked_add(&details.min_balance) {
-					Some(required) if rest < required => return Frozen,
-					None => return Overflow,
-					_ => {},
-				}
+		let rest = match account.balance.checked_sub(&amount) {
+			Some(rest) => rest,
+			None => return BalanceLow,
+		};
+		if details.supply.checked_sub(&amount).is_none() {
+			return Underflow
+		}
+		if let Some(frozen) = Self::frozen_balance(id.clone(), who) {
+			// The freezer can never hold more than the account has.
+			let frozen = frozen.min(account.balance);
+			// The freezer keeps the account alive, so it may never be reduced to zero.
+			if rest < frozen {
+				return Frozen
 			}
-
 			if rest < details.min_balance {
-				if keep_alive {
-					WouldDie
-				} else {
-					ReducedToZero(rest)
-				}
+				return WouldDie
+			}
+			if rest < frozen.saturating_add(details.min_balance) {
+				return Frozen
+			}
+		}
+
+		if rest < details.min_balance {
+			if keep_alive || Holds::<T, I>::contains_key(&id, who) {
+				WouldDie
 			} else {
-				Success
+				ReducedToZero(rest)
 			}
 		} else {
-			BalanceLow
+			Success
 		}
 	}
 
 	// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
 	// of `Success`.
+	//
+	// Any amount frozen, whether by `T::Freezer` or by a named freeze, is subtracted together with
+	// the minimum balance, whatever `keep_alive` says, since this is what `can_decrease` (and so
+	// `prep_debit`) requires. Funds on hold are not part of the balance, but the account must be
+	// kept alive for as long as they exist.
 	pub(super) fn reducible_balance(
 		id: T::AssetId,
 		who: &T::AccountId,
@@ -218,16 +598,28 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
-		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
+		let account = Self::account(&id, who).ok_or(Error::<T, I>::NoAccount)?;
+		Self::reducible_balance_of(id, who, &details, &account, keep_alive)
+	}
+
+	/// Same as `reducible_balance`, but for already loaded asset `details` and `account` of `who`.
+	fn reducible_balance_of(
+		id: T::AssetId,
+		who: &T::AccountId,
+		details: &AssetDetailsOf<T, I>,
+		account: &AssetAccountOf<T, I>,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
-			// Frozen balance: account CANNOT be deleted
-			let required =
-				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
-			account.balance.saturating_sub(required)
+		let amount = if let Some(frozen) = Self::frozen_balance(id.clone(), who) {
+			// A freezer claiming more than the whole balance leaves nothing to withdraw.
+			ensure!(frozen <= account.balance, Error::<T, I>::Frozen);
+			// Frozen balance: account CANNOT be deleted, so the minimum balance stays put as well.
+			account.balance.saturating_sub(frozen.saturating_add(details.min_balance))
 		} else {
-			if keep_alive {
+			if keep_alive || Holds::<T, I>::contains_key(&id, who) {
 				// We want to keep the account around.
 				account.balance.saturating_sub(details.min_balance)
 			} else {
@@ -245,8 +637,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   less (in the case of `best_effort` being `true`) or greater by up to the minimum balance
 	///   less one.
 	/// - `keep_alive`: Require that `target` must stay alive.
-	/// - `respect_freezer`: Respect any freezes on the account or token (or not).
 	/// - `best_effort`: The debit amount may be less than `amount`.
+	/// - `ignore_freeze`: Debit even if the asset or `target` is frozen. The balance frozen by the
+	///   `T::Freezer` still can't be debited.
 	///
 	/// On success, the amount which should be debited (this will always be at least `amount` unless
 	/// `best_effort` is `true`) together with an optional value indicating the argument which must
@@ -258,11 +651,48 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		target: &T::AccountId,
 		amount: T::Balance,
 		f: DebitFlags,
+		ignore_freeze: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(Self::can_debit_status(&details, ignore_freeze), Error::<T, I>::AssetNotLive);
+
+		let account = Self::account(&id, target).ok_or(Error::<T, I>::NoAccount)?;
+		if ignore_freeze {
+			// The debit is checked as if neither the asset nor the account were frozen. Freezes
+			// of the `T::Freezer` still apply.
+			let details = AssetDetails { status: AssetStatus::Live, ..details };
+			let account = AssetAccount { status: AccountStatus::Liquid, ..account };
+			return Self::prep_debit_of(id, target, &details, &account, amount, f)
+		}
+		Self::prep_debit_of(id, target, &details, &account, amount, f)
+	}
+
+	/// Whether an asset with the given `details` may be debited at all, which a frozen one only
+	/// may if its freeze is ignored.
+	fn can_debit_status(details: &AssetDetailsOf<T, I>, ignore_freeze: bool) -> bool {
+		match details.status {
+			AssetStatus::Live => true,
+			AssetStatus::Frozen => ignore_freeze,
+			AssetStatus::Destroying => false,
+		}
+	}
+
+	/// Same as `prep_debit`, but for already loaded asset `details` and `account` of `target`.
+	fn prep_debit_of(
+		id: T::AssetId,
+		target: &T::AccountId,
+		details: &AssetDetailsOf<T, I>,
+		account: &AssetAccountOf<T, I>,
+		amount: T::Balance,
+		f: DebitFlags,
 	) -> Result<T::Balance, DispatchError> {
-		let actual = Self::reducible_balance(id.clone(), target, f.keep_alive)?.min(amount);
+		let actual =
+			Self::reducible_balance_of(id.clone(), target, details, account, f.keep_alive)?
+				.min(amount);
 		ensure!(f.best_effort || actual >= amount, Error::<T, I>::BalanceLow);
 
-		let conseq = Self::can_decrease(id, target, actual, f.keep_alive);
+		let conseq =
+			Self::decrease_consequence(id, target, details, Some(account), actual, f.keep_alive);
 		let actual = match conseq.into_result(f.keep_alive) {
 			Ok(dust) => actual.saturating_add(dust), //< guaranteed by reducible_balance
 			Err(e) => {
@@ -281,17 +711,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - `debit`: The amount by which some other account has been debited. If this is greater than
 	///   `amount`, then the `burn_dust` parameter takes effect.
 	/// - `burn_dust`: Indicates that in the case of debit being greater than amount, the additional
-	///   (dust) value should be burned, rather than credited.
+	///   (dust) value should be handed to `T::OnDust`, rather than credited.
 	///
 	/// On success, the amount which should be credited (this will always be at least `amount`)
-	/// together with an optional value indicating the value which should be burned. The latter
-	/// will always be `None` as long as `burn_dust` is `false` or `debit` is no greater than
-	/// `amount`.
+	/// together with an optional value indicating the value which should be handed to `T::OnDust`.
+	/// The latter will always be `None` as long as `burn_dust` is `false` or `debit` is no greater
+	/// than `amount`.
+	///
+	/// The asset `details` and the account of `dest`, if it exists, must already be loaded.
 	///
 	/// If no valid credit can be made then return an `Err`.
-	pub(super) fn prep_credit(
-		id: T::AssetId,
+	fn prep_credit(
 		dest: &T::AccountId,
+		details: &AssetDetailsOf<T, I>,
+		maybe_account: Option<&AssetAccountOf<T, I>>,
 		amount: T::Balance,
 		debit: T::Balance,
 		burn_dust: bool,
@@ -300,10 +733,233 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			(true, Some(dust)) => (amount, Some(dust)),
 			_ => (debit, None),
 		};
-		Self::can_increase(id, dest, credit, false).into_result()?;
+		Self::increase_consequence(details, maybe_account, dest, credit, false).into_result()?;
 		Ok((credit, maybe_burn))
 	}
 
+	/// Reserve or unreserve funds of `who` so that its deposit of `kind` for asset `id` goes from
+	/// `old` to `new`.
+	///
+	/// Emits `DepositUpdated` if the deposit changed. Unreserving never fails.
+	pub(super) fn update_deposit(
+		id: &T::AssetId,
+		who: &T::AccountId,
+		kind: DepositKind,
+		old: DepositBalanceOf<T, I>,
+		new: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		if new > old {
+			T::Currency::reserve(who, new - old)?;
+		} else if old > new {
+			T::Currency::unreserve(who, old - new);
+		} else {
+			return Ok(())
+		}
+		Self::deposit_event(Event::DepositUpdated {
+			asset_id: id.clone(),
+			who: who.clone(),
+			kind,
+			old,
+			new,
+		});
+		Ok(())
+	}
+
+	/// Recompute the asset and metadata deposits of asset `id` under the current `DepositPolicy`
+	/// and reserve the shortfall from the owner, or unreserve the excess.
+	///
+	/// Deposits which are zero are left alone.
+	pub(super) fn do_refresh_asset_deposit(id: T::AssetId) -> DispatchResult {
+		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		if !details.deposit.is_zero() {
+			let deposit = T::DepositPolicy::asset_deposit(&id);
+			Self::update_deposit(
+				&id,
+				&details.owner,
+				DepositKind::Asset,
+				details.deposit,
+				deposit,
+			)?;
+			details.deposit = deposit;
+		}
+		if let Some(mut metadata) = Metadata::<T, I>::get(&id) {
+			if !metadata.deposit.is_zero() {
+				let deposit = Self::calc_metadata_deposit(&id, &metadata.name, &metadata.symbol);
+				Self::update_deposit(
+					&id,
+					&details.owner,
+					DepositKind::Metadata,
+					metadata.deposit,
+					deposit,
+				)?;
+				metadata.deposit = deposit;
+				details.metadata_deposit = deposit;
+				Metadata::<T, I>::insert(&id, metadata);
+			}
+		}
+		Asset::<T, I>::insert(&id, details);
+		Ok(())
+	}
+
+	/// Recompute the deposit held for the account of `who` for asset `id` under the current
+	/// `DepositPolicy` and reserve the shortfall from whoever paid it, or unreserve the excess.
+	///
+	/// Fails with `NoDeposit` unless the account exists for a deposit.
+	pub(super) fn do_poke_account_deposit(id: T::AssetId, who: &T::AccountId) -> DispatchResult {
+		use AssetStatus::*;
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
+		let account = Self::account(&id, who).ok_or(Error::<T, I>::NoAccount)?;
+		ensure!(Self::reprice_account_deposit(&id, who, account)?, Error::<T, I>::NoDeposit);
+		Ok(())
+	}
+
+	/// Price the `account` of `who` for asset `id` by its current footprint under the current
+	/// `DepositPolicy`, and reserve the shortfall from whoever paid its deposit, or unreserve the
+	/// excess.
+	///
+	/// Returns whether the account exists for a deposit at all; nothing is done otherwise.
+	pub(super) fn reprice_account_deposit(
+		id: &T::AssetId,
+		who: &T::AccountId,
+		mut account: AssetAccountOf<T, I>,
+	) -> Result<bool, DispatchError> {
+		use ExistenceReason::*;
+		let deposit = T::DepositPolicy::account_deposit(id, StorageFootprint::of(&account));
+		let (payer, held) = match &mut account.reason {
+			DepositHeld(held) => (who.clone(), held),
+			DepositFrom(depositor, held) => (depositor.clone(), held),
+			Consumer | Sufficient | DepositRefunded => return Ok(false),
+		};
+		if *held != deposit {
+			Self::update_deposit(id, &payer, DepositKind::Account, *held, deposit)?;
+			*held = deposit;
+			Account::<T, I>::insert(id, who, account);
+		}
+		Ok(true)
+	}
+
+	/// The footprint of a new account of `who` created for a deposit from `depositor`, before any
+	/// `Extra` data is written to it.
+	pub(super) fn new_account_footprint(
+		who: &T::AccountId,
+		depositor: &T::AccountId,
+	) -> StorageFootprint {
+		// The deposit itself has a fixed size, so any amount measures the same.
+		let reason = if who == depositor {
+			ExistenceReason::DepositHeld(Zero::zero())
+		} else {
+			ExistenceReason::DepositFrom(depositor.clone(), Zero::zero())
+		};
+		StorageFootprint::of(&AssetAccountOf::<T, I> {
+			balance: Zero::zero(),
+			status: AccountStatus::Liquid,
+			reason,
+			extra: Default::default(),
+		})
+	}
+
+	/// The footprint of an approval, which is the same for every approval.
+	pub(super) fn approval_footprint() -> StorageFootprint {
+		StorageFootprint::of(&Approval::<T::Balance, DepositBalanceOf<T, I>>::default())
+	}
+
+	/// Move a reserved deposit of `kind` for asset `id` from `from` to `to`.
+	///
+	/// If `from` has less than `deposit` reserved (e.g. after a slash), only what is actually
+	/// reserved is moved. Returns the amount moved, which is the deposit now held by `to`.
+	///
+	/// Emits `DepositUpdated` for both accounts if the deposit is non-zero.
+	pub(super) fn transfer_deposit(
+		id: &T::AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		kind: DepositKind,
+		deposit: DepositBalanceOf<T, I>,
+	) -> Result<DepositBalanceOf<T, I>, DispatchError> {
+		if deposit.is_zero() {
+			return Ok(deposit)
+		}
+		let expected = deposit.min(T::Currency::reserved_balance(from));
+		let unmoved = T::Currency::repatriate_reserved(from, to, expected, Reserved)?;
+		let moved = expected.saturating_sub(unmoved);
+		Self::deposit_event(Event::DepositUpdated {
+			asset_id: id.clone(),
+			who: from.clone(),
+			kind,
+			old: deposit,
+			new: Zero::zero(),
+		});
+		Self::deposit_event(Event::DepositUpdated {
+			asset_id: id.clone(),
+			who: to.clone(),
+			kind,
+			old: Zero::zero(),
+			new: moved,
+		});
+		Ok(moved)
+	}
+
+	/// Ensure `owner` may own another asset under `T::MaxOwnedAssets`.
+	pub(super) fn ensure_can_own_asset(owner: &T::AccountId) -> DispatchResult {
+		let max_owned = T::MaxOwnedAssets::get();
+		ensure!(
+			max_owned.is_zero() || OwnedAssetCount::<T, I>::get(owner) < max_owned,
+			Error::<T, I>::TooManyOwnedAssets
+		);
+		Ok(())
+	}
+
+	/// Ensure that asset `id` may be minted into the account of `who`, which it may not if the
+	/// account is frozen and the owner has disallowed minting into frozen accounts.
+	pub(super) fn ensure_can_mint_into(id: &T::AssetId, who: &T::AccountId) -> DispatchResult {
+		if !AllowMintWhenFrozen::<T, I>::get(id) {
+			let frozen = Self::account(id, who).map_or(false, |a| a.status.is_frozen());
+			ensure!(!frozen, Error::<T, I>::Frozen);
+		}
+		Ok(())
+	}
+
+	/// Note that `owner` now owns asset `id`.
+	pub(super) fn inc_owned_assets(id: &T::AssetId, owner: &T::AccountId) {
+		OwnedAssets::<T, I>::insert(owner, id, ());
+		OwnedAssetCount::<T, I>::mutate(owner, |count| count.saturating_inc());
+	}
+
+	/// Note that `owner` no longer owns asset `id`.
+	pub(super) fn dec_owned_assets(id: &T::AssetId, owner: &T::AccountId) {
+		OwnedAssets::<T, I>::remove(owner, id);
+		OwnedAssetCount::<T, I>::mutate_exists(owner, |maybe_count| {
+			*maybe_count = maybe_count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	/// Note a new approval of `owner` for `delegate` to spend asset `id`.
+	pub(super) fn inc_approval_count(
+		id: &T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) {
+		ApprovalCount::<T, I>::mutate(id, owner, |count| count.saturating_inc());
+		DelegateApprovals::<T, I>::insert(delegate, (id, owner), ());
+		DelegateApprovalCount::<T, I>::mutate(delegate, |count| count.saturating_inc());
+	}
+
+	/// Note the removal of one of the approvals `owner` has for asset `id`, made to `delegate`.
+	pub(super) fn dec_approval_count(
+		id: &T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) {
+		ApprovalCount::<T, I>::mutate_exists(id, owner, |maybe_count| {
+			*maybe_count = maybe_count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0);
+		});
+		DelegateApprovals::<T, I>::remove(delegate, (id, owner));
+		DelegateApprovalCount::<T, I>::mutate_exists(delegate, |maybe_count| {
+			*maybe_count = maybe_count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
 	/// Creates an account for `who` to hold asset `id` with a zero balance and takes a deposit.
 	///
 	/// When `check_depositor` is set to true, the depositor must be either the asset's Admin or
@@ -315,15 +971,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		check_depositor: bool,
 	) -> DispatchResult {
 		ensure!(!Account::<T, I>::contains_key(&id, &who), Error::<T, I>::AlreadyExists);
-		let deposit = T::AssetAccountDeposit::get();
+		let deposit =
+			T::DepositPolicy::account_deposit(&id, Self::new_account_footprint(&who, &depositor));
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 		ensure!(
 			!check_depositor || &depositor == &details.admin || &depositor == &details.freezer,
 			Error::<T, I>::NoPermission
 		);
+		Self::ensure_can_add_holder(&id, &details)?;
 		let reason = Self::new_account(&who, &mut details, Some((&depositor, deposit)))?;
-		T::Currency::reserve(&depositor, deposit)?;
+		Self::update_deposit(&id, &depositor, DepositKind::Account, Zero::zero(), deposit)?;
 		Asset::<T, I>::insert(&id, details);
 		Account::<T, I>::insert(
 			&id,
@@ -341,30 +999,46 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Returns a deposit or a consumer reference, destroying an asset-account.
 	/// Non-zero balance accounts refunded and destroyed only if `allow_burn` is true.
-	pub(super) fn do_refund(id: T::AssetId, who: T::AccountId, allow_burn: bool) -> DispatchResult {
+	///
+	/// If `maybe_check_admin` is `Some`, it must be the asset's Admin, and the refund goes ahead
+	/// even if the account or the asset is frozen. Otherwise frozen accounts cannot be refunded.
+	pub(super) fn do_refund(
+		id: T::AssetId,
+		who: T::AccountId,
+		allow_burn: bool,
+		maybe_check_admin: Option<T::AccountId>,
+	) -> DispatchResult {
 		use AssetStatus::*;
 		use ExistenceReason::*;
-		let mut account = Account::<T, I>::get(&id, &who).ok_or(Error::<T, I>::NoDeposit)?;
+		let mut account = Self::account(&id, &who).ok_or(Error::<T, I>::NoDeposit)?;
 		ensure!(matches!(account.reason, Consumer | DepositHeld(..)), Error::<T, I>::NoDeposit);
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
+		if let Some(check_admin) = maybe_check_admin {
+			ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+		} else {
+			ensure!(details.status == Live && !account.status.is_frozen(), Error::<T, I>::Frozen);
+		}
+		ensure!(!Holds::<T, I>::contains_key(&id, &who), Error::<T, I>::ContainsHolds);
+		ensure!(!Freezes::<T, I>::contains_key(&id, &who), Error::<T, I>::ContainsFreezes);
 		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
 
 		if let Some(deposit) = account.reason.take_deposit() {
-			T::Currency::unreserve(&who, deposit);
+			Self::update_deposit(&id, &who, DepositKind::Account, deposit, Zero::zero())?;
 		}
 
-		if let Remove = Self::dead_account(&who, &mut details, &account.reason, false) {
-			Account::<T, I>::remove(&id, &who);
-		} else {
+		let Remove(reason) = Self::dead_account(&who, &mut details, &account.reason, false) else {
 			debug_assert!(false, "refund did not result in dead account?!");
 			// deposit may have been refunded, need to update `Account`
 			Account::<T, I>::insert(id, &who, account);
 			return Ok(())
-		}
+		};
+		Account::<T, I>::remove(&id, &who);
 		Asset::<T, I>::insert(&id, details);
-		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		Self::account_died(id.clone(), &who, &reason);
+		if !account.balance.is_zero() {
+			Self::dispose_dust(id, &who, account.balance);
+		}
 		Ok(())
 	}
 
@@ -374,28 +1048,28 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		who: &T::AccountId,
 		caller: &T::AccountId,
 	) -> DispatchResult {
-		let mut account = Account::<T, I>::get(&id, &who).ok_or(Error::<T, I>::NoDeposit)?;
+		let mut account = Self::account(&id, &who).ok_or(Error::<T, I>::NoDeposit)?;
 		let (depositor, deposit) =
 			account.reason.take_deposit_from().ok_or(Error::<T, I>::NoDeposit)?;
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 		ensure!(caller == &depositor || caller == &details.admin, Error::<T, I>::NoPermission);
+		ensure!(!Holds::<T, I>::contains_key(&id, who), Error::<T, I>::ContainsHolds);
+		ensure!(!Freezes::<T, I>::contains_key(&id, who), Error::<T, I>::ContainsFreezes);
 		ensure!(account.balance.is_zero(), Error::<T, I>::WouldBurn);
 
-		T::Currency::unreserve(&depositor, deposit);
+		Self::update_deposit(&id, &depositor, DepositKind::Account, deposit, Zero::zero())?;
 
-		if let Remove = Self::dead_account(&who, &mut details, &account.reason, false) {
-			Account::<T, I>::remove(&id, &who);
-		} else {
+		let Remove(reason) = Self::dead_account(&who, &mut details, &account.reason, false) else {
 			debug_assert!(false, "refund did not result in dead account?!");
 			// deposit may have been refunded, need to update `Account`
 			Account::<T, I>::insert(&id, &who, account);
 			return Ok(())
-		}
+		};
+		Account::<T, I>::remove(&id, &who);
 		Asset::<T, I>::insert(&id, details);
-		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		Self::account_died(id, &who, &reason);
 		return Ok(())
 	}
 
@@ -414,12 +1088,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if let Some(check_issuer) = maybe_check_issuer {
 				ensure!(check_issuer == details.issuer, Error::<T, I>::NoPermission);
 			}
+			Self::ensure_can_mint_into(&id, beneficiary)?;
 			debug_assert!(details.supply.checked_add(&amount).is_some(), "checked in prep; qed");
 
 			details.supply = details.supply.saturating_add(amount);
 
 			Ok(())
 		})?;
+		if !amount.is_zero() {
+			Self::note_stats(&id, |stats| stats.mints.saturating_inc());
+		}
 
 		Self::deposit_event(Event::Issued { asset_id: id, owner: beneficiary.clone(), amount });
 
@@ -444,12 +1122,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok(())
 		}
 
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
 		Self::can_increase(id.clone(), beneficiary, amount, true).into_result()?;
 		Asset::<T, I>::try_mutate(&id, |maybe_details| -> DispatchResult {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 			check(details)?;
 
+			Self::migrate_account(&id, beneficiary);
 			Account::<T, I>::try_mutate(&id, beneficiary, |maybe_account| -> DispatchResult {
 				match maybe_account {
 					Some(ref mut account) => {
@@ -459,6 +1141,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// Note this should never fail as it's already checked by
 						// `can_increase`.
 						ensure!(amount >= details.min_balance, TokenError::BelowMinimum);
+						Self::ensure_can_add_holder(&id, details)?;
 						*maybe_account = Some(AssetAccountOf::<T, I> {
 							balance: amount,
 							reason: Self::new_account(beneficiary, details, None)?,
@@ -493,20 +1176,41 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
 			Error::<T, I>::AssetNotLive
 		);
+		// Only the Admin may confiscate frozen funds, and only if the Owner lets it.
+		let ignore_freeze =
+			maybe_check_admin.is_some() && AllowAdminBurnWhenFrozen::<T, I>::get(&id);
 
-		let actual = Self::decrease_balance(id.clone(), target, amount, f, |actual, details| {
-			// Check admin rights.
-			if let Some(check_admin) = maybe_check_admin {
-				ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
-			}
+		let actual = Self::decrease_balance(
+			id.clone(),
+			target,
+			amount,
+			f,
+			ignore_freeze,
+			|actual, details| {
+				// Check admin rights.
+				if let Some(check_admin) = maybe_check_admin {
+					ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+				}
 
-			debug_assert!(details.supply >= actual, "checked in prep; qed");
-			details.supply = details.supply.saturating_sub(actual);
+				// Any dust is left in the supply for `OnDust` to deal with.
+				debug_assert!(details.supply >= actual, "checked in prep; qed");
+				details.supply = details.supply.saturating_sub(actual.min(amount));
 
-			Ok(())
-		})?;
-		Self::deposit_event(Event::Burned { asset_id: id, owner: target.clone(), balance: actual });
-		Ok(actual)
+				Ok(())
+			},
+		)?;
+		if !actual.is_zero() {
+			Self::note_stats(&id, |stats| stats.burns.saturating_inc());
+		}
+		Self::deposit_event(Event::Burned {
+			asset_id: id.clone(),
+			owner: target.clone(),
+			balance: actual,
+		});
+		if actual > amount {
+			Self::dispose_dust(id, target, actual.saturating_sub(amount));
+		}
+		Ok(actual)
 	}
 
 	/// Reduces asset `id` balance of `target` by `amount`. Flags `f` can be given to alter whether
@@ -515,6 +1219,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_burn` if you need
 	/// that. This is not intended to be used alone.
 	///
+	/// If `ignore_freeze` is `true`, the debit is made even if the asset or `target` is frozen.
+	///
 	/// Will return an error and do nothing or will decrease the amount and return the amount
 	/// reduced by.
 	pub(super) fn decrease_balance(
@@ -522,6 +1228,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		target: &T::AccountId,
 		amount: T::Balance,
 		f: DebitFlags,
+		ignore_freeze: bool,
 		check: impl FnOnce(
 			T::Balance,
 			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
@@ -532,15 +1239,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
-		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(Self::can_debit_status(&details, ignore_freeze), Error::<T, I>::AssetNotLive);
 
-		let actual = Self::prep_debit(id.clone(), target, amount, f)?;
-		let mut target_died: Option<DeadConsequence> = None;
+		let actual = Self::prep_debit(id.clone(), target, amount, f, ignore_freeze)?;
+		let mut target_died: Option<DeadConsequenceOf<T, I>> = None;
 
 		Asset::<T, I>::try_mutate(&id, |maybe_details| -> DispatchResult {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 			check(actual, details)?;
 
+			Self::migrate_account(&id, target);
 			Account::<T, I>::try_mutate(&id, target, |maybe_account| -> DispatchResult {
 				let mut account = maybe_account.take().ok_or(Error::<T, I>::NoAccount)?;
 				debug_assert!(account.balance >= actual, "checked in prep; qed");
@@ -550,7 +1258,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				if account.balance < details.min_balance {
 					debug_assert!(account.balance.is_zero(), "checked in prep; qed");
 					target_died = Some(Self::dead_account(target, details, &account.reason, false));
-					if let Some(Remove) = target_died {
+					if let Some(Remove(_)) = target_died {
 						return Ok(())
 					}
 				};
@@ -562,12 +1270,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})?;
 
 		// Execute hook outside of `mutate`.
-		if let Some(Remove) = target_died {
-			T::Freezer::died(id, target);
+		if let Some(Remove(reason)) = target_died {
+			Self::account_died(id, target, &reason);
 		}
 		Ok(actual)
 	}
 
+	/// Hand the `amount` of asset `id` removed from `who` as dust to `T::OnDust`, which burns it
+	/// or credits it elsewhere, and report which of the two happened.
+	///
+	/// The dust must still be counted in the supply of the asset.
+	pub(super) fn dispose_dust(id: T::AssetId, who: &T::AccountId, amount: T::Balance) {
+		let dust = fungibles::Dust::<T::AccountId, Self>(id.clone(), amount).into_credit();
+		let who = who.clone();
+		match T::OnDust::handle_dust(dust) {
+			Some(to) => Self::deposit_event(Event::DustCredited { asset_id: id, who, to, amount }),
+			None => Self::deposit_event(Event::DustBurned { asset_id: id, who, amount }),
+		}
+	}
+
 	/// Reduces the asset `id` balance of `source` by some `amount` and increases the balance of
 	/// `dest` by (similar) amount.
 	///
@@ -586,13 +1307,85 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> Result<T::Balance, DispatchError> {
 		let (balance, died) =
 			Self::transfer_and_die(id.clone(), source, dest, amount, maybe_need_admin, f)?;
-		if let Some(Remove) = died {
-			T::Freezer::died(id, source);
+		if !balance.is_zero() {
+			Self::note_stats(&id, |stats| {
+				stats.transfers.saturating_inc();
+				stats.volume.saturating_accrue(balance);
+			});
+		}
+		if let Some(Remove(reason)) = died {
+			Self::account_died(id, source, &reason);
 		}
 		Ok(balance)
 	}
 
-	/// Same as `do_transfer` but it does not execute the `FrozenBalance::died` hook and
+	/// Transfers up to `amount` of the asset `id` from `source` to `dest`, as `do_transfer` does
+	/// for the `transfer` calls, and returns the amount credited to `dest`.
+	///
+	/// This is the API for other pallets which need more control than `fungibles::Mutate`
+	/// gives them, and it is kept stable:
+	///
+	/// - If `maybe_admin` is `Some`, it must be the asset's Admin, and the transfer is forced:
+	///   neither `MaxHolders` nor `MaxHoldingsPerAccount` limit the accounts it creates.
+	/// - The flags `f` decide whether `source` must be kept alive, whether less than `amount` may
+	///   be moved, whether dust left in `source` goes to `T::OnDust` rather than to `dest`, and
+	///   whether `T::TransferFilter` is bypassed.
+	/// - Either the whole transfer happens or it fails with storage untouched.
+	/// - A successful transfer of a non-zero amount emits exactly one `Transferred` event, with the
+	///   amount credited, followed by a `DustBurned` or `DustCredited` event if dust was handed to
+	///   `T::OnDust`. Transferring zero does nothing and emits no event.
+	pub fn transfer_with_flags(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		maybe_admin: Option<T::AccountId>,
+		f: TransferFlags,
+	) -> Result<T::Balance, DispatchError> {
+		with_storage_layer(|| Self::do_transfer(id, source, dest, amount, maybe_admin, f))
+	}
+
+	/// Mints `amount` of the asset `id` into `beneficiary`, as `do_mint` does for the `mint`
+	/// call.
+	///
+	/// This is the API for other pallets, and it is kept stable:
+	///
+	/// - If `maybe_issuer` is `Some`, it must be the asset's Issuer. Minting is never limited by
+	///   `MaxHoldingsPerAccount`.
+	/// - Either exactly `amount` is minted or it fails with storage untouched.
+	/// - A successful mint emits exactly one `Issued` event, even for a zero `amount`.
+	pub fn mint_to(
+		id: T::AssetId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+		maybe_issuer: Option<T::AccountId>,
+	) -> DispatchResult {
+		with_storage_layer(|| Self::do_mint(id, beneficiary, amount, maybe_issuer))
+	}
+
+	/// Burns up to `amount` of the asset `id` from `target`, as `do_burn` does for the `burn`
+	/// call, and returns the amount removed from `target`.
+	///
+	/// This is the API for other pallets, and it is kept stable:
+	///
+	/// - If `maybe_admin` is `Some`, it must be the asset's Admin.
+	/// - The flags `f` decide whether `target` must be kept alive and whether less than `amount`
+	///   may be burned. If `target` dies, the amount returned includes its dust.
+	/// - Either the burn happens or it fails with storage untouched.
+	/// - A successful burn emits exactly one `Burned` event with the amount returned, even for a
+	///   zero `amount`, followed by a `DustBurned` or `DustCredited` event if dust was handed to
+	///   `T::OnDust`.
+	pub fn burn_with_flags(
+		id: T::AssetId,
+		target: &T::AccountId,
+		amount: T::Balance,
+		maybe_admin: Option<T::AccountId>,
+		f: DebitFlags,
+	) -> Result<T::Balance, DispatchError> {
+		with_storage_layer(|| Self::do_burn(id, target, amount, maybe_admin, f))
+	}
+
+	/// Same as `do_transfer` but it does not finish off a dead `source` with `account_died` and
 	/// instead returns whether and how the `source` account died in this operation.
 	fn transfer_and_die(
 		id: T::AssetId,
@@ -601,50 +1394,62 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 		maybe_need_admin: Option<T::AccountId>,
 		f: TransferFlags,
-	) -> Result<(T::Balance, Option<DeadConsequence>), DispatchError> {
+	) -> Result<(T::Balance, Option<DeadConsequenceOf<T, I>>), DispatchError> {
 		// Early exit if no-op.
 		if amount.is_zero() {
 			return Ok((amount, None))
 		}
-		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
-		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
-
-		// Figure out the debit and credit, together with side-effects.
-		let debit = Self::prep_debit(id.clone(), source, amount, f.into())?;
-		let (credit, maybe_burn) = Self::prep_credit(id.clone(), dest, amount, debit, f.burn_dust)?;
-
-		let mut source_account =
-			Account::<T, I>::get(&id, &source).ok_or(Error::<T, I>::NoAccount)?;
-		let mut source_died: Option<DeadConsequence> = None;
-
-		Asset::<T, I>::try_mutate(&id, |maybe_details| -> DispatchResult {
-			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-
-			// Check admin rights.
-			if let Some(need_admin) = maybe_need_admin {
-				ensure!(need_admin == details.admin, Error::<T, I>::NoPermission);
-			}
+		if !f.bypass_filter {
+			T::TransferFilter::check_transfer(&id, source, dest, amount)?;
+		}
+		let mut source_died: Option<DeadConsequenceOf<T, I>> = None;
+		let is_forced = maybe_need_admin.is_some();
 
-			// Skip if source == dest
-			if source == dest {
-				return Ok(())
-			}
+		// The asset details and both accounts are read and written at most once each.
+		let (credit, maybe_burn) = Asset::<T, I>::try_mutate(
+			&id,
+			|maybe_details| -> Result<(T::Balance, Option<T::Balance>), DispatchError> {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+				// Figure out the debit and credit, together with side-effects.
+				let mut source_account =
+					Self::account(&id, source).ok_or(Error::<T, I>::NoAccount)?;
+				let debit = Self::prep_debit_of(
+					id.clone(),
+					source,
+					details,
+					&source_account,
+					amount,
+					f.into(),
+				)?;
+				// The balance is validated above, but an account never transfers to itself.
+				ensure!(source != dest, Error::<T, I>::SelfTransfer);
+				let maybe_dest_account = Self::account(&id, dest);
+				let (credit, maybe_burn) = Self::prep_credit(
+					dest,
+					details,
+					maybe_dest_account.as_ref(),
+					amount,
+					debit,
+					f.burn_dust,
+				)?;
+
+				// Check admin rights.
+				if let Some(need_admin) = maybe_need_admin {
+					ensure!(need_admin == details.admin, Error::<T, I>::NoPermission);
+				}
 
-			// Burn any dust if needed.
-			if let Some(burn) = maybe_burn {
-				// Debit dust from supply; this will not saturate since it's already checked in
+				// Debit balance from source; this will not saturate since it's already checked in
 				// prep.
-				debug_assert!(details.supply >= burn, "checked in prep; qed");
-				details.supply = details.supply.saturating_sub(burn);
-			}
+				debug_assert!(source_account.balance >= debit, "checked in prep; qed");
+				source_account.balance = source_account.balance.saturating_sub(debit);
 
-			// Debit balance from source; this will not saturate since it's already checked in prep.
-			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
-			source_account.balance = source_account.balance.saturating_sub(debit);
+				// A new holder may replace the source if it dies; the admin is never limited.
+				let check_holders = !is_forced && source_account.balance >= details.min_balance;
 
-			Account::<T, I>::try_mutate(&id, &dest, |maybe_account| -> DispatchResult {
-				match maybe_account {
-					Some(ref mut account) => {
+				let dest_account = match maybe_dest_account {
+					Some(mut account) => {
 						// Calculate new balance; this will not saturate since it's already checked
 						// in prep.
 						debug_assert!(
@@ -652,39 +1457,50 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							"checked in prep; qed"
 						);
 						account.balance.saturating_accrue(credit);
+						account
 					},
-					maybe_account @ None => {
-						*maybe_account = Some(AssetAccountOf::<T, I> {
+					None => {
+						if check_holders {
+							Self::ensure_can_add_holder(&id, details)?;
+						}
+						if !is_forced {
+							Self::ensure_can_add_holding(dest)?;
+						}
+						AssetAccountOf::<T, I> {
 							balance: credit,
 							status: AccountStatus::Liquid,
 							reason: Self::new_account(dest, details, None)?,
 							extra: T::Extra::default(),
-						});
+						}
 					},
+				};
+				Account::<T, I>::insert(&id, dest, dest_account);
+
+				// Remove source account if it's now dead.
+				if source_account.balance < details.min_balance {
+					debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
+					source_died =
+						Some(Self::dead_account(source, details, &source_account.reason, false));
+					if let Some(Remove(_)) = source_died {
+						Account::<T, I>::remove(&id, source);
+						return Ok((credit, maybe_burn))
+					}
 				}
-				Ok(())
-			})?;
-
-			// Remove source account if it's now dead.
-			if source_account.balance < details.min_balance {
-				debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
-				source_died =
-					Some(Self::dead_account(source, details, &source_account.reason, false));
-				if let Some(Remove) = source_died {
-					Account::<T, I>::remove(&id, &source);
-					return Ok(())
-				}
-			}
-			Account::<T, I>::insert(&id, &source, &source_account);
-			Ok(())
-		})?;
+				Account::<T, I>::insert(&id, source, &source_account);
+				Ok((credit, maybe_burn))
+			},
+		)?;
 
 		Self::deposit_event(Event::Transferred {
-			asset_id: id,
+			asset_id: id.clone(),
 			from: source.clone(),
 			to: dest.clone(),
 			amount: credit,
 		});
+		// Any dust was left in the supply for `OnDust` to deal with.
+		if let Some(amount) = maybe_burn.filter(|burn| !burn.is_zero()) {
+			Self::dispose_dust(id, source, amount);
+		}
 		Ok((credit, source_died))
 	}
 
@@ -720,10 +1536,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				sufficients: 0,
 				approvals: 0,
 				status: AssetStatus::Live,
+				metadata_deposit: Zero::zero(),
 			},
 		);
+		AssetCount::<T, I>::mutate(|count| count.saturating_inc());
+		Self::inc_owned_assets(&id, &owner);
 		ensure!(T::CallbackHandle::created(&id, &owner).is_ok(), Error::<T, I>::CallbackFailed);
-		Self::deposit_event(Event::ForceCreated { asset_id: id, owner: owner.clone() });
+		Self::deposit_event(Event::ForceCreated {
+			asset_id: id,
+			owner: owner.clone(),
+			deposit: Zero::zero(),
+		});
 		Ok(())
 	}
 
@@ -738,6 +1561,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if let Some(check_owner) = maybe_check_owner {
 				ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
 			}
+			ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 			details.status = AssetStatus::Destroying;
 
 			Self::deposit_event(Event::DestructionStarted { asset_id: id });
@@ -745,7 +1569,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
-	/// Destroy accounts associated with a given asset up to the max (T::RemoveItemsLimit).
+	/// Destroy accounts associated with a given asset up to the max (T::RemoveAccountsLimit).
 	///
 	/// Each call emits the `Event::DestroyedAccounts` event.
 	/// Returns the number of destroyed accounts.
@@ -753,49 +1577,63 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		id: T::AssetId,
 		max_items: u32,
 	) -> Result<u32, DispatchError> {
-		let mut dead_accounts: Vec<T::AccountId> = vec![];
+		let mut dead_accounts: Vec<(T::AccountId, ExistenceReasonOf<T, I>)> = vec![];
 		let mut remaining_accounts = 0;
+		let mut deposit_returned: DepositBalanceOf<T, I> = Zero::zero();
 		let _ =
 			Asset::<T, I>::try_mutate_exists(&id, |maybe_details| -> Result<(), DispatchError> {
 				let mut details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				// Should only destroy accounts while the asset is in a destroying state
 				ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
-				for (i, (who, mut v)) in Account::<T, I>::iter_prefix(&id).enumerate() {
-					// unreserve the existence deposit if any
-					if let Some((depositor, deposit)) = v.reason.take_deposit_from() {
-						T::Currency::unreserve(&depositor, deposit);
-					} else if let Some(deposit) = v.reason.take_deposit() {
-						T::Currency::unreserve(&who, deposit);
+				for who in Account::<T, I>::iter_key_prefix(&id).take(max_items as usize) {
+					let Some(mut v) = Self::account(&id, &who) else {
+						defensive!(
+							"asset account in neither the current nor the previous encoding"
+						);
+						continue
+					};
+					// unreserve the existence deposit if any, back to whoever paid it
+					if let Some((depositor, deposit)) = v.reason.take_deposit_and_payer(&who) {
+						Self::update_deposit(
+							&id,
+							&depositor,
+							DepositKind::Account,
+							deposit,
+							Zero::zero(),
+						)?;
+						deposit_returned.saturating_accrue(deposit);
 					}
-					if let Remove = Self::dead_account(&who, &mut details, &v.reason, false) {
+					if let Remove(reason) = Self::dead_account(&who, &mut details, &v.reason, false)
+					{
 						Account::<T, I>::remove(&id, &who);
-						dead_accounts.push(who);
+						// any held funds are destroyed together with the asset.
+						Holds::<T, I>::remove(&id, &who);
+						Freezes::<T, I>::remove(&id, &who);
+						dead_accounts.push((who, reason));
 					} else {
 						// deposit may have been released, need to update `Account`
 						Account::<T, I>::insert(&id, &who, v);
 						defensive!("destroy did not result in dead account?!");
 					}
-					if i + 1 >= (max_items as usize) {
-						break
-					}
 				}
 				remaining_accounts = details.accounts;
 				Ok(())
 			})?;
 
-		for who in &dead_accounts {
-			T::Freezer::died(id.clone(), &who);
+		for (who, reason) in &dead_accounts {
+			Self::account_died(id.clone(), who, reason);
 		}
 
 		Self::deposit_event(Event::AccountsDestroyed {
 			asset_id: id,
 			accounts_destroyed: dead_accounts.len() as u32,
 			accounts_remaining: remaining_accounts as u32,
+			deposit_returned,
 		});
 		Ok(dead_accounts.len() as u32)
 	}
 
-	/// Destroy approvals associated with a given asset up to the max (T::RemoveItemsLimit).
+	/// Destroy approvals associated with a given asset up to the max (T::RemoveApprovalsLimit).
 	///
 	/// Each call emits the `Event::DestroyedApprovals` event
 	/// Returns the number of destroyed approvals.
@@ -804,6 +1642,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		max_items: u32,
 	) -> Result<u32, DispatchError> {
 		let mut removed_approvals = 0;
+		let mut deposit_returned: DepositBalanceOf<T, I> = Zero::zero();
 		let _ = Asset::<T, I>::try_mutate_exists(
 			id.clone(),
 			|maybe_details| -> Result<(), DispatchError> {
@@ -812,18 +1651,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				// Should only destroy accounts while the asset is in a destroying state.
 				ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
 
-				for ((owner, _), approval) in Approvals::<T, I>::drain_prefix((id.clone(),)) {
-					T::Currency::unreserve(&owner, approval.deposit);
+				// `take` stops before draining more than `max_items` entries.
+				for ((owner, delegate), approval) in
+					Approvals::<T, I>::drain_prefix((id.clone(),)).take(max_items as usize)
+				{
+					Self::update_deposit(
+						&id,
+						&owner,
+						DepositKind::Approval,
+						approval.deposit,
+						Zero::zero(),
+					)?;
+					Self::dec_approval_count(&id, &owner, &delegate);
+					deposit_returned.saturating_accrue(approval.deposit);
 					removed_approvals = removed_approvals.saturating_add(1);
 					details.approvals = details.approvals.saturating_sub(1);
-					if removed_approvals >= max_items {
-						break
-					}
 				}
 				Self::deposit_event(Event::ApprovalsDestroyed {
 					asset_id: id,
 					approvals_destroyed: removed_approvals as u32,
 					approvals_remaining: details.approvals as u32,
+					deposit_returned,
 				});
 				Ok(())
 			},
@@ -831,6 +1679,95 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(removed_approvals)
 	}
 
+	/// Clear the metadata of the asset `id` while it is being destroyed, returning its deposit to
+	/// the owner right away rather than once the asset is gone.
+	///
+	/// Emits `MetadataCleared`.
+	pub(super) fn do_destroy_metadata(id: T::AssetId) -> DispatchResult {
+		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+		let metadata = Metadata::<T, I>::take(&id).ok_or(Error::<T, I>::NoMetadata)?;
+		Self::update_deposit(
+			&id,
+			&details.owner,
+			DepositKind::Metadata,
+			metadata.deposit,
+			Zero::zero(),
+		)?;
+		details.metadata_deposit = Zero::zero();
+		Asset::<T, I>::insert(&id, details);
+		Self::deposit_event(Event::MetadataCleared { asset_id: id });
+		Ok(())
+	}
+
+	/// Cancel the approval `owner` made for `delegate` to spend asset `id`, returning its deposit
+	/// to `owner`, whether `owner` cancelled it or the delegate renounced it.
+	///
+	/// Emits `ApprovalCancelled`.
+	pub(super) fn do_cancel_approval(
+		id: T::AssetId,
+		owner: T::AccountId,
+		delegate: T::AccountId,
+		by_delegate: bool,
+	) -> DispatchResult {
+		let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+		let approval = Approvals::<T, I>::take((id.clone(), &owner, &delegate))
+			.ok_or(Error::<T, I>::Unknown)?;
+		Self::update_deposit(&id, &owner, DepositKind::Approval, approval.deposit, Zero::zero())?;
+		Self::dec_approval_count(&id, &owner, &delegate);
+
+		d.approvals.saturating_dec();
+		Asset::<T, I>::insert(id.clone(), d);
+
+		Self::deposit_event(Event::ApprovalCancelled {
+			asset_id: id,
+			owner,
+			delegate,
+			by_delegate,
+		});
+		Ok(())
+	}
+
+	/// Cancel up to `max_items` of the approvals `owner` made for the asset `id`, returning their
+	/// deposits to `owner`, and return how many were cancelled.
+	///
+	/// Emits `ApprovalCancelled` for each of them.
+	pub(super) fn do_force_cancel_approvals_of(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+		let mut cancelled = 0u32;
+		// `take` stops before draining more than `max_items` entries.
+		for (delegate, approval) in
+			Approvals::<T, I>::drain_prefix((id.clone(), owner.clone())).take(max_items as usize)
+		{
+			Self::update_deposit(
+				&id,
+				owner,
+				DepositKind::Approval,
+				approval.deposit,
+				Zero::zero(),
+			)?;
+			Self::dec_approval_count(&id, owner, &delegate);
+			d.approvals.saturating_dec();
+			cancelled.saturating_inc();
+			Self::deposit_event(Event::ApprovalCancelled {
+				asset_id: id.clone(),
+				owner: owner.clone(),
+				delegate,
+				by_delegate: false,
+			});
+		}
+		Asset::<T, I>::insert(&id, d);
+		Ok(cancelled)
+	}
+
 	/// Complete destroying an asset and unreserve the deposit.
 	///
 	/// On success, the `Event::Destroyed` event is emitted.
@@ -838,25 +1775,48 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Asset::<T, I>::try_mutate_exists(id.clone(), |maybe_details| -> Result<(), DispatchError> {
 			let details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
 			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
-			ensure!(details.accounts == 0, Error::<T, I>::InUse);
-			ensure!(details.approvals == 0, Error::<T, I>::InUse);
+			ensure!(details.accounts == 0, Error::<T, I>::AccountsRemaining);
+			ensure!(details.approvals == 0, Error::<T, I>::ApprovalsRemaining);
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
-			let metadata = Metadata::<T, I>::take(&id);
-			T::Currency::unreserve(
+			// The metadata and its deposit may be gone already, through `destroy_metadata`.
+			Metadata::<T, I>::remove(&id);
+			MaxHolders::<T, I>::remove(&id);
+			AllowMintWhenFrozen::<T, I>::remove(&id);
+			AllowAdminBurnWhenFrozen::<T, I>::remove(&id);
+			AssetStats::<T, I>::remove(&id);
+			RefRepairs::<T, I>::remove(&id);
+			AssetCount::<T, I>::mutate(|count| count.saturating_dec());
+			Self::dec_owned_assets(&id, &details.owner);
+			Self::update_deposit(
+				&id,
 				&details.owner,
-				details.deposit.saturating_add(metadata.deposit),
-			);
-			Self::deposit_event(Event::Destroyed { asset_id: id });
+				DepositKind::Asset,
+				details.deposit,
+				Zero::zero(),
+			)?;
+			Self::update_deposit(
+				&id,
+				&details.owner,
+				DepositKind::Metadata,
+				details.metadata_deposit,
+				Zero::zero(),
+			)?;
+			Self::deposit_event(Event::Destroyed {
+				asset_id: id,
+				owner: details.owner,
+				deposit_returned: details.deposit.saturating_add(details.metadata_deposit),
+			});
 
 			Ok(())
 		})
 	}
 
 	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
-	/// while reserving `T::ApprovalDeposit` from owner
+	/// while reserving the approval deposit of the `DepositPolicy` from owner
 	///
-	/// If an approval already exists, the new amount is added to such existing approval
+	/// If an approval already exists, the new amount is added to such existing approval, and only
+	/// a shortfall of its deposit is reserved.
 	pub(super) fn do_approve_transfer(
 		id: T::AssetId,
 		owner: &T::AccountId,
@@ -865,6 +1825,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		// A frozen owner must not be able to hand out spending rights it cannot use itself.
+		ensure!(
+			!Self::account(&id, owner).map_or(false, |a| a.status.is_frozen()),
+			Error::<T, I>::Frozen
+		);
+		let mut deposit = Zero::zero();
+		let mut created = false;
 		Approvals::<T, I>::try_mutate(
 			(id.clone(), &owner, &delegate),
 			|maybe_approved| -> DispatchResult {
@@ -873,13 +1840,31 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					Some(a) => a,
 					// a new approval is created
 					None => {
+						ensure!(
+							ApprovalCount::<T, I>::get(&id, owner) < T::MaxApprovalsPerOwner::get(),
+							Error::<T, I>::TooManyApprovals
+						);
+						ensure!(
+							DelegateApprovalCount::<T, I>::get(delegate) <
+								T::MaxApprovalsPerDelegate::get(),
+							Error::<T, I>::TooManyDelegateApprovals
+						);
 						d.approvals.saturating_inc();
+						created = true;
 						Default::default()
 					},
 				};
-				let deposit_required = T::ApprovalDeposit::get();
+				let deposit_required =
+					T::DepositPolicy::approval_deposit(&id, Self::approval_footprint());
 				if approved.deposit < deposit_required {
-					T::Currency::reserve(owner, deposit_required - approved.deposit)?;
+					deposit = deposit_required - approved.deposit;
+					Self::update_deposit(
+						&id,
+						owner,
+						DepositKind::Approval,
+						approved.deposit,
+						deposit_required,
+					)?;
 					approved.deposit = deposit_required;
 				}
 				approved.amount = approved.amount.saturating_add(amount);
@@ -887,12 +1872,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Ok(())
 			},
 		)?;
+		if created {
+			Self::inc_approval_count(&id, owner, delegate);
+		}
 		Asset::<T, I>::insert(&id, d);
 		Self::deposit_event(Event::ApprovedTransfer {
 			asset_id: id,
 			source: owner.clone(),
 			delegate: delegate.clone(),
 			amount,
+			deposit,
 		});
 
 		Ok(())
@@ -912,7 +1901,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		destination: &T::AccountId,
 		amount: T::Balance,
 	) -> DispatchResult {
-		let mut owner_died: Option<DeadConsequence> = None;
+		let mut owner_died: Option<DeadConsequenceOf<T, I>> = None;
+		let mut remaining = Zero::zero();
 
 		let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
@@ -921,21 +1911,35 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			(id.clone(), &owner, delegate),
 			|maybe_approved| -> DispatchResult {
 				let mut approved = maybe_approved.take().ok_or(Error::<T, I>::Unapproved)?;
-				let remaining =
+				remaining =
 					approved.amount.checked_sub(&amount).ok_or(Error::<T, I>::Unapproved)?;
 
-				let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+				let f = TransferFlags {
+					keep_alive: false,
+					best_effort: false,
+					burn_dust: false,
+					bypass_filter: false,
+				};
 				owner_died =
 					Self::transfer_and_die(id.clone(), owner, destination, amount, None, f)?.1;
 
 				if remaining.is_zero() {
-					T::Currency::unreserve(owner, approved.deposit);
+					Self::update_deposit(
+						&id,
+						owner,
+						DepositKind::Approval,
+						approved.deposit,
+						Zero::zero(),
+					)?;
+					Self::dec_approval_count(&id, owner, delegate);
 					Asset::<T, I>::mutate(id.clone(), |maybe_details| {
 						if let Some(details) = maybe_details {
 							details.approvals.saturating_dec();
 						}
 					});
 				} else {
+					// The deposit pays for the approval in storage, so it is kept in full until the
+					// approval is gone.
 					approved.amount = remaining;
 					*maybe_approved = Some(approved);
 				}
@@ -944,9 +1948,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)?;
 
 		// Execute hook outside of `mutate`.
-		if let Some(Remove) = owner_died {
-			T::Freezer::died(id, owner);
+		if let Some(Remove(reason)) = owner_died {
+			Self::account_died(id.clone(), owner, &reason);
 		}
+		Self::deposit_event(Event::TransferredApproved {
+			asset_id: id,
+			owner: owner.clone(),
+			delegate: delegate.clone(),
+			destination: destination.clone(),
+			amount,
+			remaining,
+		});
 		Ok(())
 	}
 
@@ -958,12 +1970,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		symbol: Vec<u8>,
 		decimals: u8,
 	) -> DispatchResult {
-		let bounded_name: BoundedVec<u8, T::StringLimit> =
-			name.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-		let bounded_symbol: BoundedVec<u8, T::StringLimit> =
-			symbol.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		let name: BoundedVec<u8, T::StringLimit> =
+			name.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		let symbol: BoundedVec<u8, T::StringLimit> =
+			symbol.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		ensure!(T::MetadataPolicy::is_valid(&name, &symbol), Error::<T, I>::BadMetadata);
 
-		let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 		ensure!(from == &d.owner, Error::<T, I>::NoPermission);
 
@@ -971,18 +1984,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(metadata.as_ref().map_or(true, |m| !m.is_frozen), Error::<T, I>::NoPermission);
 
 			let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
-			let new_deposit = Self::calc_metadata_deposit(&name, &symbol);
+			let new_deposit = Self::calc_metadata_deposit(&id, &name, &symbol);
 
-			if new_deposit > old_deposit {
-				T::Currency::reserve(from, new_deposit - old_deposit)?;
-			} else {
-				T::Currency::unreserve(from, old_deposit - new_deposit);
-			}
+			Self::update_deposit(&id, from, DepositKind::Metadata, old_deposit, new_deposit)?;
+			d.metadata_deposit = new_deposit;
+			Asset::<T, I>::insert(&id, d);
 
 			*metadata = Some(AssetMetadata {
 				deposit: new_deposit,
-				name: bounded_name,
-				symbol: bounded_symbol,
+				name: name.clone(),
+				symbol: symbol.clone(),
 				decimals,
 				is_frozen: false,
 			});
@@ -998,11 +2009,144 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
-	/// Calculate the metadata deposit for the provided data.
-	pub(super) fn calc_metadata_deposit(name: &[u8], symbol: &[u8]) -> DepositBalanceOf<T, I> {
-		T::MetadataDepositPerByte::get()
-			.saturating_mul(((name.len() + symbol.len()) as u32).into())
-			.saturating_add(T::MetadataDepositBase::get())
+	/// Get the total balance of `who` on hold for asset `id`, across all reasons.
+	pub fn total_balance_on_hold(id: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(id, who)
+			.iter()
+			.fold(Zero::zero(), |total, hold| total.saturating_add(hold.amount))
+	}
+
+	/// Get the balance of `who` on hold for asset `id` with the given `reason`.
+	pub fn balance_on_hold(
+		id: T::AssetId,
+		reason: &T::RuntimeHoldReason,
+		who: &T::AccountId,
+	) -> T::Balance {
+		Holds::<T, I>::get(id, who)
+			.iter()
+			.find(|hold| &hold.id == reason)
+			.map_or_else(Zero::zero, |hold| hold.amount)
+	}
+
+	/// Set the balance of `who` on hold for asset `id` with the given `reason` to `amount`.
+	///
+	/// This neither touches the account's balance nor the asset's supply; it is up to the caller
+	/// to move the difference from or to the account's balance.
+	pub(super) fn set_balance_on_hold(
+		id: T::AssetId,
+		reason: &T::RuntimeHoldReason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(Account::<T, I>::contains_key(&id, who), Error::<T, I>::NoAccount);
+
+		Holds::<T, I>::try_mutate_exists(&id, who, |maybe_holds| -> DispatchResult {
+			let mut holds = maybe_holds.take().unwrap_or_default();
+			if let Some(hold) = holds.iter_mut().find(|hold| &hold.id == reason) {
+				hold.amount = amount;
+				holds.retain(|hold| !hold.amount.is_zero());
+			} else if !amount.is_zero() {
+				holds
+					.try_push(IdAmount { id: *reason, amount })
+					.map_err(|_| Error::<T, I>::TooManyHolds)?;
+			}
+			*maybe_holds = if holds.is_empty() { None } else { Some(holds) };
+			Ok(())
+		})
+	}
+
+	/// Get the amount of `who`'s balance of asset `id` which cannot be withdrawn: the largest of
+	/// the amount reported by `T::Freezer` and any named freezes, or `None` if neither applies.
+	pub(super) fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		let named = Freezes::<T, I>::get(&id, who).iter().map(|freeze| freeze.amount).max();
+		match (T::Freezer::frozen_balance(id, who), named) {
+			(Some(a), Some(b)) => Some(a.max(b)),
+			(a, b) => a.or(b),
+		}
+	}
+
+	/// Get the amount of `who`'s balance of asset `id` frozen under the given `reason`.
+	pub fn balance_frozen(
+		id: T::AssetId,
+		reason: &T::FreezeIdentifier,
+		who: &T::AccountId,
+	) -> T::Balance {
+		Freezes::<T, I>::get(id, who)
+			.iter()
+			.find(|freeze| &freeze.id == reason)
+			.map_or_else(Zero::zero, |freeze| freeze.amount)
+	}
+
+	/// Set the amount of `who`'s balance of asset `id` frozen under the given `reason` to
+	/// `amount`, removing the freeze if `amount` is zero.
+	pub(super) fn set_frozen(
+		id: T::AssetId,
+		reason: &T::FreezeIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if !amount.is_zero() {
+			let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(Account::<T, I>::contains_key(&id, who), Error::<T, I>::NoAccount);
+		}
+
+		Freezes::<T, I>::try_mutate_exists(&id, who, |maybe_freezes| -> DispatchResult {
+			let mut freezes = maybe_freezes.take().unwrap_or_default();
+			if let Some(freeze) = freezes.iter_mut().find(|freeze| &freeze.id == reason) {
+				freeze.amount = amount;
+				freezes.retain(|freeze| !freeze.amount.is_zero());
+			} else if !amount.is_zero() {
+				freezes
+					.try_push(IdAmount { id: *reason, amount })
+					.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+			}
+			*maybe_freezes = if freezes.is_empty() { None } else { Some(freezes) };
+			Ok(())
+		})
+	}
+
+	/// Calculate the metadata deposit of asset `id` for the provided data.
+	pub(super) fn calc_metadata_deposit(
+		id: &T::AssetId,
+		name: &[u8],
+		symbol: &[u8],
+	) -> DepositBalanceOf<T, I> {
+		T::DepositPolicy::metadata_deposit(id, (name.len() + symbol.len()) as u32)
+	}
+
+	/// Update the statistics of asset `id` with `f`, if `T::TrackStats` is set.
+	fn note_stats(id: &T::AssetId, f: impl FnOnce(&mut AssetStatistics<T::Balance>)) {
+		if T::TrackStats::get() {
+			AssetStats::<T, I>::mutate(id, f);
+		}
+	}
+
+	/// Get the activity counters of asset `id`, which stay zero unless `T::TrackStats` is set.
+	pub fn stats(id: T::AssetId) -> AssetStatistics<T::Balance> {
+		AssetStats::<T, I>::get(id)
+	}
+
+	/// Get the number of assets in existence, including those being destroyed.
+	pub fn asset_count() -> u32 {
+		AssetCount::<T, I>::get()
+	}
+
+	/// Returns an iterator of the assets in existence.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	pub fn asset_ids() -> impl Iterator<Item = T::AssetId> {
+		Asset::<T, I>::iter_keys()
+	}
+
+	/// Returns an iterator of the assets `who` has an account for.
+	///
+	/// NOTE: this goes through every asset in existence, invoking two storage reads per asset
+	/// rather than per account of `who`, so it should be used sparingly on-chain.
+	pub fn owned_assets(who: T::AccountId) -> impl Iterator<Item = T::AssetId> {
+		Asset::<T, I>::iter_keys().filter(move |id| Account::<T, I>::contains_key(id, &who))
 	}
 
 	/// Returns all the non-zero balances for all assets of the given `account`.
@@ -1013,4 +2157,316 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			})
 			.collect::<Vec<_>>()
 	}
+
+	/// Returns the balance of up to `limit` asset accounts of `account`, together with whether it
+	/// is frozen and how much of it can be withdrawn, starting after the asset `start_after`, or
+	/// from the first asset if it is `None`.
+	///
+	/// Assets are gone through in storage order, so the id of the last asset returned is the
+	/// cursor of the next page.
+	///
+	/// NOTE: like `account_balances`, this goes through every asset in existence, invoking at
+	/// least two storage reads per asset rather than per account of `account`.
+	pub fn account_balances_info(
+		account: T::AccountId,
+		start_after: Option<T::AssetId>,
+		limit: u32,
+	) -> Vec<(T::AssetId, AccountBalanceSummary<T::Balance>)> {
+		let assets = match start_after {
+			Some(id) => Asset::<T, I>::iter_from(Asset::<T, I>::hashed_key_for(id)),
+			None => Asset::<T, I>::iter(),
+		};
+		assets
+			.filter_map(|(id, details)| {
+				let asset_account = Self::account(&id, &account)?;
+				let is_frozen =
+					asset_account.status.is_frozen() || details.status == AssetStatus::Frozen;
+				let reducible =
+					Self::reducible_balance(id.clone(), &account, false).unwrap_or_default();
+				Some((
+					id,
+					AccountBalanceSummary { balance: asset_account.balance, is_frozen, reducible },
+				))
+			})
+			.take(limit as usize)
+			.collect()
+	}
+
+	/// Returns the balances of up to `limit` holders of the asset `id`, starting after the account
+	/// `start_after`, or from the first holder if it is `None`.
+	///
+	/// Holders are listed in the order of their storage keys, that is by the hash of their
+	/// account id, not by balance. The cursor is the raw storage key of `start_after`, so it stays
+	/// valid when balances change or when `start_after` itself is reaped between two pages: every
+	/// holder is then returned exactly once, at most with the balance it had when its page was
+	/// read. The last account returned is the cursor of the next page.
+	///
+	/// NOTE: this invokes a storage read per holder.
+	pub fn holders(
+		id: T::AssetId,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, T::Balance)> {
+		let holders = match start_after {
+			Some(who) => Account::<T, I>::iter_key_prefix_from(
+				&id,
+				Account::<T, I>::hashed_key_for(&id, who),
+			),
+			None => Account::<T, I>::iter_key_prefix(&id),
+		};
+		holders
+			.filter_map(|who| Self::account(&id, &who).map(|account| (who, account.balance)))
+			.take(limit as usize)
+			.collect()
+	}
+
+	/// Returns the metadata of the asset `id` and a summary of its details, if it exists.
+	pub fn asset_info(
+		id: T::AssetId,
+	) -> Option<(AssetMetadata<DepositBalanceOf<T, I>, Vec<u8>>, AssetDetailsSummary<T::Balance>)>
+	{
+		let details = Asset::<T, I>::get(&id)?;
+		Some((Self::unbounded_metadata(id), details.into()))
+	}
+
+	/// Returns the metadata and a summary of the details of up to `limit` assets, starting after
+	/// the asset `start_after`, or from the first asset if it is `None`.
+	///
+	/// Assets are listed in storage order, so the id of the last asset returned is the cursor of
+	/// the next page.
+	///
+	/// NOTE: this invokes two storage reads per asset.
+	pub fn assets_info(
+		start_after: Option<T::AssetId>,
+		limit: u32,
+	) -> Vec<(
+		T::AssetId,
+		AssetMetadata<DepositBalanceOf<T, I>, Vec<u8>>,
+		AssetDetailsSummary<T::Balance>,
+	)> {
+		let assets = match start_after {
+			Some(id) => Asset::<T, I>::iter_from(Asset::<T, I>::hashed_key_for(id)),
+			None => Asset::<T, I>::iter(),
+		};
+		assets
+			.take(limit as usize)
+			.map(|(id, details)| (id.clone(), Self::unbounded_metadata(id), details.into()))
+			.collect()
+	}
+
+	/// The metadata of the asset `id`, with its strings unbounded.
+	fn unbounded_metadata(id: T::AssetId) -> AssetMetadata<DepositBalanceOf<T, I>, Vec<u8>> {
+		let metadata = Metadata::<T, I>::get(id).unwrap_or_default();
+		AssetMetadata {
+			deposit: metadata.deposit,
+			name: metadata.name.into_inner(),
+			symbol: metadata.symbol.into_inner(),
+			decimals: metadata.decimals,
+			is_frozen: metadata.is_frozen,
+		}
+	}
+
+	/// Get the amount `delegate` may transfer out of the balance of `owner` in the asset `id`.
+	pub fn allowance(id: T::AssetId, owner: &T::AccountId, delegate: &T::AccountId) -> T::Balance {
+		Approvals::<T, I>::get((id, owner, delegate))
+			.map(|x| x.amount)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Returns the approvals `owner` has outstanding for the asset `id`, with their delegates.
+	///
+	/// NOTE: there are at most `MaxApprovalsPerOwner` of them, each costing a storage read.
+	pub fn approvals_of(
+		id: T::AssetId,
+		owner: &T::AccountId,
+	) -> Vec<(T::AccountId, Approval<T::Balance, DepositBalanceOf<T, I>>)> {
+		Approvals::<T, I>::iter_prefix((id, owner.clone()))
+			.take(T::MaxApprovalsPerOwner::get() as usize)
+			.collect()
+	}
+
+	/// Returns the amounts `owner` has approved for transfer, with their assets and delegates,
+	/// either for the asset `maybe_id` or, if it is `None`, for every asset.
+	///
+	/// NOTE: for a single asset this is as costly as `approvals_of`. For every asset, it also
+	/// invokes a storage read per asset in existence, so it should be used sparingly on-chain.
+	pub fn allowances(
+		owner: T::AccountId,
+		maybe_id: Option<T::AssetId>,
+	) -> Vec<(T::AssetId, T::AccountId, T::Balance)> {
+		let approvals_of = |id: T::AssetId| {
+			Self::approvals_of(id.clone(), &owner)
+				.into_iter()
+				.map(move |(delegate, approval)| (id.clone(), delegate, approval.amount))
+		};
+		match maybe_id {
+			Some(id) => approvals_of(id).collect(),
+			None => Asset::<T, I>::iter_keys()
+				.filter(|id| ApprovalCount::<T, I>::get(id, &owner) > 0)
+				.flat_map(approvals_of)
+				.collect(),
+		}
+	}
+
+	/// Returns the amounts `delegate` has been approved to transfer, with their assets and
+	/// owners.
+	///
+	/// NOTE: there are at most `MaxApprovalsPerDelegate` of them, each costing two storage reads.
+	pub fn approvals_for_delegate(
+		delegate: &T::AccountId,
+	) -> Vec<(T::AssetId, T::AccountId, T::Balance)> {
+		DelegateApprovals::<T, I>::iter_key_prefix(delegate)
+			.take(T::MaxApprovalsPerDelegate::get() as usize)
+			.map(|(id, owner)| {
+				let amount = Self::allowance(id.clone(), &owner, delegate);
+				(id, owner, amount)
+			})
+			.collect()
+	}
+
+	/// Returns the assets of which `owner` is the owner, unlike `owned_assets` which returns the
+	/// assets it holds.
+	///
+	/// NOTE: accounts given assets by `ForceOrigin` may own more than `MaxOwnedAssets` of them.
+	pub fn assets_owned_by(owner: &T::AccountId) -> Vec<T::AssetId> {
+		OwnedAssets::<T, I>::iter_key_prefix(owner).collect()
+	}
+
+	/// Check the invariants of the pallet's storage:
+	///
+	/// - `AssetCount` is the number of assets, and there are no accounts of unknown assets.
+	/// - Every `Account` entry decodes, possibly from the previous encoding of an ongoing lazy
+	///   migration.
+	/// - The `accounts`, `sufficients` and `approvals` of every asset match its `Account` and
+	///   `Approvals` entries, and `ApprovalCount` matches the approvals of each owner.
+	/// - `DelegateApprovals` indexes exactly the `Approvals`, and `DelegateApprovalCount` matches
+	///   the approvals given to each delegate.
+	/// - `HoldingCount` matches the `Account` entries of each account.
+	/// - `OwnedAssets` indexes exactly the owner of every asset, and `OwnedAssetCount` matches the
+	///   assets of each owner.
+	/// - The `metadata_deposit` of every asset is the deposit of its metadata.
+	/// - The balances and holds of an asset add up to its supply. Only the first
+	///   `TRY_STATE_SUPPLY_SAMPLE` accounts are added up, so for larger assets, and for assets
+	///   being destroyed (whose supply is left alone as their accounts go), they may only not
+	///   exceed it.
+	#[cfg(any(test, feature = "try-runtime"))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use sp_std::collections::btree_map::BTreeMap;
+
+		ensure!(
+			AssetCount::<T, I>::get() as usize == Asset::<T, I>::iter_keys().count(),
+			"`AssetCount` does not match the number of assets"
+		);
+
+		let mut total_accounts = 0usize;
+		for (id, details) in Asset::<T, I>::iter() {
+			let mut accounts = 0u32;
+			let mut sufficients = 0u32;
+			let mut sum = T::Balance::zero();
+			let mut sampled_all = true;
+			for (i, who) in Account::<T, I>::iter_key_prefix(&id).enumerate() {
+				let account = Self::account(&id, &who).ok_or("asset account does not decode")?;
+				accounts.saturating_inc();
+				if matches!(account.reason, ExistenceReason::Sufficient) {
+					sufficients.saturating_inc();
+				}
+				if i < TRY_STATE_SUPPLY_SAMPLE {
+					let held = Holds::<T, I>::get(&id, &who)
+						.iter()
+						.fold(T::Balance::zero(), |held, hold| held.saturating_add(hold.amount));
+					sum = sum.saturating_add(account.balance).saturating_add(held);
+				} else {
+					sampled_all = false;
+				}
+			}
+			total_accounts.saturating_accrue(accounts as usize);
+			ensure!(details.accounts == accounts, "asset `accounts` does not match its accounts");
+			ensure!(
+				details.sufficients == sufficients,
+				"asset `sufficients` does not match its sufficient accounts"
+			);
+			ensure!(
+				details.sufficients <= details.accounts,
+				"asset has more sufficients than accounts"
+			);
+			ensure!(sum <= details.supply, "asset balances exceed its supply");
+			if sampled_all && details.status != AssetStatus::Destroying {
+				ensure!(sum == details.supply, "asset balances do not add up to its supply");
+			}
+
+			let mut approvals = 0u32;
+			let mut approvals_per_owner = BTreeMap::<T::AccountId, u32>::new();
+			for ((owner, _delegate), _) in Approvals::<T, I>::iter_prefix((id.clone(),)) {
+				approvals.saturating_inc();
+				approvals_per_owner.entry(owner).or_default().saturating_inc();
+			}
+			ensure!(
+				details.approvals == approvals,
+				"asset `approvals` does not match its approvals"
+			);
+			ensure!(
+				ApprovalCount::<T, I>::iter_prefix(&id).collect::<BTreeMap<_, _>>() ==
+					approvals_per_owner,
+				"`ApprovalCount` does not match the approvals of each owner"
+			);
+
+			ensure!(
+				Metadata::<T, I>::get(&id).map_or(Zero::zero(), |m| m.deposit) ==
+					details.metadata_deposit,
+				"asset `metadata_deposit` does not match its metadata"
+			);
+		}
+		ensure!(
+			Account::<T, I>::iter_keys().count() == total_accounts,
+			"there are accounts of unknown assets"
+		);
+
+		let mut approvals = 0usize;
+		let mut approvals_per_delegate = BTreeMap::<T::AccountId, u32>::new();
+		for (id, owner, delegate) in Approvals::<T, I>::iter_keys() {
+			ensure!(
+				DelegateApprovals::<T, I>::contains_key(&delegate, (&id, &owner)),
+				"an approval is missing from `DelegateApprovals`"
+			);
+			approvals.saturating_inc();
+			approvals_per_delegate.entry(delegate).or_default().saturating_inc();
+		}
+		ensure!(
+			DelegateApprovals::<T, I>::iter_keys().count() == approvals,
+			"`DelegateApprovals` has entries without an approval"
+		);
+		ensure!(
+			DelegateApprovalCount::<T, I>::iter().collect::<BTreeMap<_, _>>() ==
+				approvals_per_delegate,
+			"`DelegateApprovalCount` does not match the approvals of each delegate"
+		);
+
+		let mut holdings = BTreeMap::<T::AccountId, u32>::new();
+		for (_, who) in Account::<T, I>::iter_keys() {
+			holdings.entry(who).or_default().saturating_inc();
+		}
+		ensure!(
+			HoldingCount::<T, I>::iter().collect::<BTreeMap<_, _>>() == holdings,
+			"`HoldingCount` does not match the accounts of each holder"
+		);
+
+		let mut owned = BTreeMap::<T::AccountId, u32>::new();
+		for (id, details) in Asset::<T, I>::iter() {
+			ensure!(
+				OwnedAssets::<T, I>::contains_key(&details.owner, &id),
+				"`OwnedAssets` misses the owner of an asset"
+			);
+			owned.entry(details.owner).or_default().saturating_inc();
+		}
+		ensure!(
+			OwnedAssets::<T, I>::iter_keys().count() == Asset::<T, I>::iter_keys().count(),
+			"`OwnedAssets` has entries without an asset"
+		);
+		ensure!(
+			OwnedAssetCount::<T, I>::iter().collect::<BTreeMap<_, _>>() == owned,
+			"`OwnedAssetCount` does not match the assets of each owner"
+		);
+
+		Ok(())
+	}
 }
@@ -0,0 +1,227 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Adapter exposing a single asset through the `Currency` family of traits.
+
+use super::*;
+use frame_support::traits::{
+	fungible::{
+		self, Balanced as _, BalancedHold as _, Inspect as _, InspectHold as _, Mutate as _,
+		MutateHold as _,
+	},
+	tokens::{
+		Fortitude::{Force, Polite},
+		Precision::{BestEffort, Exact},
+		Preservation::{Expendable, Preserve},
+		Restriction,
+	},
+	BalanceStatus, ExistenceRequirement, Get, Imbalance, SignedImbalance, WithdrawReasons,
+};
+use sp_std::marker::PhantomData;
+
+/// The `fungible` view of the asset backing a [`CurrencyOf`].
+type ItemOf<T, I, A> = fungible::ItemOf<Pallet<T, I>, A, <T as SystemConfig>::AccountId>;
+
+type PositiveImbalanceOf<T, I, A> = fungible::Debt<<T as SystemConfig>::AccountId, ItemOf<T, I, A>>;
+type NegativeImbalanceOf<T, I, A> =
+	fungible::Credit<<T as SystemConfig>::AccountId, ItemOf<T, I, A>>;
+
+/// Exposes the asset `A::get()` of this pallet as a `Currency` and `ReservableCurrency`, for
+/// pallets which have not moved to the `fungible` traits yet.
+///
+/// - The existential deposit is the asset's `min_balance`.
+/// - Reserved funds are funds on hold for the reason `R::get()`, so the asset accounts must be
+///   allowed at least one hold.
+/// - Imbalances adjust the asset's supply when dropped, just like the `fungibles::Balanced` ones.
+/// - `WithdrawReasons` are ignored, since assets have no locks. Freezes and the `Freezer` still
+///   apply to every withdrawal, including slashes.
+/// - `deactivate` and `reactivate` do nothing, since assets do not track inactive issuance.
+pub struct CurrencyOf<T, I, A, R>(PhantomData<(T, I, A, R)>);
+
+impl<T, I, A, R> Currency<T::AccountId> for CurrencyOf<T, I, A, R>
+where
+	T: Config<I>,
+	I: 'static,
+	A: Get<T::AssetId>,
+	R: Get<T::RuntimeHoldReason>,
+{
+	type Balance = T::Balance;
+	type PositiveImbalance = PositiveImbalanceOf<T, I, A>;
+	type NegativeImbalance = NegativeImbalanceOf<T, I, A>;
+
+	fn total_balance(who: &T::AccountId) -> Self::Balance {
+		ItemOf::<T, I, A>::total_balance(who)
+	}
+
+	fn can_slash(who: &T::AccountId, value: Self::Balance) -> bool {
+		value.is_zero() || Self::free_balance(who) >= value
+	}
+
+	fn total_issuance() -> Self::Balance {
+		ItemOf::<T, I, A>::total_issuance()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		ItemOf::<T, I, A>::minimum_balance()
+	}
+
+	fn burn(amount: Self::Balance) -> Self::PositiveImbalance {
+		ItemOf::<T, I, A>::rescind(amount)
+	}
+
+	fn issue(amount: Self::Balance) -> Self::NegativeImbalance {
+		ItemOf::<T, I, A>::issue(amount)
+	}
+
+	fn free_balance(who: &T::AccountId) -> Self::Balance {
+		ItemOf::<T, I, A>::balance(who)
+	}
+
+	fn ensure_can_withdraw(
+		who: &T::AccountId,
+		amount: Self::Balance,
+		_reasons: WithdrawReasons,
+		_new_balance: Self::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		ItemOf::<T, I, A>::can_withdraw(who, amount).into_result(false)?;
+		Ok(())
+	}
+
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		value: Self::Balance,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		let preservation = match existence_requirement {
+			ExistenceRequirement::KeepAlive => Preserve,
+			ExistenceRequirement::AllowDeath => Expendable,
+		};
+		ItemOf::<T, I, A>::transfer(source, dest, value, preservation)?;
+		Ok(())
+	}
+
+	fn slash(who: &T::AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+		// Take what we can from the free balance, then the rest from the reserved balance.
+		let free = ItemOf::<T, I, A>::withdraw(who, value, BestEffort, Expendable, Force)
+			.unwrap_or_else(|_| Imbalance::zero());
+		let remaining = value.saturating_sub(free.peek());
+		if remaining.is_zero() {
+			return (free, Zero::zero())
+		}
+		let (reserved, remaining) = ItemOf::<T, I, A>::slash(&R::get(), who, remaining);
+		(free.merge(reserved), remaining)
+	}
+
+	fn deposit_into_existing(
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Result<Self::PositiveImbalance, DispatchError> {
+		ensure!(Account::<T, I>::contains_key(A::get(), who), Error::<T, I>::NoAccount);
+		ItemOf::<T, I, A>::deposit(who, value, Exact)
+	}
+
+	fn deposit_creating(who: &T::AccountId, value: Self::Balance) -> Self::PositiveImbalance {
+		ItemOf::<T, I, A>::deposit(who, value, Exact).unwrap_or_else(|_| Imbalance::zero())
+	}
+
+	fn withdraw(
+		who: &T::AccountId,
+		value: Self::Balance,
+		_reasons: WithdrawReasons,
+		liveness: ExistenceRequirement,
+	) -> Result<Self::NegativeImbalance, DispatchError> {
+		let preservation = match liveness {
+			ExistenceRequirement::KeepAlive => Preserve,
+			ExistenceRequirement::AllowDeath => Expendable,
+		};
+		ItemOf::<T, I, A>::withdraw(who, value, Exact, preservation, Polite)
+	}
+
+	fn make_free_balance_be(
+		who: &T::AccountId,
+		balance: Self::Balance,
+	) -> SignedImbalance<Self::Balance, Self::PositiveImbalance> {
+		let free = Self::free_balance(who);
+		if balance >= free {
+			SignedImbalance::Positive(Self::deposit_creating(who, balance - free))
+		} else {
+			SignedImbalance::Negative(
+				ItemOf::<T, I, A>::withdraw(who, free - balance, Exact, Expendable, Force)
+					.unwrap_or_else(|_| Imbalance::zero()),
+			)
+		}
+	}
+}
+
+impl<T, I, A, R> ReservableCurrency<T::AccountId> for CurrencyOf<T, I, A, R>
+where
+	T: Config<I>,
+	I: 'static,
+	A: Get<T::AssetId>,
+	R: Get<T::RuntimeHoldReason>,
+{
+	fn can_reserve(who: &T::AccountId, value: Self::Balance) -> bool {
+		ItemOf::<T, I, A>::can_hold(&R::get(), who, value)
+	}
+
+	fn slash_reserved(
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> (Self::NegativeImbalance, Self::Balance) {
+		ItemOf::<T, I, A>::slash(&R::get(), who, value)
+	}
+
+	fn reserved_balance(who: &T::AccountId) -> Self::Balance {
+		ItemOf::<T, I, A>::balance_on_hold(&R::get(), who)
+	}
+
+	fn reserve(who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		ItemOf::<T, I, A>::hold(&R::get(), who, value)
+	}
+
+	fn unreserve(who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		let released =
+			ItemOf::<T, I, A>::release(&R::get(), who, value, BestEffort).unwrap_or_default();
+		value.saturating_sub(released)
+	}
+
+	fn repatriate_reserved(
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError> {
+		let mode = match status {
+			BalanceStatus::Free => Restriction::Free,
+			BalanceStatus::Reserved => Restriction::OnHold,
+		};
+		let moved = ItemOf::<T, I, A>::transfer_on_hold(
+			&R::get(),
+			slashed,
+			beneficiary,
+			value,
+			BestEffort,
+			mode,
+			Polite,
+		)?;
+		Ok(value.saturating_sub(moved))
+	}
+}
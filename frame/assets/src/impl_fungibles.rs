@@ -19,15 +19,17 @@
 
 use frame_support::{
 	defensive,
+	storage::with_storage_layer,
 	traits::tokens::{
 		Fortitude,
-		Precision::{self, BestEffort},
+		Precision::{self, BestEffort, Exact},
 		Preservation::{self, Expendable},
 		Provenance::{self, Minted},
 	},
 };
 
 use super::*;
+use sp_runtime::traits::Bounded;
 
 impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId> for Pallet<T, I> {
 	type AssetId = T::AssetId;
@@ -46,7 +48,8 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 	}
 
 	fn total_balance(asset: Self::AssetId, who: &<T as SystemConfig>::AccountId) -> Self::Balance {
-		Pallet::<T, I>::balance(asset, who)
+		Pallet::<T, I>::balance(asset.clone(), who)
+			.saturating_add(Pallet::<T, I>::total_balance_on_hold(asset, who))
 	}
 
 	fn reducible_balance(
@@ -77,7 +80,7 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 	}
 
 	fn asset_exists(asset: Self::AssetId) -> bool {
-		Asset::<T, I>::contains_key(asset)
+		Pallet::<T, I>::asset_exists(asset)
 	}
 }
 
@@ -111,6 +114,65 @@ impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId>
 			amount,
 		});
 	}
+
+	// Same as the default, except that the owner may have disallowed minting into frozen accounts.
+	fn mint_into(
+		asset: Self::AssetId,
+		who: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::ensure_can_mint_into(&asset, who)?;
+		Self::total_issuance(asset.clone())
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		let actual = <Self as fungibles::Unbalanced<_>>::increase_balance(
+			asset.clone(),
+			who,
+			amount,
+			Exact,
+		)?;
+		Self::set_total_issuance(
+			asset.clone(),
+			Self::total_issuance(asset.clone()).saturating_add(actual),
+		);
+		Self::done_mint_into(asset, who, amount);
+		Ok(actual)
+	}
+
+	// Same as the default, except that any dust left in `source` goes to `T::OnDust`, rather than
+	// staying in the supply with no account holding it.
+	fn transfer(
+		asset: Self::AssetId,
+		source: &<T as SystemConfig>::AccountId,
+		dest: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		let _extra = Self::can_withdraw(asset.clone(), source, amount)
+			.into_result(preservation != Expendable)?;
+		Self::can_deposit(asset.clone(), dest, amount, Provenance::Extant).into_result()?;
+		let debit = <Self as fungibles::Unbalanced<_>>::decrease_balance(
+			asset.clone(),
+			source,
+			amount,
+			BestEffort,
+			preservation,
+			Fortitude::Polite,
+		)?;
+		// This should never fail as we checked `can_deposit` earlier. But we do a best-effort
+		// anyway.
+		let _ = <Self as fungibles::Unbalanced<_>>::increase_balance(
+			asset.clone(),
+			dest,
+			amount,
+			BestEffort,
+		);
+		Self::done_transfer(asset.clone(), source, dest, amount);
+		if debit > amount {
+			Self::dispose_dust(asset, source, debit.saturating_sub(amount));
+		}
+		Ok(amount)
+	}
 }
 
 impl<T: Config<I>, I: 'static> fungibles::Balanced<<T as SystemConfig>::AccountId>
@@ -152,14 +214,23 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 			keep_alive: preservation != Expendable,
 			best_effort: precision == BestEffort,
 		};
-		Self::decrease_balance(asset, who, amount, f, |_, _| Ok(()))
+		Self::decrease_balance(asset, who, amount, f, false, |_, _| Ok(()))
 	}
 	fn increase_balance(
 		asset: T::AssetId,
 		who: &T::AccountId,
 		amount: Self::Balance,
-		_: Precision,
+		precision: Precision,
 	) -> Result<Self::Balance, DispatchError> {
+		// The supply bounds every account balance, so capping the increase at what is left of the
+		// supply is enough to avoid an overflow.
+		let amount = match precision {
+			BestEffort => {
+				let supply = Asset::<T, I>::get(&asset).map(|d| d.supply).unwrap_or_default();
+				amount.min(T::Balance::max_value().saturating_sub(supply))
+			},
+			_ => amount,
+		};
 		Self::increase_balance(asset, who, amount, |_| Ok(()))?;
 		Ok(amount)
 	}
@@ -167,6 +238,139 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 	// TODO: #13196 implement deactivate/reactivate once we have inactive balance tracking.
 }
 
+impl<T: Config<I>, I: 'static> fungibles::InspectHold<T::AccountId> for Pallet<T, I> {
+	type Reason = T::RuntimeHoldReason;
+
+	fn total_balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Pallet::<T, I>::total_balance_on_hold(asset, who)
+	}
+
+	fn balance_on_hold(asset: T::AssetId, reason: &Self::Reason, who: &T::AccountId) -> T::Balance {
+		Pallet::<T, I>::balance_on_hold(asset, reason, who)
+	}
+
+	fn hold_available(asset: T::AssetId, reason: &Self::Reason, who: &T::AccountId) -> bool {
+		if !Account::<T, I>::contains_key(&asset, who) {
+			return false
+		}
+		let holds = Holds::<T, I>::get(asset, who);
+		!holds.is_full() || holds.iter().any(|hold| &hold.id == reason)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::UnbalancedHold<T::AccountId> for Pallet<T, I> {
+	fn set_balance_on_hold(
+		asset: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Pallet::<T, I>::set_balance_on_hold(asset, reason, who, amount)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::MutateHold<T::AccountId> for Pallet<T, I> {
+	fn done_hold(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::Held { asset_id, who: who.clone(), reason: *reason, amount });
+	}
+
+	fn done_release(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::Released {
+			asset_id,
+			who: who.clone(),
+			reason: *reason,
+			amount,
+		});
+	}
+
+	fn done_burn_held(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::BurnedHeld {
+			asset_id,
+			who: who.clone(),
+			reason: *reason,
+			amount,
+		});
+	}
+}
+
+/// Held funds are kept apart from `balance`, so a slash can never take more than what is on hold
+/// for `reason`; any shortfall is returned to the caller. The resulting credit reduces the supply
+/// when dropped, or may be resolved into a beneficiary account instead.
+impl<T: Config<I>, I: 'static> fungibles::BalancedHold<T::AccountId> for Pallet<T, I> {
+	fn done_slash(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::SlashedHeld {
+			asset_id,
+			who: who.clone(),
+			reason: *reason,
+			amount,
+		});
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::InspectFreeze<T::AccountId> for Pallet<T, I> {
+	type Id = T::FreezeIdentifier;
+
+	fn balance_frozen(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> T::Balance {
+		Pallet::<T, I>::balance_frozen(asset, id, who)
+	}
+
+	fn can_freeze(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		if !Account::<T, I>::contains_key(&asset, who) {
+			return false
+		}
+		let freezes = Freezes::<T, I>::get(asset, who);
+		!freezes.is_full() || freezes.iter().any(|freeze| &freeze.id == id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::MutateFreeze<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(
+		asset: T::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Self::set_frozen(asset, id, who, amount)
+	}
+
+	fn extend_freeze(
+		asset: T::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let current = Pallet::<T, I>::balance_frozen(asset.clone(), id, who);
+		Self::set_frozen(asset, id, who, current.max(amount))
+	}
+
+	fn thaw(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		Self::set_frozen(asset, id, who, Zero::zero())
+	}
+}
+
 impl<T: Config<I>, I: 'static> fungibles::Create<T::AccountId> for Pallet<T, I> {
 	fn create(
 		id: T::AssetId,
@@ -174,7 +378,8 @@ impl<T: Config<I>, I: 'static> fungibles::Create<T::AccountId> for Pallet<T, I>
 		is_sufficient: bool,
 		min_balance: Self::Balance,
 	) -> DispatchResult {
-		Self::do_force_create(id, admin, is_sufficient, min_balance)
+		// Not called from a dispatchable, so a failing `CallbackHandle` must be rolled back here.
+		with_storage_layer(|| Self::do_force_create(id, admin, is_sufficient, min_balance))
 	}
 }
 
@@ -192,7 +397,7 @@ impl<T: Config<I>, I: 'static> fungibles::Destroy<T::AccountId> for Pallet<T, I>
 	}
 
 	fn finish_destroy(id: T::AssetId) -> DispatchResult {
-		Self::do_finish_destroy(id)
+		with_storage_layer(|| Self::do_finish_destroy(id))
 	}
 }
 
@@ -200,15 +405,15 @@ impl<T: Config<I>, I: 'static> fungibles::metadata::Inspect<<T as SystemConfig>:
 	for Pallet<T, I>
 {
 	fn name(asset: T::AssetId) -> Vec<u8> {
-		Metadata::<T, I>::get(asset).name.to_vec()
+		Metadata::<T, I>::get(asset).map(|m| m.name.to_vec()).unwrap_or_default()
 	}
 
 	fn symbol(asset: T::AssetId) -> Vec<u8> {
-		Metadata::<T, I>::get(asset).symbol.to_vec()
+		Metadata::<T, I>::get(asset).map(|m| m.symbol.to_vec()).unwrap_or_default()
 	}
 
 	fn decimals(asset: T::AssetId) -> u8 {
-		Metadata::<T, I>::get(asset).decimals
+		Metadata::<T, I>::get(asset).map_or(0, |m| m.decimals)
 	}
 }
 
@@ -231,11 +436,13 @@ impl<T: Config<I>, I: 'static>
 		<T::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance,
 	> for Pallet<T, I>
 {
+	// The asset isn't known, so this is the most the `DepositPolicy` reserves for the metadata of
+	// any asset.
 	fn calc_metadata_deposit(
 		name: &[u8],
 		symbol: &[u8],
 	) -> <T::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance {
-		Self::calc_metadata_deposit(&name, &symbol)
+		T::DepositPolicy::max_metadata_deposit((name.len() + symbol.len()) as u32)
 	}
 }
 
@@ -248,9 +455,7 @@ impl<T: Config<I>, I: 'static> fungibles::approvals::Inspect<<T as SystemConfig>
 		owner: &<T as SystemConfig>::AccountId,
 		delegate: &<T as SystemConfig>::AccountId,
 	) -> T::Balance {
-		Approvals::<T, I>::get((asset, &owner, &delegate))
-			.map(|x| x.amount)
-			.unwrap_or_else(Zero::zero)
+		Self::allowance(asset, owner, delegate)
 	}
 }
 
@@ -282,19 +487,19 @@ impl<T: Config<I>, I: 'static> fungibles::roles::Inspect<<T as SystemConfig>::Ac
 	for Pallet<T, I>
 {
 	fn owner(asset: T::AssetId) -> Option<<T as SystemConfig>::AccountId> {
-		Asset::<T, I>::get(asset).map(|x| x.owner)
+		Pallet::<T, I>::owner(asset)
 	}
 
 	fn issuer(asset: T::AssetId) -> Option<<T as SystemConfig>::AccountId> {
-		Asset::<T, I>::get(asset).map(|x| x.issuer)
+		Pallet::<T, I>::issuer(asset)
 	}
 
 	fn admin(asset: T::AssetId) -> Option<<T as SystemConfig>::AccountId> {
-		Asset::<T, I>::get(asset).map(|x| x.admin)
+		Pallet::<T, I>::admin(asset)
 	}
 
 	fn freezer(asset: T::AssetId) -> Option<<T as SystemConfig>::AccountId> {
-		Asset::<T, I>::get(asset).map(|x| x.freezer)
+		Pallet::<T, I>::freezer(asset)
 	}
 }
 
@@ -17,38 +17,81 @@
 
 //! Assets pallet's `StoredMap` implementation.
 
-use super::*;
+use super::{functions::DeadConsequence, *};
 
 impl<T: Config<I>, I: 'static> StoredMap<(T::AssetId, T::AccountId), T::Extra> for Pallet<T, I> {
 	fn get(id_who: &(T::AssetId, T::AccountId)) -> T::Extra {
 		let (id, who) = id_who;
-		Account::<T, I>::get(id, who).map(|a| a.extra).unwrap_or_default()
+		Self::account(id, who).map(|a| a.extra).unwrap_or_default()
 	}
 
+	/// Writing `Some` extra data for an account which does not exist yet creates it, taking the
+	/// account deposit from `T::ExtraDepositor`. Writing `None` for an account which exists
+	/// destroys it, returning its deposit; this fails unless the account is empty.
 	fn try_mutate_exists<R, E: From<DispatchError>>(
 		id_who: &(T::AssetId, T::AccountId),
 		f: impl FnOnce(&mut Option<T::Extra>) -> Result<R, E>,
 	) -> Result<R, E> {
 		let (id, who) = id_who;
-		let mut maybe_extra = Account::<T, I>::get(id, who).map(|a| a.extra);
+		Self::migrate_account(id, who);
+		let maybe_account = Account::<T, I>::get(id, who);
+		let existed = maybe_account.is_some();
+		let mut maybe_extra = maybe_account.map(|a| a.extra);
 		let r = f(&mut maybe_extra)?;
-		// They want to write some value or delete it.
-		// If the account existed and they want to write a value, then we write.
-		// If the account didn't exist and they want to delete it, then we let it pass.
-		// Otherwise, we fail.
-		Account::<T, I>::try_mutate(id, who, |maybe_account| {
-			if let Some(extra) = maybe_extra {
-				// They want to write a value. Let this happen only if the account actually exists.
-				if let Some(ref mut account) = maybe_account {
-					account.extra = extra;
-				} else {
-					return Err(DispatchError::NoProviders.into())
-				}
-			} else {
-				// They want to delete it. Let this pass if the item never existed anyway.
-				ensure!(maybe_account.is_none(), DispatchError::ConsumerRemaining);
-			}
-			Ok(r)
-		})
+		match (existed, maybe_extra) {
+			(true, Some(extra)) => Self::set_extra(id, who, extra)?,
+			(false, Some(extra)) => {
+				let depositor = T::ExtraDepositor::convert((id.clone(), who.clone()))
+					.ok_or(DispatchError::from(Error::<T, I>::NoDeposit))?;
+				Self::do_touch(id.clone(), who.clone(), depositor, false)?;
+				Self::set_extra(id, who, extra)?;
+			},
+			(true, None) => Self::kill_extra_account(id.clone(), who)?,
+			(false, None) => {},
+		}
+		Ok(r)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Writes the `extra` data of the existing account `who` of the asset `id`, repricing the
+	/// deposit it exists for, if any, since its footprint may have changed.
+	fn set_extra(id: &T::AssetId, who: &T::AccountId, extra: T::Extra) -> DispatchResult {
+		let mut account = Self::account(id, who).ok_or(Error::<T, I>::NoAccount)?;
+		account.extra = extra;
+		Account::<T, I>::insert(id, who, &account);
+		Self::reprice_account_deposit(id, who, account)?;
+		Ok(())
+	}
+
+	/// Destroys the empty account `who` of the asset `id` on behalf of `StoredMap`, returning the
+	/// account deposit to whoever paid it.
+	///
+	/// Whatever the account exists for, it dies the same way as an account emptied by a burn or a
+	/// transfer: the asset's counters are released by `dead_account`, and the account is finished
+	/// off by `account_died` once, after storage was updated.
+	fn kill_extra_account(id: T::AssetId, who: &T::AccountId) -> DispatchResult {
+		use AssetStatus::*;
+		let mut account = Self::account(&id, who).ok_or(Error::<T, I>::NoAccount)?;
+		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
+		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
+		ensure!(!Holds::<T, I>::contains_key(&id, who), Error::<T, I>::ContainsHolds);
+		ensure!(!Freezes::<T, I>::contains_key(&id, who), Error::<T, I>::ContainsFreezes);
+		ensure!(account.balance.is_zero(), Error::<T, I>::WouldBurn);
+
+		if let Some((payer, deposit)) = account.reason.take_deposit_and_payer(who) {
+			Self::update_deposit(&id, &payer, DepositKind::Account, deposit, Zero::zero())?;
+		}
+		// With any deposit returned, nothing keeps the account alive.
+		let DeadConsequence::Remove(reason) =
+			Self::dead_account(who, &mut details, &account.reason, true)
+		else {
+			return Ok(())
+		};
+		Account::<T, I>::remove(&id, who);
+		Asset::<T, I>::insert(&id, details);
+		Self::account_died(id, who, &reason);
+		Ok(())
 	}
 }
@@ -81,11 +81,16 @@
 //! * `transfer_keep_alive`: Transfer sender's assets to another account, keeping the sender alive.
 //! * `approve_transfer`: Create or increase an delegated transfer.
 //! * `cancel_approval`: Rescind a previous approval.
+//! * `reject_approval`: Renounce an approval made to the caller.
 //! * `transfer_approved`: Transfer third-party's assets to another account.
 //! * `touch`: Create an asset account for non-provider assets. Caller must place a deposit.
 //! * `refund`: Return the deposit (if any) of the caller's asset account or a consumer reference
 //!   (if any) of the caller's account.
 //! * `refund_other`: Return the deposit (if any) of a specified asset account.
+//! * `poke_account_deposit`: Bring the deposit of an asset account in line with the current deposit
+//!   constants.
+//! * `destroy_metadata`: Remove the metadata of an asset class being destroyed, returning its
+//!   deposit to the Owner.
 //!
 //! ### Permissioned Functions
 //!
@@ -94,6 +99,7 @@
 //! * `force_clear_metadata`: Remove the metadata of an asset class.
 //! * `force_asset_status`: Alter an asset class's attributes.
 //! * `force_cancel_approval`: Rescind a previous approval.
+//! * `repair_refs`: Set the account counters of an asset class right.
 //!
 //! ### Privileged Functions
 //!
@@ -129,6 +135,25 @@
 //! Using `CallbackHandle` associated type, user can configure custom callback functions which are
 //! executed when new asset is created or an existing asset is destroyed.
 //!
+//! ### Transfer Filter
+//!
+//! Using `TransferFilter` associated type, user can veto transfers between accounts, e.g. to
+//! enforce a compliance policy. The filter is consulted by `transfer`, `transfer_keep_alive` and
+//! `transfer_approved`, but not by `force_transfer`, minting or burning.
+//!
+//! ### Dust Handling
+//!
+//! Using `OnDust` associated type, user can decide what happens to the dust removed from accounts
+//! which would otherwise be left below the minimum balance. It is burned by `()`, or credited to
+//! an account by [`ResolveDustTo`]. Either way, a `DustBurned` or `DustCredited` event says which
+//! happened.
+//!
+//! ### Currency Adapter
+//!
+//! [`CurrencyOf`] exposes a single asset through the `Currency` and `ReservableCurrency` traits,
+//! with reserves backed by holds, so that pallets still using those traits can be configured with
+//! an asset instead of the native token.
+//!
 //! ## Related Modules
 //!
 //! * [`System`](../frame_system/index.html)
@@ -152,6 +177,8 @@ pub mod weights;
 mod extra_mutator;
 pub use extra_mutator::*;
 mod functions;
+mod impl_currency;
+pub use impl_currency::*;
 mod impl_fungibles;
 mod impl_stored_map;
 mod types;
@@ -159,13 +186,15 @@ pub use types::*;
 
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Saturating, StaticLookup, Zero},
+	traits::{
+		AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Convert, Saturating, StaticLookup, Zero,
+	},
 	ArithmeticError, TokenError,
 };
 use sp_std::prelude::*;
 
 use frame_support::{
-	dispatch::{DispatchError, DispatchResult},
+	dispatch::{DispatchError, DispatchErrorWithPostInfo, DispatchResult, WithPostDispatchInfo},
 	ensure,
 	pallet_prelude::DispatchResultWithPostInfo,
 	storage::KeyPrefixIterator,
@@ -174,6 +203,7 @@ use frame_support::{
 		BalanceStatus::Reserved,
 		Currency, EnsureOriginWithArg, ReservableCurrency, StoredMap,
 	},
+	ReversibleStorageHasher, StorageHasher,
 };
 use frame_system::Config as SystemConfig;
 
@@ -199,26 +229,98 @@ pub trait AssetsCallback<AssetId, AccountId> {
 /// Empty implementation in case no callbacks are required.
 impl<AssetId, AccountId> AssetsCallback<AssetId, AccountId> for () {}
 
+/// Policy consulted before assets are transferred from one account to another.
+pub trait CheckTransfer<AssetId, AccountId, Balance> {
+	/// Return an error to veto the transfer of `amount` of asset `id` from `source` to `dest`.
+	fn check_transfer(
+		id: &AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+/// Allow every transfer.
+impl<AssetId, AccountId, Balance> CheckTransfer<AssetId, AccountId, Balance> for () {
+	fn check_transfer(_: &AssetId, _: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Handler for the dust removed from accounts, which reports what it did with it.
+pub trait HandleDust<AccountId, F: fungibles::Balanced<AccountId>> {
+	/// Take the `dust`, returning the account it was credited to, or `None` if it was burned.
+	fn handle_dust(dust: fungibles::Credit<AccountId, F>) -> Option<AccountId>;
+}
+
+/// Burn the dust.
+impl<AccountId, F: fungibles::Balanced<AccountId>> HandleDust<AccountId, F> for () {
+	fn handle_dust(_: fungibles::Credit<AccountId, F>) -> Option<AccountId> {
+		None
+	}
+}
+
+/// Policy deciding which names and symbols assets may be given.
+pub trait MetadataPolicy {
+	/// Whether `name` and `symbol` may be set as the name and symbol of an asset.
+	fn is_valid(name: &[u8], symbol: &[u8]) -> bool;
+}
+
+/// Accept any bytes.
+impl MetadataPolicy for () {
+	fn is_valid(_: &[u8], _: &[u8]) -> bool {
+		true
+	}
+}
+
+/// Deposits reserved for the storage used by an asset class, its accounts and approvals.
+///
+/// Accounts and approvals are priced by the [`StorageFootprint`] of what is stored for them, and
+/// the deposit reserved is recorded alongside, so that exactly that much is returned.
+pub trait DepositPolicy<AssetId, Balance> {
+	/// The deposit reserved from the creator of the asset `id`.
+	fn asset_deposit(id: &AssetId) -> Balance;
+
+	/// The deposit reserved for an account of the asset `id` which isn't provided for otherwise,
+	/// and takes up `footprint` in storage.
+	fn account_deposit(id: &AssetId, footprint: StorageFootprint) -> Balance;
+
+	/// The deposit reserved for `bytes` of metadata of the asset `id`.
+	fn metadata_deposit(id: &AssetId, bytes: u32) -> Balance;
+
+	/// The most reserved for `bytes` of metadata of any asset. This is the deposit reported through
+	/// `fungibles::metadata::MetadataDeposit`, whose callers don't say which asset it is for.
+	fn max_metadata_deposit(bytes: u32) -> Balance;
+
+	/// The deposit reserved from the owner of an approval to spend the asset `id`, which takes up
+	/// `footprint` in storage.
+	fn approval_deposit(id: &AssetId, footprint: StorageFootprint) -> Balance;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{AccountTouch, ContainsPair},
+		traits::{AccountTouch, ConstBool, Contains, ContainsPair},
 	};
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
+	/// Creates the asset ids the benchmarks work with, for runtimes whose `AssetIdParameter` is
+	/// not a small integer.
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<AssetIdParameter> {
+		/// Returns the `id`-th asset id parameter; distinct `id`s must give distinct asset ids.
 		fn create_asset_id_parameter(id: u32) -> AssetIdParameter;
 	}
+	/// Uses `id` itself, for any `AssetIdParameter` which can be built from a `u32`.
 	#[cfg(feature = "runtime-benchmarks")]
 	impl<AssetIdParameter: From<u32>> BenchmarkHelper<AssetIdParameter> for () {
 		fn create_asset_id_parameter(id: u32) -> AssetIdParameter {
@@ -243,11 +345,31 @@ pub mod pallet {
 			+ MaxEncodedLen
 			+ TypeInfo;
 
-		/// Max number of items to destroy per `destroy_accounts` and `destroy_approvals` call.
+		/// The hasher of the asset id in the keys of every storage item keyed by asset.
 		///
-		/// Must be configured to result in a weight that makes each call fit in a block.
+		/// `Blake2_128Concat` is always safe. `Twox64Concat` gives shorter keys and so smaller
+		/// proofs, and is cheaper to compute, but must only be used if asset ids cannot be chosen
+		/// to unbalance the storage trie, for instance because only governance may pick them.
+		/// Changing it on a live chain requires `migration::asset_id_hasher::Migrate`.
+		type AssetIdHasher: StorageHasher + ReversibleStorageHasher;
+
+		/// Max number of accounts to destroy per `destroy_accounts` call, however many the caller
+		/// asks for.
+		///
+		/// Must be configured to result in a weight that makes each call fit in a block. Together
+		/// with `RemoveApprovalsLimit`, this replaces the former `RemoveItemsLimit`, whose value
+		/// both can be set to.
+		#[pallet::constant]
+		type RemoveAccountsLimit: Get<u32>;
+
+		/// Max number of approvals to destroy per `destroy_approvals` call, however many the caller
+		/// asks for.
+		///
+		/// Must be configured to result in a weight that makes each call fit in a block. An
+		/// approval is cheaper to remove than an account, so this may be larger than
+		/// `RemoveAccountsLimit`.
 		#[pallet::constant]
-		type RemoveItemsLimit: Get<u32>;
+		type RemoveApprovalsLimit: Get<u32>;
 
 		/// Identifier for the class of asset.
 		type AssetId: Member + Parameter + Clone + MaybeSerializeDeserialize + MaxEncodedLen;
@@ -280,6 +402,19 @@ pub mod pallet {
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which may act in a role of an asset, on top of the account holding it. Such
+		/// an origin acts as if signed by that account, e.g. a collective acting as the Admin of
+		/// some assets. Use `NeverEnsureOrigin` to only let the accounts act.
+		///
+		/// `touch_other` and `refund_other` take and return deposits of the signer, so they are
+		/// only open to the accounts.
+		type RoleOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, (Self::AssetId, Role)>;
+
+		/// Asset ids which `create` refuses, whatever the origin, such as those of system or
+		/// bridged assets. They can still be created with `force_create` or through
+		/// `fungibles::Create`.
+		type ReservedAssetIds: Contains<Self::AssetId>;
+
 		/// The basic amount of funds that must be reserved for an asset.
 		#[pallet::constant]
 		type AssetDeposit: Get<DepositBalanceOf<Self, I>>;
@@ -298,24 +433,104 @@ pub mod pallet {
 		#[pallet::constant]
 		type MetadataDepositPerByte: Get<DepositBalanceOf<Self, I>>;
 
-		/// The amount of funds that must be reserved when creating a new approval.
+		/// The deposits actually reserved for each asset class. [`ConfiguredDeposits`] reserves the
+		/// amounts configured here for every asset class, while [`FootprintDeposits`] prices
+		/// accounts and approvals by their size instead.
+		///
+		/// The amounts reserved are recorded, so changing the policy doesn't affect refunds.
+		type DepositPolicy: DepositPolicy<Self::AssetId, DepositBalanceOf<Self, I>>;
+
+		/// The amount of funds that must be reserved when creating a new approval, as reserved by
+		/// [`ConfiguredDeposits`].
+		///
+		/// May be zero, in which case approvals neither reserve nor unreserve any funds.
 		#[pallet::constant]
 		type ApprovalDeposit: Get<DepositBalanceOf<Self, I>>;
 
+		/// The maximum number of approvals an account may have outstanding for one asset class.
+		#[pallet::constant]
+		type MaxApprovalsPerOwner: Get<u32>;
+
+		/// The maximum number of approvals an account may have been given, across all asset
+		/// classes and owners.
+		#[pallet::constant]
+		type MaxApprovalsPerDelegate: Get<u32>;
+
+		/// The maximum number of asset classes an account may own, or `0` for no limit.
+		///
+		/// Only `create` is limited: assets created by `ForceOrigin`, or given to an account by
+		/// `transfer_ownership` or `force_asset_status`, may exceed it.
+		#[pallet::constant]
+		type MaxOwnedAssets: Get<u32>;
+
+		/// The maximum number of assets an account may hold, or `0` for no limit.
+		///
+		/// Only new accounts created on behalf of the holder, by a `touch` or a transfer in, are
+		/// limited: mints by the issuer, forced transfers, and accounts created by other pallets
+		/// through `AccountTouch` or `StoredMap` may exceed it.
+		#[pallet::constant]
+		type MaxHoldingsPerAccount: Get<u32>;
+
 		/// The maximum length of a name or symbol stored on-chain.
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
 
+		/// The names and symbols `set_metadata` and `force_set_metadata` accept.
+		/// [`ReadableMetadata`] only accepts readable text, while `()` accepts any bytes.
+		type MetadataPolicy: MetadataPolicy;
+
 		/// A hook to allow a per-asset, per-account minimum balance to be enforced. This must be
 		/// respected in all permissionless operations.
+		///
+		/// The weights only account for the storage the `Freezer` of the benchmarked runtime
+		/// reads. A freezer backed by storage should be benchmarked as a [`BenchmarkFreezer`]
+		/// reading at least as many items.
 		type Freezer: FrozenBalance<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The maximum number of holds that can exist on an asset account at any time.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// The identifier of a named freeze on an asset account.
+		type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The maximum number of named freezes that can exist on an asset account at any time.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
+		/// Who pays the account deposit when an asset account is created through the
+		/// `StoredMap` implementation in order to hold `Extra` data. If it returns `None`, as
+		/// `()` does, such accounts cannot be created that way.
+		type ExtraDepositor: Convert<(Self::AssetId, Self::AccountId), Option<Self::AccountId>>;
+
 		/// Callback methods for asset state change (e.g. asset created or destroyed)
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// Policy which may veto transfers between accounts. Transfers forced by the asset's Admin
+		/// are not subject to it, and neither are mints and burns.
+		type TransferFilter: CheckTransfer<Self::AssetId, Self::AccountId, Self::Balance>;
+
+		/// Handler for the dust removed from accounts: the remainder below `min_balance` left by a
+		/// burn or a dust-burning transfer, and the balance discarded by `refund` with
+		/// `allow_burn`. `()` burns it, reducing the supply, while [`ResolveDustTo`] credits it to
+		/// an account.
+		type OnDust: HandleDust<Self::AccountId, Pallet<Self, I>>;
+
+		/// Whether to count the transfers, mints and burns of each asset in `AssetStats`. With
+		/// `ConstBool<false>` they cost no extra storage access.
+		#[pallet::constant]
+		type TrackStats: Get<bool>;
+
+		/// Translates the `Account` entries still in their previous encoding during a lazy
+		/// migration of them, see [`migration::lazy`]. `()` when there is none to translate.
+		type AccountMigration: migration::lazy::TranslateAccount<Self, I>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -328,7 +543,7 @@ pub mod pallet {
 	/// Details of an asset.
 	pub(super) type Asset<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
-		Blake2_128Concat,
+		T::AssetIdHasher,
 		T::AssetId,
 		AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
 	>;
@@ -337,7 +552,7 @@ pub mod pallet {
 	/// The holdings of a specific account for a specific asset.
 	pub(super) type Account<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
-		Blake2_128Concat,
+		T::AssetIdHasher,
 		T::AssetId,
 		Blake2_128Concat,
 		T::AccountId,
@@ -351,7 +566,7 @@ pub mod pallet {
 	pub(super) type Approvals<T: Config<I>, I: 'static = ()> = StorageNMap<
 		_,
 		(
-			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<T::AssetIdHasher, T::AssetId>,
 			NMapKey<Blake2_128Concat, T::AccountId>, // owner
 			NMapKey<Blake2_128Concat, T::AccountId>, // delegate
 		),
@@ -359,15 +574,124 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	/// Metadata of an asset.
-	pub(super) type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
+	/// The number of assets in existence, including those being destroyed.
+	pub(super) type AssetCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The number of approvals an owner has outstanding for an asset.
+	/// First key is the asset ID, second key is the owner.
+	pub(super) type ApprovalCount<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		T::AssetIdHasher,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The approvals an account has been given, to find them by their delegate.
+	/// First key is the delegate, second key is the asset ID and the owner.
+	pub(super) type DelegateApprovals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(T::AssetId, T::AccountId),
+		(),
+	>;
+
+	#[pallet::storage]
+	/// The number of approvals an account has been given, across all assets.
+	pub(super) type DelegateApprovalCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The number of assets an account holds, that is of its `Account` entries.
+	pub(super) type HoldingCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The assets an account owns, to find them by their owner.
+	/// First key is the owner, second key is the asset ID.
+	pub(super) type OwnedAssets<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AssetId, ()>;
+
+	#[pallet::storage]
+	/// The number of assets an account owns.
+	pub(super) type OwnedAssetCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Holds on asset account balances. Held funds are not part of `AssetAccount::balance`, but
+	/// still count towards the asset's supply.
+	/// First key is the asset ID, second key is the account.
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		T::AssetIdHasher,
 		T::AssetId,
-		AssetMetadata<DepositBalanceOf<T, I>, BoundedVec<u8, T::StringLimit>>,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::RuntimeHoldReason, T::Balance>, T::MaxHolds>,
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// Named freezes on asset account balances. Together with `T::Freezer`, the largest of these
+	/// is the amount of the balance which cannot be withdrawn.
+	/// First key is the asset ID, second key is the account.
+	pub(super) type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		T::AssetIdHasher,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::FreezeIdentifier, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The maximum number of accounts which may hold an asset, if limited by its owner.
+	pub(super) type MaxHolders<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, T::AssetIdHasher, T::AssetId, u32>;
+
+	#[pallet::storage]
+	/// Whether the Issuer of an asset may mint into frozen accounts. Allowed unless disabled by
+	/// its owner.
+	pub(super) type AllowMintWhenFrozen<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, T::AssetIdHasher, T::AssetId, bool, ValueQuery, ConstBool<true>>;
+
+	#[pallet::storage]
+	/// Whether the Admin of an asset may burn from frozen accounts, and while the asset is frozen.
+	/// Allowed unless disabled by its owner.
+	pub(super) type AllowAdminBurnWhenFrozen<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, T::AssetIdHasher, T::AssetId, bool, ValueQuery, ConstBool<true>>;
+
+	#[pallet::storage]
+	/// Metadata of an asset, if it has any.
+	pub(super) type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		T::AssetIdHasher,
+		T::AssetId,
+		AssetMetadata<DepositBalanceOf<T, I>, BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	/// Counters of the activity of an asset, if `Config::TrackStats` is set.
+	pub(super) type AssetStats<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, T::AssetIdHasher, T::AssetId, AssetStatistics<T::Balance>, ValueQuery>;
+
+	#[pallet::storage]
+	/// How far the ongoing lazy migration of the `Account` entries has got, if there is one.
+	pub(super) type AccountMigrationCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, MigrationCursor>;
+
+	#[pallet::storage]
+	/// How far the ongoing repair of the counters of an asset has got, if there is one.
+	pub(super) type RefRepairs<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, T::AssetIdHasher, T::AssetId, RefRepair>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -375,16 +699,31 @@ pub mod pallet {
 		pub assets: Vec<(T::AssetId, T::AccountId, bool, T::Balance)>,
 		/// Genesis metadata: id, name, symbol, decimals
 		pub metadata: Vec<(T::AssetId, Vec<u8>, Vec<u8>, u8)>,
+		/// Whether the genesis metadata reserves its deposit from the asset owner, as
+		/// `set_metadata` would. Otherwise it is deposit-free, and nothing is returned when it is
+		/// cleared or replaced.
+		pub reserve_metadata_deposits: bool,
 		/// Genesis accounts: id, account_id, balance
 		pub accounts: Vec<(T::AssetId, T::AccountId, T::Balance)>,
+		/// Genesis approvals, taking no deposit: id, owner, delegate, amount
+		pub approvals: Vec<(T::AssetId, T::AccountId, T::AccountId, T::Balance)>,
+		/// Genesis assets to freeze: id
+		pub frozen_assets: Vec<T::AssetId>,
+		/// Genesis accounts to freeze: id, account_id
+		pub frozen_accounts: Vec<(T::AssetId, T::AccountId)>,
 	}
 
 	#[pallet::genesis_build]
 	impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
 		fn build(&self) {
+			let problems = self.validate();
+			assert!(
+				problems.is_empty(),
+				"Invalid assets genesis config:{}",
+				GenesisProblems(&problems)
+			);
+
 			for (id, owner, is_sufficient, min_balance) in &self.assets {
-				assert!(!Asset::<T, I>::contains_key(id), "Asset id already in use");
-				assert!(!min_balance.is_zero(), "Min balance should not be zero");
 				Asset::<T, I>::insert(
 					id,
 					AssetDetails {
@@ -400,20 +739,36 @@ pub mod pallet {
 						sufficients: 0,
 						approvals: 0,
 						status: AssetStatus::Live,
+						metadata_deposit: Zero::zero(),
 					},
 				);
+				AssetCount::<T, I>::mutate(|count| count.saturating_inc());
+				<Pallet<T, I>>::inc_owned_assets(id, owner);
 			}
 
 			for (id, name, symbol, decimals) in &self.metadata {
-				assert!(Asset::<T, I>::contains_key(id), "Asset does not exist");
+				let mut details = Asset::<T, I>::get(id).expect("checked in validate; qed");
 
 				let bounded_name: BoundedVec<u8, T::StringLimit> =
-					name.clone().try_into().expect("asset name is too long");
+					name.clone().try_into().expect("checked in validate; qed");
 				let bounded_symbol: BoundedVec<u8, T::StringLimit> =
-					symbol.clone().try_into().expect("asset symbol is too long");
+					symbol.clone().try_into().expect("checked in validate; qed");
+
+				let deposit = if self.reserve_metadata_deposits {
+					let deposit = <Pallet<T, I>>::calc_metadata_deposit(id, name, symbol);
+					assert!(
+						T::Currency::reserve(&details.owner, deposit).is_ok(),
+						"Asset owner cannot reserve the metadata deposit"
+					);
+					details.metadata_deposit = deposit;
+					Asset::<T, I>::insert(id, details);
+					deposit
+				} else {
+					Zero::zero()
+				};
 
 				let metadata = AssetMetadata {
-					deposit: Zero::zero(),
+					deposit,
 					name: bounded_name,
 					symbol: bounded_symbol,
 					decimals: *decimals,
@@ -430,22 +785,161 @@ pub mod pallet {
 					|details| -> DispatchResult {
 						debug_assert!(
 							details.supply.checked_add(&amount).is_some(),
-							"checked in prep; qed"
+							"checked in validate; qed"
 						);
 						details.supply = details.supply.saturating_add(*amount);
 						Ok(())
 					},
 				);
-				assert!(result.is_ok());
+				assert!(result.is_ok(), "Genesis account cannot be created");
+			}
+
+			for (id, owner, delegate, amount) in &self.approvals {
+				Approvals::<T, I>::insert(
+					(id, owner, delegate),
+					Approval { amount: *amount, deposit: Zero::zero() },
+				);
+				Asset::<T, I>::mutate(id, |maybe_details| {
+					if let Some(details) = maybe_details {
+						details.approvals.saturating_inc();
+					}
+				});
+				<Pallet<T, I>>::inc_approval_count(id, owner, delegate);
+			}
+
+			for id in &self.frozen_assets {
+				Asset::<T, I>::mutate(id, |maybe_details| {
+					if let Some(details) = maybe_details {
+						details.status = AssetStatus::Frozen;
+					}
+				});
+			}
+
+			for (id, account_id) in &self.frozen_accounts {
+				Account::<T, I>::mutate(id, account_id, |maybe_account| {
+					if let Some(account) = maybe_account {
+						account.status = AccountStatus::Frozen;
+					}
+				});
 			}
 		}
 	}
 
+	impl<T: Config<I>, I: 'static> GenesisConfig<T, I> {
+		/// Check the whole config before anything is built, returning every problem found.
+		///
+		/// Each account may only be listed once per asset: duplicates are rejected rather than
+		/// merged, as are approvals listed twice.
+		pub(crate) fn validate(&self) -> Vec<GenesisProblem> {
+			use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+			let mut problems = Vec::new();
+			let mut report = |list, index, problem| {
+				problems.push(GenesisProblem { list, index, problem });
+			};
+
+			// The min balance and the supply of each asset, by encoded id.
+			let mut assets = BTreeMap::<Vec<u8>, (T::Balance, T::Balance)>::new();
+			for (index, (id, _, _, min_balance)) in self.assets.iter().enumerate() {
+				if assets.contains_key(&id.encode()) {
+					report("assets", index, "the asset id is already in use");
+				} else {
+					assets.insert(id.encode(), (*min_balance, Zero::zero()));
+				}
+				if min_balance.is_zero() {
+					report("assets", index, "the min balance is zero");
+				}
+			}
+
+			let mut with_metadata = BTreeSet::new();
+			let limit = T::StringLimit::get() as usize;
+			for (index, (id, name, symbol, _)) in self.metadata.iter().enumerate() {
+				if !assets.contains_key(&id.encode()) {
+					report("metadata", index, "the asset does not exist");
+				}
+				if !with_metadata.insert(id.encode()) {
+					report("metadata", index, "the asset already has metadata");
+				}
+				if name.len() > limit {
+					report("metadata", index, "the name is too long");
+				}
+				if symbol.len() > limit {
+					report("metadata", index, "the symbol is too long");
+				}
+				if !T::MetadataPolicy::is_valid(name, symbol) {
+					report("metadata", index, "the name or symbol is not allowed");
+				}
+			}
+
+			let mut accounts = BTreeSet::new();
+			for (index, (id, who, amount)) in self.accounts.iter().enumerate() {
+				if !accounts.insert((id, who).encode()) {
+					report("accounts", index, "the account is already listed for the asset");
+				}
+				let Some((min_balance, supply)) = assets.get_mut(&id.encode()) else {
+					report("accounts", index, "the asset does not exist");
+					continue
+				};
+				if amount < min_balance {
+					report("accounts", index, "the balance is below the min balance");
+				}
+				match supply.checked_add(amount) {
+					Some(new_supply) => *supply = new_supply,
+					None => report("accounts", index, "the supply of the asset overflows"),
+				}
+			}
+
+			let mut approvals = BTreeSet::new();
+			let mut per_owner = BTreeMap::<Vec<u8>, u32>::new();
+			let mut per_delegate = BTreeMap::<Vec<u8>, u32>::new();
+			for (index, (id, owner, delegate, _)) in self.approvals.iter().enumerate() {
+				if !assets.contains_key(&id.encode()) {
+					report("approvals", index, "the asset does not exist");
+				} else if !accounts.contains(&(id, owner).encode()) {
+					report("approvals", index, "the owner has no account of the asset");
+				}
+				if !approvals.insert((id, owner, delegate).encode()) {
+					report("approvals", index, "the approval is already listed");
+					continue
+				}
+				let owner_count = per_owner.entry((id, owner).encode()).or_default();
+				owner_count.saturating_inc();
+				if *owner_count > T::MaxApprovalsPerOwner::get() {
+					report("approvals", index, "the owner has too many approvals");
+				}
+				let delegate_count = per_delegate.entry(delegate.encode()).or_default();
+				delegate_count.saturating_inc();
+				if *delegate_count > T::MaxApprovalsPerDelegate::get() {
+					report("approvals", index, "the delegate has too many approvals");
+				}
+			}
+
+			for (index, id) in self.frozen_assets.iter().enumerate() {
+				if !assets.contains_key(&id.encode()) {
+					report("frozen_assets", index, "the asset does not exist");
+				}
+			}
+
+			for (index, (id, who)) in self.frozen_accounts.iter().enumerate() {
+				if !accounts.contains(&(id, who).encode()) {
+					report("frozen_accounts", index, "the account does not exist");
+				}
+			}
+
+			problems
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
-		/// Some asset class was created.
-		Created { asset_id: T::AssetId, creator: T::AccountId, owner: T::AccountId },
+		/// Some asset class was created. `deposit` is the amount reserved from `creator`.
+		Created {
+			asset_id: T::AssetId,
+			creator: T::AccountId,
+			owner: T::AccountId,
+			deposit: DepositBalanceOf<T, I>,
+		},
 		/// Some assets were issued.
 		Issued { asset_id: T::AssetId, owner: T::AccountId, amount: T::Balance },
 		/// Some assets were transferred.
@@ -457,6 +951,17 @@ pub mod pallet {
 		},
 		/// Some assets were destroyed.
 		Burned { asset_id: T::AssetId, owner: T::AccountId, balance: T::Balance },
+		/// The sub-minimum remainder of `who`'s balance was removed while debiting it, and burned
+		/// by `OnDust`.
+		DustBurned { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
+		/// The sub-minimum remainder of `who`'s balance was removed while debiting it, and
+		/// credited to `to` by `OnDust`.
+		DustCredited {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
 		/// The management team changed.
 		TeamChanged {
 			asset_id: T::AssetId,
@@ -474,47 +979,70 @@ pub mod pallet {
 		AssetFrozen { asset_id: T::AssetId },
 		/// Some asset `asset_id` was thawed.
 		AssetThawed { asset_id: T::AssetId },
-		/// Accounts were destroyed for given asset.
-		AccountsDestroyed { asset_id: T::AssetId, accounts_destroyed: u32, accounts_remaining: u32 },
-		/// Approvals were destroyed for given asset.
+		/// Accounts were destroyed for given asset. `deposit_returned` is the total amount of
+		/// account deposits unreserved in this batch.
+		AccountsDestroyed {
+			asset_id: T::AssetId,
+			accounts_destroyed: u32,
+			accounts_remaining: u32,
+			deposit_returned: DepositBalanceOf<T, I>,
+		},
+		/// Approvals were destroyed for given asset. `deposit_returned` is the total amount of
+		/// approval deposits unreserved in this batch.
 		ApprovalsDestroyed {
 			asset_id: T::AssetId,
 			approvals_destroyed: u32,
 			approvals_remaining: u32,
+			deposit_returned: DepositBalanceOf<T, I>,
 		},
 		/// An asset class is in the process of being destroyed.
 		DestructionStarted { asset_id: T::AssetId },
-		/// An asset class was destroyed.
-		Destroyed { asset_id: T::AssetId },
-		/// Some asset class was force-created.
-		ForceCreated { asset_id: T::AssetId, owner: T::AccountId },
+		/// An asset class was destroyed. `deposit_returned` is the asset and metadata deposit
+		/// unreserved to `owner`.
+		Destroyed {
+			asset_id: T::AssetId,
+			owner: T::AccountId,
+			deposit_returned: DepositBalanceOf<T, I>,
+		},
+		/// Some asset class was force-created. `deposit` is the amount reserved for it, which is
+		/// always zero for force-created assets.
+		ForceCreated { asset_id: T::AssetId, owner: T::AccountId, deposit: DepositBalanceOf<T, I> },
 		/// New metadata has been set for an asset.
 		MetadataSet {
 			asset_id: T::AssetId,
-			name: Vec<u8>,
-			symbol: Vec<u8>,
+			name: BoundedVec<u8, T::StringLimit>,
+			symbol: BoundedVec<u8, T::StringLimit>,
 			decimals: u8,
 			is_frozen: bool,
 		},
 		/// Metadata has been cleared for an asset.
 		MetadataCleared { asset_id: T::AssetId },
-		/// (Additional) funds have been approved for transfer to a destination account.
+		/// (Additional) funds have been approved for transfer to a destination account. `deposit`
+		/// is the amount of `T::Currency` additionally reserved from `source` by this approval.
 		ApprovedTransfer {
 			asset_id: T::AssetId,
 			source: T::AccountId,
 			delegate: T::AccountId,
 			amount: T::Balance,
+			deposit: DepositBalanceOf<T, I>,
+		},
+		/// An approval for account `delegate` was cancelled by `owner`, or renounced by the
+		/// `delegate` itself if `by_delegate`.
+		ApprovalCancelled {
+			asset_id: T::AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			by_delegate: bool,
 		},
-		/// An approval for account `delegate` was cancelled by `owner`.
-		ApprovalCancelled { asset_id: T::AssetId, owner: T::AccountId, delegate: T::AccountId },
 		/// An `amount` was transferred in its entirety from `owner` to `destination` by
-		/// the approved `delegate`.
+		/// the approved `delegate`, who may still transfer up to `remaining`.
 		TransferredApproved {
 			asset_id: T::AssetId,
 			owner: T::AccountId,
 			delegate: T::AccountId,
 			destination: T::AccountId,
 			amount: T::Balance,
+			remaining: T::Balance,
 		},
 		/// An asset has had its attributes changed by the `Force` origin.
 		AssetStatusChanged { asset_id: T::AssetId },
@@ -524,6 +1052,61 @@ pub mod pallet {
 		Touched { asset_id: T::AssetId, who: T::AccountId, depositor: T::AccountId },
 		/// Some account `who` was blocked.
 		Blocked { asset_id: T::AssetId, who: T::AccountId },
+		/// The maximum number of holders of an asset has been updated by the asset owner.
+		MaxHoldersSet { asset_id: T::AssetId, max_holders: Option<u32> },
+		/// Whether the Issuer may mint into frozen accounts has been updated by the asset owner.
+		AllowMintWhenFrozenSet { asset_id: T::AssetId, allow: bool },
+		/// Whether the Admin may burn frozen funds has been updated by the asset owner.
+		AllowAdminBurnWhenFrozenSet { asset_id: T::AssetId, allow: bool },
+		/// The deposit of `kind` held from `who` for an asset changed from `old` to `new`.
+		DepositUpdated {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			kind: DepositKind,
+			old: DepositBalanceOf<T, I>,
+			new: DepositBalanceOf<T, I>,
+		},
+		/// Some balance was placed on hold.
+		Held {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// Some held balance was released.
+		Released {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// Some held balance was burned.
+		BurnedHeld {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// Some held balance was slashed.
+		SlashedHeld {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// All the `Account` entries are in the current encoding, the lazy migration is over.
+		AccountMigrationCompleted,
+		/// `visited` more accounts of an asset were counted by `repair_refs`, which restored
+		/// `restored` references of theirs on the system. `accounts` and `sufficients` are the
+		/// totals counted so far, and the counters of the asset were set to them if `finished`.
+		RefsRepaired {
+			asset_id: T::AssetId,
+			visited: u32,
+			restored: u32,
+			accounts: u32,
+			sufficients: u32,
+			finished: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -536,7 +1119,7 @@ pub mod pallet {
 		NoPermission,
 		/// The given asset ID is unknown.
 		Unknown,
-		/// The origin account is frozen.
+		/// The origin account, or the account to mint into, is frozen.
 		Frozen,
 		/// The asset ID is already taken.
 		InUse,
@@ -571,6 +1154,50 @@ pub mod pallet {
 		NotFrozen,
 		/// Callback action resulted in error
 		CallbackFailed,
+		/// The amount given to a transfer, mint, burn or approval is zero.
+		AmountZero,
+		/// The source and destination of a transfer are the same account.
+		SelfTransfer,
+		/// The owner already has the maximum number of approvals for this asset.
+		TooManyApprovals,
+		/// The asset already has the maximum number of holders set by its owner.
+		TooManyHolders,
+		/// The asset still has accounts which must be destroyed with `destroy_accounts` first.
+		AccountsRemaining,
+		/// The asset still has approvals which must be destroyed with `destroy_approvals` first.
+		ApprovalsRemaining,
+		/// The account already has the maximum number of holds.
+		TooManyHolds,
+		/// The account has some balance on hold and cannot be removed.
+		ContainsHolds,
+		/// The account already has the maximum number of freezes.
+		TooManyFreezes,
+		/// The account has some named freezes and cannot be removed.
+		ContainsFreezes,
+		/// The delegate has already been given the maximum number of approvals.
+		TooManyDelegateApprovals,
+		/// There is no lazy migration of the `Account` entries to continue.
+		NoMigrationOngoing,
+		/// The account already holds the maximum number of assets.
+		TooManyHoldings,
+		/// The asset id is reserved and cannot be used by `create`.
+		IdReserved,
+		/// The asset has no metadata.
+		NoMetadata,
+		/// The account already owns the maximum number of assets.
+		TooManyOwnedAssets,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::migrate_accounts_within(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -585,12 +1212,14 @@ pub mod pallet {
 		///
 		/// Parameters:
 		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
-		/// an existing asset.
+		/// an existing asset, nor be one of the `ReservedAssetIds`.
 		/// - `admin`: The admin of this class of assets. The admin is the initial address of each
 		/// member of the asset class's admin team.
 		/// - `min_balance`: The minimum balance of this new asset that any single account must
 		/// have. If an account's balance is reduced below this, then it collapses to zero.
 		///
+		/// Fails with `TooManyOwnedAssets` if the sender owns `MaxOwnedAssets` assets already.
+		///
 		/// Emits `Created` event when successful.
 		///
 		/// Weight: `O(1)`
@@ -605,11 +1234,13 @@ pub mod pallet {
 			let owner = T::CreateOrigin::ensure_origin(origin, &id)?;
 			let admin = T::Lookup::lookup(admin)?;
 
+			ensure!(!T::ReservedAssetIds::contains(&id), Error::<T, I>::IdReserved);
 			ensure!(!Asset::<T, I>::contains_key(&id), Error::<T, I>::InUse);
 			ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+			Self::ensure_can_own_asset(&owner)?;
 
-			let deposit = T::AssetDeposit::get();
-			T::Currency::reserve(&owner, deposit)?;
+			let deposit = T::DepositPolicy::asset_deposit(&id);
+			Self::update_deposit(&id, &owner, DepositKind::Asset, Zero::zero(), deposit)?;
 
 			Asset::<T, I>::insert(
 				id.clone(),
@@ -626,13 +1257,17 @@ pub mod pallet {
 					sufficients: 0,
 					approvals: 0,
 					status: AssetStatus::Live,
+					metadata_deposit: Zero::zero(),
 				},
 			);
+			AssetCount::<T, I>::mutate(|count| count.saturating_inc());
+			Self::inc_owned_assets(&id, &owner);
 			ensure!(T::CallbackHandle::created(&id, &owner).is_ok(), Error::<T, I>::CallbackFailed);
 			Self::deposit_event(Event::Created {
 				asset_id: id,
 				creator: owner.clone(),
 				owner: admin,
+				deposit,
 			});
 
 			Ok(())
@@ -681,14 +1316,15 @@ pub mod pallet {
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
 		///
-		/// The asset class must be frozen before calling `start_destroy`.
+		/// The asset class must be `Live` or `Frozen`; an asset class which is already being
+		/// destroyed cannot be moved back to either state.
 		#[pallet::call_index(2)]
 		pub fn start_destroy(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			let id: T::AssetId = id.into();
 			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
 				Ok(_) => None,
-				Err(origin) => Some(ensure_signed(origin)?),
+				Err(origin) => Some(Self::ensure_role(origin, &id, Role::Owner)?),
 			};
-			let id: T::AssetId = id.into();
 			Self::do_start_destroy(id, maybe_check_owner)
 		}
 
@@ -698,53 +1334,72 @@ pub mod pallet {
 		/// asset is in a `Destroying` state.
 		///
 		/// Due to weight restrictions, this function may need to be called multiple times to fully
-		/// destroy all accounts. It will destroy `RemoveItemsLimit` accounts at a time.
+		/// destroy all accounts. It will destroy at most `max_items` accounts at a time, and never
+		/// more than `RemoveAccountsLimit`.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
+		/// - `max_items`: The most accounts to destroy in this call, which is weighed for as many.
+		///   Zero destroys nothing, but still checks that the asset is being destroyed and reports
+		///   how many accounts remain.
 		///
 		/// Each call emits the `Event::DestroyedAccounts` event.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		#[pallet::weight(T::WeightInfo::destroy_accounts(
+			(*max_items).min(T::RemoveAccountsLimit::get())
+		))]
 		pub fn destroy_accounts(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
+			max_items: u32,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
-			let removed_accounts = Self::do_destroy_accounts(id, T::RemoveItemsLimit::get())?;
+			let max_items = max_items.min(T::RemoveAccountsLimit::get());
+			let removed_accounts = Self::do_destroy_accounts(id, max_items)?;
 			Ok(Some(T::WeightInfo::destroy_accounts(removed_accounts)).into())
 		}
 
-		/// Destroy all approvals associated with a given asset up to the max (T::RemoveItemsLimit).
+		/// Destroy all approvals associated with a given asset up to the max
+		/// (T::RemoveApprovalsLimit).
 		///
 		/// `destroy_approvals` should only be called after `start_destroy` has been called, and the
 		/// asset is in a `Destroying` state.
 		///
 		/// Due to weight restrictions, this function may need to be called multiple times to fully
-		/// destroy all approvals. It will destroy `RemoveItemsLimit` approvals at a time.
+		/// destroy all approvals. It will destroy at most `max_items` approvals at a time, and
+		/// never more than `RemoveApprovalsLimit`.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
+		/// - `max_items`: The most approvals to destroy in this call, which is weighed for as many.
+		///   Zero destroys nothing, but still checks that the asset is being destroyed and reports
+		///   how many approvals remain.
 		///
 		/// Each call emits the `Event::DestroyedApprovals` event.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::destroy_approvals(T::RemoveItemsLimit::get()))]
+		#[pallet::weight(T::WeightInfo::destroy_approvals(
+			(*max_items).min(T::RemoveApprovalsLimit::get())
+		))]
 		pub fn destroy_approvals(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
+			max_items: u32,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
-			let removed_approvals = Self::do_destroy_approvals(id, T::RemoveItemsLimit::get())?;
+			let max_items = max_items.min(T::RemoveApprovalsLimit::get());
+			let removed_approvals = Self::do_destroy_approvals(id, max_items)?;
 			Ok(Some(T::WeightInfo::destroy_approvals(removed_approvals)).into())
 		}
 
 		/// Complete destroying asset and unreserve currency.
 		///
 		/// `finish_destroy` should only be called after `start_destroy` has been called, and the
-		/// asset is in a `Destroying` state. All accounts or approvals should be destroyed before
-		/// hand.
+		/// asset is in a `Destroying` state, otherwise it fails with `IncorrectStatus`. All
+		/// accounts and approvals should be destroyed before hand, otherwise it fails with
+		/// `AccountsRemaining` or `ApprovalsRemaining` respectively. Any metadata which was not
+		/// removed by `destroy_metadata` yet is removed as well.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
@@ -763,7 +1418,11 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to have some amount minted.
 		/// - `beneficiary`: The account to be credited with the minted assets.
-		/// - `amount`: The amount of the asset to be minted.
+		/// - `amount`: The amount of the asset to be minted. Must be greater than zero.
+		///
+		/// Fails with `Frozen` if `beneficiary` is frozen and the Owner has disallowed minting into
+		/// frozen accounts with `set_allow_mint_when_frozen`. Nothing can be minted while the whole
+		/// asset is frozen.
 		///
 		/// Emits `Issued` event when successful.
 		///
@@ -775,12 +1434,14 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			beneficiary: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let beneficiary = T::Lookup::lookup(beneficiary)?;
+		) -> DispatchResultWithPostInfo {
 			let id: T::AssetId = id.into();
-			Self::do_mint(id, &beneficiary, amount, Some(origin))?;
-			Ok(())
+			let origin = Self::ensure_role(origin, &id, Role::Issuer)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::AmountZero.with_weight(Weight::zero()));
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_mint(id, &beneficiary, amount, Some(origin))
+				.map_err(Self::with_early_exit_weight)?;
+			Ok(().into())
 		}
 
 		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
@@ -791,11 +1452,25 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to have some amount burned.
 		/// - `who`: The account to be debited from.
-		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		/// - `amount`: The maximum amount by which `who`'s balance should be reduced. Must be
+		/// greater than zero.
 		///
 		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
 		/// minimum for the asset, then the amount burned is increased to take it to zero.
 		///
+		/// Freezes don't stop the Admin from burning, unless the Owner has required them to be
+		/// thawed first with `set_allow_admin_burn_when_frozen`:
+		///
+		/// | `who` frozen | asset frozen | allowed (default) | disallowed     |
+		/// |--------------|--------------|-------------------|----------------|
+		/// | no           | no           | burns             | burns          |
+		/// | yes          | no           | burns             | `Frozen`       |
+		/// | no           | yes          | burns             | `AssetNotLive` |
+		/// | yes          | yes          | burns             | `AssetNotLive` |
+		///
+		/// Blocked accounts count as frozen. Funds frozen by `T::Freezer` are never burned, and
+		/// burns through `fungibles::Mutate` always respect every freeze.
+		///
 		/// Weight: `O(1)`
 		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
 		#[pallet::call_index(7)]
@@ -804,14 +1479,16 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			who: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let who = T::Lookup::lookup(who)?;
+		) -> DispatchResultWithPostInfo {
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Admin)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::AmountZero.with_weight(Weight::zero()));
+			let who = T::Lookup::lookup(who)?;
 
 			let f = DebitFlags { keep_alive: false, best_effort: true };
-			let _ = Self::do_burn(id, &who, amount, Some(origin), f)?;
-			Ok(())
+			let _ = Self::do_burn(id, &who, amount, Some(origin), f)
+				.map_err(Self::with_early_exit_weight)?;
+			Ok(().into())
 		}
 
 		/// Move some assets from the sender account to another.
@@ -819,7 +1496,7 @@ pub mod pallet {
 		/// Origin must be Signed.
 		///
 		/// - `id`: The identifier of the asset to have some amount transferred.
-		/// - `target`: The account to be credited.
+		/// - `target`: The account to be credited. Must not be the sender.
 		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
 		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
 		/// the case that the transfer would otherwise take the sender balance above zero but below
@@ -838,13 +1515,21 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			target: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::AmountZero.with_weight(Weight::zero()));
 			let dest = T::Lookup::lookup(target)?;
 			let id: T::AssetId = id.into();
 
-			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &origin, &dest, amount, None, f).map(|_| ())
+			let f = TransferFlags {
+				keep_alive: false,
+				best_effort: false,
+				burn_dust: false,
+				bypass_filter: false,
+			};
+			Self::do_transfer(id, &origin, &dest, amount, None, f)
+				.map_err(Self::with_early_exit_weight)?;
+			Ok(().into())
 		}
 
 		/// Move some assets from the sender account to another, keeping the sender account alive.
@@ -852,7 +1537,7 @@ pub mod pallet {
 		/// Origin must be Signed.
 		///
 		/// - `id`: The identifier of the asset to have some amount transferred.
-		/// - `target`: The account to be credited.
+		/// - `target`: The account to be credited. Must not be the sender.
 		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
 		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
 		/// the case that the transfer would otherwise take the sender balance above zero but below
@@ -871,13 +1556,21 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			target: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let source = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::AmountZero.with_weight(Weight::zero()));
 			let dest = T::Lookup::lookup(target)?;
 			let id: T::AssetId = id.into();
 
-			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &source, &dest, amount, None, f).map(|_| ())
+			let f = TransferFlags {
+				keep_alive: true,
+				best_effort: false,
+				burn_dust: false,
+				bypass_filter: false,
+			};
+			Self::do_transfer(id, &source, &dest, amount, None, f)
+				.map_err(Self::with_early_exit_weight)?;
+			Ok(().into())
 		}
 
 		/// Move some assets from one account to another.
@@ -886,7 +1579,7 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to have some amount transferred.
 		/// - `source`: The account to be debited.
-		/// - `dest`: The account to be credited.
+		/// - `dest`: The account to be credited. Must not be `source`.
 		/// - `amount`: The amount by which the `source`'s balance of assets should be reduced and
 		/// `dest`'s balance increased. The amount actually transferred may be slightly greater in
 		/// the case that the transfer would otherwise take the `source` balance above zero but
@@ -906,14 +1599,22 @@ pub mod pallet {
 			source: AccountIdLookupOf<T>,
 			dest: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
+		) -> DispatchResultWithPostInfo {
+			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Admin)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::AmountZero.with_weight(Weight::zero()));
 			let source = T::Lookup::lookup(source)?;
 			let dest = T::Lookup::lookup(dest)?;
-			let id: T::AssetId = id.into();
 
-			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &source, &dest, amount, Some(origin), f).map(|_| ())
+			let f = TransferFlags {
+				keep_alive: false,
+				best_effort: false,
+				burn_dust: false,
+				bypass_filter: true,
+			};
+			Self::do_transfer(id, &source, &dest, amount, Some(origin), f)
+				.map_err(Self::with_early_exit_weight)?;
+			Ok(().into())
 		}
 
 		/// Disallow further unprivileged transfers of an asset `id` from an account `who`. `who`
@@ -934,8 +1635,8 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			who: AccountIdLookupOf<T>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Freezer)?;
 
 			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			ensure!(
@@ -945,6 +1646,7 @@ pub mod pallet {
 			ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
 			let who = T::Lookup::lookup(who)?;
 
+			Self::migrate_account(&id, &who);
 			Account::<T, I>::try_mutate(&id, &who, |maybe_account| -> DispatchResult {
 				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.status =
 					AccountStatus::Frozen;
@@ -971,8 +1673,8 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			who: AccountIdLookupOf<T>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Admin)?;
 
 			let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			ensure!(
@@ -982,6 +1684,7 @@ pub mod pallet {
 			ensure!(origin == details.admin, Error::<T, I>::NoPermission);
 			let who = T::Lookup::lookup(who)?;
 
+			Self::migrate_account(&id, &who);
 			Account::<T, I>::try_mutate(&id, &who, |maybe_account| -> DispatchResult {
 				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.status =
 					AccountStatus::Liquid;
@@ -1003,8 +1706,8 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		#[pallet::call_index(13)]
 		pub fn freeze_asset(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Freezer)?;
 
 			Asset::<T, I>::try_mutate(id.clone(), |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
@@ -1029,12 +1732,13 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		#[pallet::call_index(14)]
 		pub fn thaw_asset(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Admin)?;
 
 			Asset::<T, I>::try_mutate(id.clone(), |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+				ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 				ensure!(d.status == AssetStatus::Frozen, Error::<T, I>::NotFrozen);
 
 				d.status = AssetStatus::Live;
@@ -1051,7 +1755,10 @@ pub mod pallet {
 		/// - `id`: The identifier of the asset.
 		/// - `owner`: The new Owner of this asset.
 		///
-		/// Emits `OwnerChanged`.
+		/// If the current Owner has less reserved than its recorded deposits, only what is
+		/// reserved is moved and the deposits are updated to match.
+		///
+		/// Emits `OwnerChanged`, and `DepositUpdated` for each deposit moved.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(15)]
@@ -1060,9 +1767,9 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			owner: AccountIdLookupOf<T>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let owner = T::Lookup::lookup(owner)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
+			let owner = T::Lookup::lookup(owner)?;
 
 			Asset::<T, I>::try_mutate(id.clone(), |maybe_details| {
 				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
@@ -1072,12 +1779,34 @@ pub mod pallet {
 					return Ok(())
 				}
 
-				let metadata_deposit = Metadata::<T, I>::get(&id).deposit;
-				let deposit = details.deposit + metadata_deposit;
-
-				// Move the deposit to the new owner.
-				T::Currency::repatriate_reserved(&details.owner, &owner, deposit, Reserved)?;
+				// Move the deposits to the new owner. The old owner may hold less in reserve than
+				// recorded, in which case the deposits are reduced to whatever could be moved.
+				details.deposit = Self::transfer_deposit(
+					&id,
+					&details.owner,
+					&owner,
+					DepositKind::Asset,
+					details.deposit,
+				)?;
+				let metadata_deposit = Self::transfer_deposit(
+					&id,
+					&details.owner,
+					&owner,
+					DepositKind::Metadata,
+					details.metadata_deposit,
+				)?;
+				// `Metadata` only needs updating in the rare case the deposit shrank.
+				if metadata_deposit != details.metadata_deposit {
+					Metadata::<T, I>::mutate_exists(&id, |maybe_metadata| {
+						if let Some(metadata) = maybe_metadata {
+							metadata.deposit = metadata_deposit;
+						}
+					});
+					details.metadata_deposit = metadata_deposit;
+				}
 
+				Self::dec_owned_assets(&id, &details.owner);
+				Self::inc_owned_assets(&id, &owner);
 				details.owner = owner.clone();
 
 				Self::deposit_event(Event::OwnerChanged { asset_id: id, owner });
@@ -1105,11 +1834,11 @@ pub mod pallet {
 			admin: AccountIdLookupOf<T>,
 			freezer: AccountIdLookupOf<T>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
 			let issuer = T::Lookup::lookup(issuer)?;
 			let admin = T::Lookup::lookup(admin)?;
 			let freezer = T::Lookup::lookup(freezer)?;
-			let id: T::AssetId = id.into();
 
 			Asset::<T, I>::try_mutate(id.clone(), |maybe_details| {
 				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
@@ -1138,6 +1867,8 @@ pub mod pallet {
 		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
 		/// - `decimals`: The number of decimals this asset uses to represent one unit.
 		///
+		/// Fails with `BadMetadata` if `MetadataPolicy` doesn't accept the name or symbol.
+		///
 		/// Emits `MetadataSet`.
 		///
 		/// Weight: `O(1)`
@@ -1150,8 +1881,8 @@ pub mod pallet {
 			symbol: Vec<u8>,
 			decimals: u8,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
 			Self::do_set_metadata(id, &origin, name, symbol, decimals)
 		}
 
@@ -1163,21 +1894,25 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to clear.
 		///
+		/// Fails with `NoMetadata` if the asset has no metadata.
+		///
 		/// Emits `MetadataCleared`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(18)]
 		pub fn clear_metadata(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
 
-			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 			ensure!(origin == d.owner, Error::<T, I>::NoPermission);
 
 			Metadata::<T, I>::try_mutate_exists(id.clone(), |metadata| {
-				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
-				T::Currency::unreserve(&d.owner, deposit);
+				let deposit = metadata.take().ok_or(Error::<T, I>::NoMetadata)?.deposit;
+				Self::update_deposit(&id, &d.owner, DepositKind::Metadata, deposit, Zero::zero())?;
+				d.metadata_deposit = Zero::zero();
+				Asset::<T, I>::insert(&id, d);
 				Self::deposit_event(Event::MetadataCleared { asset_id: id });
 				Ok(())
 			})
@@ -1187,14 +1922,18 @@ pub mod pallet {
 		///
 		/// Origin must be ForceOrigin.
 		///
-		/// Any deposit is left alone.
+		/// Metadata without a deposit stays deposit-free. Otherwise the owner's deposit is brought
+		/// in line with the new metadata, just like `set_metadata`: the shortfall is reserved or
+		/// the excess unreserved.
 		///
 		/// - `id`: The identifier of the asset to update.
 		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
 		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
 		/// - `decimals`: The number of decimals this asset uses to represent one unit.
 		///
-		/// Emits `MetadataSet`.
+		/// Fails with `BadMetadata` if `MetadataPolicy` doesn't accept the name or symbol.
+		///
+		/// Emits `MetadataSet`, and `DepositUpdated` if the deposit changed.
 		///
 		/// Weight: `O(N + S)` where N and S are the length of the name and symbol respectively.
 		#[pallet::call_index(19)]
@@ -1210,19 +1949,34 @@ pub mod pallet {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let id: T::AssetId = id.into();
 
-			let bounded_name: BoundedVec<u8, T::StringLimit> =
-				name.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			let name: BoundedVec<u8, T::StringLimit> =
+				name.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
 
-			let bounded_symbol: BoundedVec<u8, T::StringLimit> =
-				symbol.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			let symbol: BoundedVec<u8, T::StringLimit> =
+				symbol.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			ensure!(T::MetadataPolicy::is_valid(&name, &symbol), Error::<T, I>::BadMetadata);
 
-			ensure!(Asset::<T, I>::contains_key(&id), Error::<T, I>::Unknown);
+			let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			Metadata::<T, I>::try_mutate_exists(id.clone(), |metadata| {
-				let deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+				// Metadata set for the first time or without a deposit stays deposit-free.
+				let old_deposit = match metadata.take() {
+					Some(old) => old.deposit,
+					None => Zero::zero(),
+				};
+				let deposit = if old_deposit.is_zero() {
+					Zero::zero()
+				} else {
+					Self::calc_metadata_deposit(&id, &name, &symbol)
+				};
+				Self::update_deposit(&id, &d.owner, DepositKind::Metadata, old_deposit, deposit)?;
+				if d.metadata_deposit != deposit {
+					d.metadata_deposit = deposit;
+					Asset::<T, I>::insert(&id, d);
+				}
 				*metadata = Some(AssetMetadata {
 					deposit,
-					name: bounded_name,
-					symbol: bounded_symbol,
+					name: name.clone(),
+					symbol: symbol.clone(),
 					decimals,
 					is_frozen,
 				});
@@ -1246,6 +2000,8 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to clear.
 		///
+		/// Fails with `NoMetadata` if the asset has no metadata.
+		///
 		/// Emits `MetadataCleared`.
 		///
 		/// Weight: `O(1)`
@@ -1257,10 +2013,12 @@ pub mod pallet {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let id: T::AssetId = id.into();
 
-			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			Metadata::<T, I>::try_mutate_exists(id.clone(), |metadata| {
-				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
-				T::Currency::unreserve(&d.owner, deposit);
+				let deposit = metadata.take().ok_or(Error::<T, I>::NoMetadata)?.deposit;
+				Self::update_deposit(&id, &d.owner, DepositKind::Metadata, deposit, Zero::zero())?;
+				d.metadata_deposit = Zero::zero();
+				Asset::<T, I>::insert(&id, d);
 				Self::deposit_event(Event::MetadataCleared { asset_id: id });
 				Ok(())
 			})
@@ -1285,6 +2043,8 @@ pub mod pallet {
 		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
 		/// instructions.
 		///
+		/// Fails with `IncorrectStatus` if the asset is being destroyed.
+		///
 		/// Emits `AssetStatusChanged` with the identity of the asset.
 		///
 		/// Weight: `O(1)`
@@ -1305,11 +2065,17 @@ pub mod pallet {
 
 			Asset::<T, I>::try_mutate(id.clone(), |maybe_asset| {
 				let mut asset = maybe_asset.take().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(asset.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
-				asset.owner = T::Lookup::lookup(owner)?;
+				// A destroying asset must not come back to life while its accounts are removed.
+				ensure!(asset.status != AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+				let owner = T::Lookup::lookup(owner)?;
 				asset.issuer = T::Lookup::lookup(issuer)?;
 				asset.admin = T::Lookup::lookup(admin)?;
 				asset.freezer = T::Lookup::lookup(freezer)?;
+				if asset.owner != owner {
+					Self::dec_owned_assets(&id, &asset.owner);
+					Self::inc_owned_assets(&id, &owner);
+					asset.owner = owner;
+				}
 				asset.min_balance = min_balance;
 				asset.is_sufficient = is_sufficient;
 				if is_frozen {
@@ -1328,18 +2094,18 @@ pub mod pallet {
 		///
 		/// Origin must be Signed.
 		///
-		/// Ensures that `ApprovalDeposit` worth of `Currency` is reserved from signing account
-		/// for the purpose of holding the approval. If some non-zero amount of assets is already
-		/// approved from signing account to `delegate`, then it is topped up or unreserved to
-		/// meet the right value.
+		/// Ensures that the approval deposit of the `DepositPolicy` worth of `Currency` is reserved
+		/// from signing account for the purpose of holding the approval. If some non-zero amount of
+		/// assets is already approved from signing account to `delegate`, then its deposit is
+		/// topped up to meet the right value, but never unreserved.
 		///
 		/// NOTE: The signing account does not need to own `amount` of assets at the point of
-		/// making this call.
+		/// making this call, but its account must not be frozen or blocked.
 		///
 		/// - `id`: The identifier of the asset.
 		/// - `delegate`: The account to delegate permission to transfer asset.
 		/// - `amount`: The amount of asset that may be transferred by `delegate`. If there is
-		/// already an approval in place, then this acts additively.
+		/// already an approval in place, then this acts additively. Must be greater than zero.
 		///
 		/// Emits `ApprovedTransfer` on success.
 		///
@@ -1350,11 +2116,14 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			delegate: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::AmountZero.with_weight(Weight::zero()));
 			let delegate = T::Lookup::lookup(delegate)?;
 			let id: T::AssetId = id.into();
 			Self::do_approve_transfer(id, &owner, &delegate, amount)
+				.map_err(Self::with_early_exit_weight)?;
+			Ok(().into())
 		}
 
 		/// Cancel all of some asset approved for delegated transfer by a third-party account.
@@ -1378,19 +2147,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let delegate = T::Lookup::lookup(delegate)?;
-			let id: T::AssetId = id.into();
-			let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
-			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
-
-			let approval = Approvals::<T, I>::take((id.clone(), &owner, &delegate))
-				.ok_or(Error::<T, I>::Unknown)?;
-			T::Currency::unreserve(&owner, approval.deposit);
-
-			d.approvals.saturating_dec();
-			Asset::<T, I>::insert(id.clone(), d);
-
-			Self::deposit_event(Event::ApprovalCancelled { asset_id: id, owner, delegate });
-			Ok(())
+			Self::do_cancel_approval(id.into(), owner, delegate, false)
 		}
 
 		/// Cancel all of some asset approved for delegated transfer by a third-party account.
@@ -1419,7 +2176,7 @@ pub mod pallet {
 			T::ForceOrigin::try_origin(origin)
 				.map(|_| ())
 				.or_else(|origin| -> DispatchResult {
-					let origin = ensure_signed(origin)?;
+					let origin = Self::ensure_role(origin, &id, Role::Admin)?;
 					ensure!(origin == d.admin, Error::<T, I>::NoPermission);
 					Ok(())
 				})?;
@@ -1429,11 +2186,23 @@ pub mod pallet {
 
 			let approval = Approvals::<T, I>::take((id.clone(), &owner, &delegate))
 				.ok_or(Error::<T, I>::Unknown)?;
-			T::Currency::unreserve(&owner, approval.deposit);
+			Self::update_deposit(
+				&id,
+				&owner,
+				DepositKind::Approval,
+				approval.deposit,
+				Zero::zero(),
+			)?;
+			Self::dec_approval_count(&id, &owner, &delegate);
 			d.approvals.saturating_dec();
 			Asset::<T, I>::insert(id.clone(), d);
 
-			Self::deposit_event(Event::ApprovalCancelled { asset_id: id, owner, delegate });
+			Self::deposit_event(Event::ApprovalCancelled {
+				asset_id: id,
+				owner,
+				delegate,
+				by_delegate: false,
+			});
 			Ok(())
 		}
 
@@ -1444,7 +2213,9 @@ pub mod pallet {
 		/// signer.
 		///
 		/// If the entire amount approved for transfer is transferred, then any deposit previously
-		/// reserved by `approve_transfer` is unreserved.
+		/// reserved by `approve_transfer` is unreserved. A partial transfer keeps the whole
+		/// deposit reserved, however little allowance remains, since the approval stays in storage
+		/// until it is spent, cancelled or rejected.
 		///
 		/// - `id`: The identifier of the asset.
 		/// - `owner`: The account which previously approved for a transfer of at least `amount` and
@@ -1452,7 +2223,7 @@ pub mod pallet {
 		/// - `destination`: The account to which the asset balance of `amount` will be transferred.
 		/// - `amount`: The amount of assets to transfer.
 		///
-		/// Emits `TransferredApproved` on success.
+		/// Emits `TransferredApproved` on success, with the allowance which remains.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(25)]
@@ -1484,6 +2255,7 @@ pub mod pallet {
 		pub fn touch(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			Self::ensure_can_add_holding(&who)?;
 			Self::do_touch(id, who.clone(), who, false)
 		}
 
@@ -1492,6 +2264,9 @@ pub mod pallet {
 		///
 		/// The origin must be Signed.
 		///
+		/// Fails with `Frozen` if the account or the asset is frozen; the Admin may still refund
+		/// such an account with `force_refund`.
+		///
 		/// - `id`: The identifier of the asset for which the caller would like the deposit
 		///   refunded.
 		/// - `allow_burn`: If `true` then assets may be destroyed in order to complete the refund.
@@ -1505,7 +2280,7 @@ pub mod pallet {
 			allow_burn: bool,
 		) -> DispatchResult {
 			let id: T::AssetId = id.into();
-			Self::do_refund(id, ensure_signed(origin)?, allow_burn)
+			Self::do_refund(id, ensure_signed(origin)?, allow_burn, None)
 		}
 
 		/// Sets the minimum balance of an asset.
@@ -1526,8 +2301,8 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			min_balance: T::Balance,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
 
 			let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
@@ -1616,8 +2391,8 @@ pub mod pallet {
 			id: T::AssetIdParameter,
 			who: AccountIdLookupOf<T>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Freezer)?;
 
 			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 			ensure!(
@@ -1627,6 +2402,7 @@ pub mod pallet {
 			ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
 			let who = T::Lookup::lookup(who)?;
 
+			Self::migrate_account(&id, &who);
 			Account::<T, I>::try_mutate(&id, &who, |maybe_account| -> DispatchResult {
 				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.status =
 					AccountStatus::Blocked;
@@ -1636,6 +2412,324 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Blocked { asset_id: id, who });
 			Ok(())
 		}
+
+		/// Sets the maximum number of accounts which may hold an asset.
+		///
+		/// Origin must be Signed and the sender has to be the Owner of the asset `id`.
+		///
+		/// Accounts already holding the asset are unaffected if the new limit is lower than the
+		/// current number of holders; only the creation of further accounts is prevented. Forced
+		/// transfers by the Admin are not subject to the limit.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_holders`: The new limit, or `None` to remove it.
+		///
+		/// Emits `MaxHoldersSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		pub fn set_max_holders(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			max_holders: Option<u32>,
+		) -> DispatchResult {
+			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(origin == d.owner, Error::<T, I>::NoPermission);
+
+			MaxHolders::<T, I>::set(&id, max_holders);
+
+			Self::deposit_event(Event::MaxHoldersSet { asset_id: id, max_holders });
+			Ok(())
+		}
+
+		/// Return the deposit (if any) or the consumer reference (if any) of an asset account,
+		/// even if the account or the asset is frozen.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to refund.
+		/// - `allow_burn`: If `true` then assets may be destroyed in order to complete the refund.
+		///
+		/// Emits `DepositUpdated` if a deposit is returned.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::refund())]
+		pub fn force_refund(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			allow_burn: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::do_refund(id, who, allow_burn, Some(origin))
+		}
+
+		/// Continue the ongoing lazy migration of the `Account` entries, see
+		/// [`migration::lazy`]. The migration also progresses in `on_idle`, this lets anyone
+		/// complete it sooner.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `limit`: The maximum number of entries to visit.
+		///
+		/// Emits `AccountMigrationCompleted` if the migration is over.
+		///
+		/// Weight: `O(limit)`, refunded down to the number of entries visited.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::continue_migration(*limit))]
+		pub fn continue_migration(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(AccountMigrationCursor::<T, I>::exists(), Error::<T, I>::NoMigrationOngoing);
+			let visited = Self::do_migrate_accounts(limit);
+			Ok(Some(T::WeightInfo::continue_migration(visited)).into())
+		}
+
+		/// Cancel up to `max` of the approvals made by `owner` for the asset `id`, for instance
+		/// once `owner` is known to be compromised.
+		///
+		/// Origin must be either ForceOrigin or Signed origin with the signer being the Admin
+		/// account of the asset `id`.
+		///
+		/// Unreserves the deposits previously reserved by `approve_transfer` for the approvals.
+		/// If `owner` has more than `max` approvals, the call can be repeated until
+		/// `approvals_of` returns none.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which made the approvals.
+		/// - `max`: The maximum number of approvals to cancel, itself capped at
+		///   `MaxApprovalsPerOwner`.
+		///
+		/// Emits `ApprovalCancelled` for each approval cancelled.
+		///
+		/// Weight: `O(max)`, refunded down to the number of approvals cancelled.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::force_cancel_approvals_of(
+			(*max).min(T::MaxApprovalsPerOwner::get())
+		))]
+		pub fn force_cancel_approvals_of(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			owner: AccountIdLookupOf<T>,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			let id: T::AssetId = id.into();
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			T::ForceOrigin::try_origin(origin)
+				.map(|_| ())
+				.or_else(|origin| -> DispatchResult {
+					let origin = Self::ensure_role(origin, &id, Role::Admin)?;
+					ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+					Ok(())
+				})?;
+			let owner = T::Lookup::lookup(owner)?;
+			let max = max.min(T::MaxApprovalsPerOwner::get());
+
+			let cancelled = Self::do_force_cancel_approvals_of(id, &owner, max)?;
+			Ok(Some(T::WeightInfo::force_cancel_approvals_of(cancelled)).into())
+		}
+
+		/// Bring the deposits of the asset `id` and of its metadata in line with the current
+		/// `DepositPolicy`, after a runtime upgrade changed it.
+		///
+		/// Origin must be Signed, by any account.
+		///
+		/// The shortfall is reserved from the Owner, or the excess unreserved. Deposit-free assets
+		/// and metadata, such as those forced into existence, stay deposit-free.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `DepositUpdated` for each deposit which changed.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(36)]
+		pub fn refresh_asset_deposit(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_refresh_asset_deposit(id.into())
+		}
+
+		/// Sets whether the Issuer may mint into frozen accounts, which it may by default.
+		///
+		/// Origin must be Signed and the sender has to be the Owner of the asset `id`.
+		///
+		/// This only concerns accounts frozen by the Freezer: nothing can be minted while the
+		/// whole asset is frozen, and transfers into frozen accounts are unaffected.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `allow`: Whether minting into frozen accounts is allowed.
+		///
+		/// Emits `AllowMintWhenFrozenSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(37)]
+		pub fn set_allow_mint_when_frozen(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			allow: bool,
+		) -> DispatchResult {
+			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(origin == d.owner, Error::<T, I>::NoPermission);
+
+			// Only the non-default setting is stored.
+			if allow {
+				AllowMintWhenFrozen::<T, I>::remove(&id);
+			} else {
+				AllowMintWhenFrozen::<T, I>::insert(&id, false);
+			}
+
+			Self::deposit_event(Event::AllowMintWhenFrozenSet { asset_id: id, allow });
+			Ok(())
+		}
+
+		/// Sets whether the Admin may burn from frozen accounts and while the asset is frozen,
+		/// which it may by default. Otherwise they have to be thawed first.
+		///
+		/// Origin must be Signed and the sender has to be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `allow`: Whether the Admin may burn frozen funds.
+		///
+		/// Emits `AllowAdminBurnWhenFrozenSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(38)]
+		pub fn set_allow_admin_burn_when_frozen(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			allow: bool,
+		) -> DispatchResult {
+			let id: T::AssetId = id.into();
+			let origin = Self::ensure_role(origin, &id, Role::Owner)?;
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(origin == d.owner, Error::<T, I>::NoPermission);
+
+			// Only the non-default setting is stored.
+			if allow {
+				AllowAdminBurnWhenFrozen::<T, I>::remove(&id);
+			} else {
+				AllowAdminBurnWhenFrozen::<T, I>::insert(&id, false);
+			}
+
+			Self::deposit_event(Event::AllowAdminBurnWhenFrozenSet { asset_id: id, allow });
+			Ok(())
+		}
+
+		/// Remove the metadata of an asset being destroyed and return its deposit to the Owner.
+		///
+		/// `destroy_metadata` should only be called after `start_destroy` has been called, and the
+		/// asset is in a `Destroying` state, otherwise it fails with `IncorrectStatus`. It need not
+		/// wait for the accounts and approvals to be destroyed.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		///
+		/// Fails with `NoMetadata` if the asset has no metadata.
+		///
+		/// Emits `MetadataCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		pub fn destroy_metadata(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_destroy_metadata(id)
+		}
+
+		/// Bring the deposit held for the account of `who` in line with the current
+		/// `DepositPolicy`, after a runtime upgrade changed it, or its footprint changed.
+		///
+		/// Origin must be Signed, by any account.
+		///
+		/// The shortfall is reserved from whoever paid the deposit, or the excess unreserved to
+		/// them. The account must exist for a deposit, otherwise it fails with `NoDeposit`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose deposit should be brought up to date.
+		///
+		/// Emits `DepositUpdated` if the deposit changed.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		pub fn poke_account_deposit(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_poke_account_deposit(id.into(), &who)
+		}
+
+		/// Renounce an approval made to the sender for spending the assets of `owner`.
+		///
+		/// Origin must be Signed and there must be an approval in place from `owner` to the
+		/// signer.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval,
+		/// which goes back to `owner`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which approved the sender to transfer its assets.
+		///
+		/// Emits `ApprovalCancelled` with `by_delegate` set on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(41)]
+		pub fn reject_approval(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_cancel_approval(id.into(), owner, delegate, true)
+		}
+
+		/// Count up to `max_accounts` more accounts of the asset `id`, to set its `accounts` and
+		/// `sufficients` counters right once all of them were counted.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// The accounts are counted in chunks across calls, each carrying on where the last one
+		/// stopped. Accounts created or removed in between may throw the counters off, so the
+		/// asset should be frozen while it is repaired; a repair can always be run again.
+		///
+		/// The reference which an account holds on the system because of the asset is restored
+		/// if the system has none of its kind left for it. Other references are never released,
+		/// since they may be held for another asset or pallet.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_accounts`: The maximum number of accounts to count.
+		///
+		/// Emits `RefsRepaired`.
+		///
+		/// Weight: `O(max_accounts)`, refunded down to the number of accounts counted.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::repair_refs(*max_accounts))]
+		pub fn repair_refs(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			max_accounts: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let visited = Self::do_repair_refs(id.into(), max_accounts)?;
+			Ok(Some(T::WeightInfo::repair_refs(visited)).into())
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -1644,8 +2738,14 @@ pub mod pallet {
 	impl<T: Config<I>, I: 'static> AccountTouch<T::AssetId, T::AccountId> for Pallet<T, I> {
 		type Balance = DepositBalanceOf<T, I>;
 
-		fn deposit_required(_: T::AssetId) -> Self::Balance {
-			T::AssetAccountDeposit::get()
+		/// Quoted for the largest footprint an account may take, since the account and its
+		/// depositor are not known yet.
+		fn deposit_required(asset: T::AssetId) -> Self::Balance {
+			let footprint = StorageFootprint {
+				items: 1,
+				bytes: AssetAccountOf::<T, I>::max_encoded_len() as u32,
+			};
+			T::DepositPolicy::account_deposit(&asset, footprint)
 		}
 
 		fn touch(asset: T::AssetId, who: T::AccountId, depositor: T::AccountId) -> DispatchResult {
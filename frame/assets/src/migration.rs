@@ -27,7 +27,7 @@ pub mod v1 {
 
 	use super::*;
 
-	#[derive(Decode)]
+	#[derive(Decode, Encode)]
 	pub struct OldAssetDetails<Balance, AccountId, DepositBalance> {
 		pub owner: AccountId,
 		pub issuer: AccountId,
@@ -43,7 +43,7 @@ pub mod v1 {
 		pub is_frozen: bool,
 	}
 
-	impl<Balance, AccountId, DepositBalance> OldAssetDetails<Balance, AccountId, DepositBalance> {
+	impl<Balance, AccountId, DepositBalance: Zero> OldAssetDetails<Balance, AccountId, DepositBalance> {
 		fn migrate_to_v1(self) -> AssetDetails<Balance, AccountId, DepositBalance> {
 			let status = if self.is_frozen { AssetStatus::Frozen } else { AssetStatus::Live };
 
@@ -60,6 +60,7 @@ pub mod v1 {
 				sufficients: self.sufficients,
 				approvals: self.approvals,
 				status,
+				metadata_deposit: Zero::zero(),
 			}
 		}
 	}
@@ -67,9 +68,8 @@ pub mod v1 {
 	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
 		fn on_runtime_upgrade() -> Weight {
-			let current_version = Pallet::<T>::current_storage_version();
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
-			if onchain_version == 0 && current_version == 1 {
+			if onchain_version == 0 {
 				let mut translated = 0u64;
 				Asset::<T>::translate::<
 					OldAssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T>>,
@@ -78,12 +78,11 @@ pub mod v1 {
 					translated.saturating_inc();
 					Some(old_value.migrate_to_v1())
 				});
-				current_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 				log::info!(
 					target: LOG_TARGET,
-					"Upgraded {} pools, storage to version {:?}",
+					"Upgraded {} pools, storage to version 1",
 					translated,
-					current_version
 				);
 				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
 			} else {
@@ -101,7 +100,8 @@ pub mod v1 {
 				Pallet::<T>::on_chain_storage_version() == 0,
 				"must upgrade linearly"
 			);
-			let prev_count = Asset::<T>::iter().count();
+			// The values are still in the old layout, so only the keys are counted.
+			let prev_count = Asset::<T>::iter_keys().count();
 			Ok((prev_count as u32).encode())
 		}
 
@@ -110,19 +110,20 @@ pub mod v1 {
 			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
 				"the state parameter should be something that was generated by pre_upgrade",
 			);
-			let post_count = Asset::<T>::iter().count() as u32;
+			let post_count = Asset::<T>::iter_keys().count() as u32;
 			ensure!(
 				prev_count == post_count,
 				"the asset count before and after the migration should be the same"
 			);
+			// `iter` skips the values which cannot be decoded.
+			ensure!(
+				Asset::<T>::iter_values().count() as u32 == post_count,
+				"all the assets should decode in the new layout"
+			);
 
-			let current_version = Pallet::<T>::current_storage_version();
-			let onchain_version = Pallet::<T>::on_chain_storage_version();
-
-			frame_support::ensure!(current_version == 1, "must_upgrade");
 			ensure!(
-				current_version == onchain_version,
-				"after migration, the current_version and onchain_version should be the same"
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"after migration, the onchain_version should be 1"
 			);
 
 			Asset::<T>::iter().try_for_each(|(_id, asset)| -> Result<(), TryRuntimeError> {
@@ -136,3 +137,733 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Initializes `ApprovalCount` from the existing `Approvals`.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 1 {
+				let mut approvals = 0u64;
+				let mut owners = 0u64;
+				for (id, owner, _) in Approvals::<T>::iter_keys() {
+					approvals.saturating_inc();
+					ApprovalCount::<T>::mutate(&id, &owner, |count| {
+						if count.is_zero() {
+							owners.saturating_inc();
+						}
+						count.saturating_inc()
+					});
+				}
+				StorageVersion::new(2).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Counted {} approvals of {} owners, storage to version 2",
+					approvals,
+					owners,
+				);
+				T::DbWeight::get().reads_writes(approvals * 2 + 1, approvals + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"must upgrade linearly"
+			);
+			let prev_count = Approvals::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let counted = ApprovalCount::<T>::iter_values().fold(0u32, |acc, c| acc + c);
+			ensure!(prev_count == counted, "every approval should be counted exactly once");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"after migration, the onchain_version should be 2"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Initializes `AssetCount` from the existing `Asset`s.
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 2 {
+				let assets = Asset::<T>::iter_keys().count() as u32;
+				AssetCount::<T>::put(assets);
+				StorageVersion::new(3).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Counted {} assets, storage to version 3",
+					assets,
+				);
+				T::DbWeight::get().reads_writes(assets as u64 + 1, 2)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"must upgrade linearly"
+			);
+			let prev_count = Asset::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(prev_count == AssetCount::<T>::get(), "every asset should be counted");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"after migration, the onchain_version should be 3"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// `AssetDetails` before the metadata deposit was recorded in it.
+	#[derive(Decode, Encode)]
+	pub struct OldAssetDetails<Balance, AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub admin: AccountId,
+		pub freezer: AccountId,
+		pub supply: Balance,
+		pub deposit: DepositBalance,
+		pub min_balance: Balance,
+		pub is_sufficient: bool,
+		pub accounts: u32,
+		pub sufficients: u32,
+		pub approvals: u32,
+		pub status: AssetStatus,
+	}
+
+	impl<Balance, AccountId, DepositBalance> OldAssetDetails<Balance, AccountId, DepositBalance> {
+		fn migrate_to_v4(
+			self,
+			metadata_deposit: DepositBalance,
+		) -> AssetDetails<Balance, AccountId, DepositBalance> {
+			AssetDetails {
+				owner: self.owner,
+				issuer: self.issuer,
+				admin: self.admin,
+				freezer: self.freezer,
+				supply: self.supply,
+				deposit: self.deposit,
+				min_balance: self.min_balance,
+				is_sufficient: self.is_sufficient,
+				accounts: self.accounts,
+				sufficients: self.sufficients,
+				approvals: self.approvals,
+				status: self.status,
+				metadata_deposit,
+			}
+		}
+	}
+
+	/// Records the deposit of each asset's metadata in its `AssetDetails`.
+	pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 3 {
+				let mut translated = 0u64;
+				Asset::<T>::translate::<
+					OldAssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T>>,
+					_,
+				>(|id, old_value| {
+					translated.saturating_inc();
+					Some(
+						old_value.migrate_to_v4(
+							Metadata::<T>::get(id).map_or(Zero::zero(), |m| m.deposit),
+						),
+					)
+				});
+				StorageVersion::new(4).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Recorded the metadata deposit of {} assets, storage to version 4",
+					translated,
+				);
+				T::DbWeight::get().reads_writes(translated * 2 + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"must upgrade linearly"
+			);
+			let prev_count = Asset::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Asset::<T>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the asset count before and after the migration should be the same"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 4,
+				"after migration, the onchain_version should be 4"
+			);
+			Asset::<T>::iter().try_for_each(|(id, asset)| -> Result<(), TryRuntimeError> {
+				ensure!(
+					asset.metadata_deposit ==
+						Metadata::<T>::get(id).map_or(Zero::zero(), |m| m.deposit),
+					"the metadata deposit should be recorded for every asset"
+				);
+				Ok(())
+			})
+		}
+	}
+}
+
+pub mod v5 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Initializes `DelegateApprovals` and `DelegateApprovalCount` from the existing `Approvals`.
+	pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 4 {
+				let mut approvals = 0u64;
+				let mut delegates = 0u64;
+				for (id, owner, delegate) in Approvals::<T>::iter_keys() {
+					approvals.saturating_inc();
+					DelegateApprovals::<T>::insert(&delegate, (id, owner), ());
+					DelegateApprovalCount::<T>::mutate(&delegate, |count| {
+						if count.is_zero() {
+							delegates.saturating_inc();
+						}
+						count.saturating_inc()
+					});
+				}
+				StorageVersion::new(5).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Indexed {} approvals of {} delegates, storage to version 5",
+					approvals,
+					delegates,
+				);
+				T::DbWeight::get().reads_writes(approvals * 2 + 1, approvals * 2 + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 4,
+				"must upgrade linearly"
+			);
+			let prev_count = Approvals::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let indexed = DelegateApprovals::<T>::iter_keys().count() as u32;
+			let counted = DelegateApprovalCount::<T>::iter_values().fold(0u32, |acc, c| acc + c);
+			ensure!(prev_count == indexed, "every approval should be indexed exactly once");
+			ensure!(prev_count == counted, "every approval should be counted exactly once");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 5,
+				"after migration, the onchain_version should be 5"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v6 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Initializes `HoldingCount` from the existing `Account` entries.
+	pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 5 {
+				let mut accounts = 0u64;
+				let mut holders = 0u64;
+				// Only the keys are read, so accounts of an ongoing lazy migration are counted too.
+				for (_, who) in Account::<T>::iter_keys() {
+					accounts.saturating_inc();
+					HoldingCount::<T>::mutate(&who, |count| {
+						if count.is_zero() {
+							holders.saturating_inc();
+						}
+						count.saturating_inc()
+					});
+				}
+				StorageVersion::new(6).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Counted {} asset accounts of {} holders, storage to version 6",
+					accounts,
+					holders,
+				);
+				T::DbWeight::get().reads_writes(accounts * 2 + 1, accounts + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 5,
+				"must upgrade linearly"
+			);
+			let prev_count = Account::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let counted = HoldingCount::<T>::iter_values().fold(0u32, |acc, c| acc + c);
+			ensure!(prev_count == counted, "every asset account should be counted exactly once");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 6,
+				"after migration, the onchain_version should be 6"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v7 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Removes the `Metadata` entries holding the default metadata, which assets without any
+	/// metadata were given before `Metadata` became optional.
+	pub struct MigrateToV7<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 6 {
+				let mut read = 0u64;
+				let defaults: Vec<T::AssetId> = Metadata::<T>::iter()
+					.filter_map(|(id, metadata)| {
+						read.saturating_inc();
+						(metadata == Default::default()).then_some(id)
+					})
+					.collect();
+				for id in &defaults {
+					Metadata::<T>::remove(id);
+				}
+				let removed = defaults.len() as u64;
+				StorageVersion::new(7).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Removed {} default metadata out of {}, storage to version 7",
+					removed,
+					read,
+				);
+				T::DbWeight::get().reads_writes(read + 1, removed + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 6,
+				"must upgrade linearly"
+			);
+			let kept = Metadata::<T>::iter_values().filter(|m| *m != Default::default()).count();
+			Ok((kept as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(kept: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let kept: u32 = Decode::decode(&mut kept.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(
+				Metadata::<T>::iter_values().count() as u32 == kept,
+				"only the default metadata should be removed"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 7,
+				"after migration, the onchain_version should be 7"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v8 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Initializes `OwnedAssets` and `OwnedAssetCount` from the owners of the existing `Asset`s.
+	pub struct MigrateToV8<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 7 {
+				let mut assets = 0u64;
+				let mut owners = 0u64;
+				for (id, details) in Asset::<T>::iter() {
+					assets.saturating_inc();
+					OwnedAssets::<T>::insert(&details.owner, id, ());
+					OwnedAssetCount::<T>::mutate(&details.owner, |count| {
+						if count.is_zero() {
+							owners.saturating_inc();
+						}
+						count.saturating_inc()
+					});
+				}
+				StorageVersion::new(8).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Indexed {} assets of {} owners, storage to version 8",
+					assets,
+					owners,
+				);
+				T::DbWeight::get().reads_writes(assets * 2 + 1, assets * 2 + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 7,
+				"must upgrade linearly"
+			);
+			let prev_count = Asset::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let indexed = OwnedAssets::<T>::iter_keys().count() as u32;
+			let counted = OwnedAssetCount::<T>::iter_values().fold(0u32, |acc, c| acc + c);
+			ensure!(prev_count == indexed, "every asset should be indexed exactly once");
+			ensure!(prev_count == counted, "every asset should be counted exactly once");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 8,
+				"after migration, the onchain_version should be 8"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Switching the hasher of the asset ids in storage keys, see `Config::AssetIdHasher`.
+pub mod asset_id_hasher {
+	use frame_support::{
+		pallet_prelude::*,
+		storage::{storage_prefix, unhashed},
+		weights::Weight,
+	};
+
+	use super::*;
+
+	/// The storage items keyed by asset, all of which have the asset id as their first key.
+	const STORAGE_ITEMS: [&str; 12] = [
+		"Asset",
+		"Account",
+		"Approvals",
+		"ApprovalCount",
+		"Holds",
+		"Freezes",
+		"MaxHolders",
+		"AllowMintWhenFrozen",
+		"AllowAdminBurnWhenFrozen",
+		"Metadata",
+		"AssetStats",
+		"RefRepairs",
+	];
+
+	/// Rewrites the keys of all the storage items keyed by asset, hashing their asset id with
+	/// `New` instead of `Old`, and returns the number of keys rewritten.
+	///
+	/// Keys whose asset id is not hashed with `Old` are left as they are, so running this twice
+	/// does no harm.
+	pub fn rehash<T, I, Old, New>() -> u64
+	where
+		T: Config<I>,
+		I: 'static,
+		Old: ReversibleStorageHasher,
+		New: StorageHasher,
+	{
+		let pallet = <Pallet<T, I> as PalletInfoAccess>::name();
+		let mut rehashed = 0u64;
+		for item in STORAGE_ITEMS {
+			let prefix = storage_prefix(pallet.as_bytes(), item.as_bytes());
+			// The new keys share the prefix of the old ones, so collect the old ones first.
+			let keys: Vec<Vec<u8>> =
+				KeyPrefixIterator::new(prefix.to_vec(), prefix.to_vec(), |key| Ok(key.to_vec()))
+					.collect();
+			for key in keys {
+				let Some((id, rest)) = split_asset_id::<T, I, Old>(&key) else { continue };
+				let old_key = [&prefix[..], &key].concat();
+				let new_key = [&prefix[..], New::hash(id).as_ref(), rest].concat();
+				if let Some(value) = unhashed::get_raw(&old_key) {
+					unhashed::kill(&old_key);
+					unhashed::put_raw(&new_key, &value);
+					rehashed.saturating_inc();
+				}
+			}
+		}
+		rehashed
+	}
+
+	/// Splits `key`, a storage key without its prefix, into the encoded asset id and the rest of
+	/// the key, if the asset id is hashed with `Hasher`.
+	fn split_asset_id<T, I, Hasher>(key: &[u8]) -> Option<(&[u8], &[u8])>
+	where
+		T: Config<I>,
+		I: 'static,
+		Hasher: ReversibleStorageHasher,
+	{
+		let unhashed = Hasher::reverse(key);
+		let mut input = unhashed;
+		T::AssetId::decode(&mut input).ok()?;
+		let (id, rest) = unhashed.split_at(unhashed.len() - input.len());
+		// A key hashed otherwise may still happen to decode.
+		(Hasher::hash(id).as_ref() == &key[..key.len() - rest.len()]).then_some((id, rest))
+	}
+
+	/// Counts the keys of all the storage items keyed by asset whose asset id is hashed with
+	/// `Hasher`.
+	#[cfg(feature = "try-runtime")]
+	fn count_keys<T, I, Hasher>() -> u32
+	where
+		T: Config<I>,
+		I: 'static,
+		Hasher: ReversibleStorageHasher,
+	{
+		let pallet = <Pallet<T, I> as PalletInfoAccess>::name();
+		STORAGE_ITEMS
+			.iter()
+			.map(|item| {
+				let prefix = storage_prefix(pallet.as_bytes(), item.as_bytes());
+				KeyPrefixIterator::new(prefix.to_vec(), prefix.to_vec(), |key| Ok(key.to_vec()))
+					.filter(|key| split_asset_id::<T, I, Hasher>(key).is_some())
+					.count() as u32
+			})
+			.sum()
+	}
+
+	/// Rehashes the asset ids of the instance `I` from `Old` to `T::AssetIdHasher`. It must be
+	/// part of the runtime upgrade which changes `AssetIdHasher`.
+	///
+	/// Every key of the storage items keyed by asset is rewritten within the upgrade block, so
+	/// this is only suited to chains with a moderate number of asset accounts and approvals. Run
+	/// it with try-runtime against the live state to check its weight first.
+	pub struct Migrate<T, I, Old>(sp_std::marker::PhantomData<(T, I, Old)>);
+	impl<T, I, Old> OnRuntimeUpgrade for Migrate<T, I, Old>
+	where
+		T: Config<I>,
+		I: 'static,
+		Old: ReversibleStorageHasher,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let rehashed = rehash::<T, I, Old, T::AssetIdHasher>();
+			log::info!(target: LOG_TARGET, "Rehashed the asset id of {} storage keys", rehashed);
+			T::DbWeight::get().reads_writes(rehashed, rehashed * 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(count_keys::<T, I, Old>().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(
+				count_keys::<T, I, T::AssetIdHasher>() == prev_count,
+				"every key should be rehashed"
+			);
+			ensure!(
+				Asset::<T, I>::iter_keys().count() as u32 == AssetCount::<T, I>::get(),
+				"every asset should be found"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrating the `Account` entries to a new encoding over many blocks.
+///
+/// A chain may hold too many asset accounts to rewrite them all in one runtime upgrade. Instead:
+///
+/// 1. The upgrade sets `Config::AccountMigration` to a [`TranslateAccount`] from the previous
+///    encoding, and runs [`StartAccountMigration`].
+/// 2. Until the migration is over, entries in either encoding are read correctly, and an entry in
+///    the previous one is rewritten in the current one before it is changed.
+/// 3. Each block, `on_idle` rewrites as many entries as its remaining weight allows. Anyone may
+///    also call `continue_migration` to rewrite more.
+/// 4. Once every entry has been visited, `AccountMigrationCompleted` is emitted. The translation
+///    can then be dropped from the runtime, by setting `Config::AccountMigration` back to `()`.
+///
+/// A migration must be over before the next one is started.
+pub mod lazy {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Translates the `Account` entries from their previous encoding to the current one.
+	///
+	/// An entry is in the previous encoding if it does not decode exactly in the current one, so
+	/// both encodings must not have the same length for the same entry. This holds when a field
+	/// is added or removed.
+	pub trait TranslateAccount<T: Config<I>, I: 'static> {
+		/// The previous encoding of the entries.
+		type Old: Decode;
+
+		/// Translate `old`, the entry of `who` for the asset `id`.
+		fn translate(id: &T::AssetId, who: &T::AccountId, old: Self::Old) -> AssetAccountOf<T, I>;
+	}
+
+	/// Nothing to translate.
+	impl<T: Config<I>, I: 'static> TranslateAccount<T, I> for () {
+		type Old = AssetAccountOf<T, I>;
+
+		fn translate(_: &T::AssetId, _: &T::AccountId, old: Self::Old) -> AssetAccountOf<T, I> {
+			old
+		}
+	}
+
+	/// Starts a lazy migration of the `Account` entries of the instance `I`, translated by
+	/// `Config::AccountMigration`. Bumping the storage version, if needed, is left to the
+	/// runtime upgrade using this.
+	pub struct StartAccountMigration<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for StartAccountMigration<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if AccountMigrationCursor::<T, I>::exists() {
+				log::warn!(
+					target: LOG_TARGET,
+					"An account migration is already ongoing, it must be over before the next one"
+				);
+				T::DbWeight::get().reads(1)
+			} else {
+				AccountMigrationCursor::<T, I>::put(MigrationCursor::Start);
+				log::info!(target: LOG_TARGET, "Started a lazy migration of the accounts");
+				T::DbWeight::get().reads_writes(1, 1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				!AccountMigrationCursor::<T, I>::exists(),
+				"the previous account migration must be over"
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				AccountMigrationCursor::<T, I>::get() == Some(MigrationCursor::Start),
+				"the account migration should have started"
+			);
+			// Every entry must be readable in one encoding or the other.
+			Account::<T, I>::iter_keys().try_for_each(|(id, who)| -> Result<(), TryRuntimeError> {
+				ensure!(
+					Pallet::<T, I>::account(&id, &who).is_some(),
+					"every account should decode in the current or the previous encoding"
+				);
+				Ok(())
+			})
+		}
+	}
+}
@@ -20,11 +20,15 @@
 use super::*;
 use crate as pallet_assets;
 
-use codec::Encode;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	construct_runtime,
+	instances::Instance2,
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, Contains, EnsureOrigin},
+	Blake2_128Concat, Twox64Concat,
 };
+use serde::{Deserialize, Serialize};
 use sp_core::H256;
 use sp_io::storage;
 use sp_runtime::{
@@ -40,6 +44,9 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		LocationAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>},
+		Deposits: pallet_deposits::{Pallet, Call},
+		Tags: pallet_tags::{Pallet, Call},
 	}
 );
 
@@ -90,20 +97,20 @@ impl pallet_balances::Config for Test {
 
 pub struct AssetsCallbackHandle;
 impl AssetsCallback<AssetId, AccountId> for AssetsCallbackHandle {
-	fn created(_id: &AssetId, _owner: &AccountId) -> Result<(), ()> {
+	fn created(id: &AssetId, owner: &AccountId) -> Result<(), ()> {
 		if Self::should_err() {
 			Err(())
 		} else {
-			storage::set(Self::CREATED.as_bytes(), &().encode());
+			storage::set(Self::CREATED.as_bytes(), &(id, owner).encode());
 			Ok(())
 		}
 	}
 
-	fn destroyed(_id: &AssetId) -> Result<(), ()> {
+	fn destroyed(id: &AssetId) -> Result<(), ()> {
 		if Self::should_err() {
 			Err(())
 		} else {
-			storage::set(Self::DESTROYED.as_bytes(), &().encode());
+			storage::set(Self::DESTROYED.as_bytes(), &id.encode());
 			Ok(())
 		}
 	}
@@ -125,6 +132,16 @@ impl AssetsCallbackHandle {
 		storage::set(Self::RETURN_ERROR.as_bytes(), &().encode());
 	}
 
+	// The asset and owner passed to the last successful `created` callback.
+	pub fn last_created() -> Option<(AssetId, AccountId)> {
+		storage::get(Self::CREATED.as_bytes()).and_then(|raw| Decode::decode(&mut &raw[..]).ok())
+	}
+
+	// The asset passed to the last successful `destroyed` callback.
+	pub fn last_destroyed() -> Option<AssetId> {
+		storage::get(Self::DESTROYED.as_bytes()).and_then(|raw| Decode::decode(&mut &raw[..]).ok())
+	}
+
 	// If `true`, callback should return `Err`, `Ok` otherwise.
 	fn should_err() -> bool {
 		storage::exists(Self::RETURN_ERROR.as_bytes())
@@ -137,21 +154,229 @@ impl Config for Test {
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
+	type CreateOrigin = TestCreateOrigin;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type RoleOrigin = TestRoleOrigin;
+	type ReservedAssetIds = ReservedAssetIds;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type DepositPolicy = TestDepositPolicy;
+	type ApprovalDeposit = ApprovalDeposit;
+	type MaxApprovalsPerOwner = ConstU32<5>;
+	type MaxApprovalsPerDelegate = ConstU32<8>;
+	type MaxOwnedAssets = MaxOwnedAssets;
+	type MaxHoldingsPerAccount = MaxHoldingsPerAccount;
+	type StringLimit = ConstU32<50>;
+	type MetadataPolicy = TestMetadataPolicy;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Freezer = (TestFreezer, SecondFreezer);
+	#[cfg(feature = "runtime-benchmarks")]
+	type Freezer = (TestFreezer, SecondFreezer, BenchmarkFreezer<Test, (), 2>);
+	type RuntimeHoldReason = TestHoldReason;
+	type MaxHolds = ConstU32<2>;
+	type FreezeIdentifier = TestFreezeId;
+	type MaxFreezes = ConstU32<2>;
+	type WeightInfo = ();
+	type CallbackHandle = AssetsCallbackHandle;
+	type TransferFilter = TestTransferFilter;
+	type OnDust = TestDust;
+	type TrackStats = TrackStats;
+	type AccountMigration = TestAccountMigration;
+	type Extra = u32;
+	type ExtraDepositor = TestExtraDepositor;
+	type RemoveAccountsLimit = ConstU32<5>;
+	type RemoveApprovalsLimit = ConstU32<5>;
+	type AssetIdHasher = Blake2_128Concat;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// A structured asset id, like the locations used by XCM. It is neither `Copy` nor compact.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+pub struct Location {
+	pub parents: u8,
+	pub index: u32,
+}
+
+/// The call parameter for a `Location`, encoding its index compactly.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LocationParameter(pub u8, #[codec(compact)] pub u32);
+
+impl From<Location> for LocationParameter {
+	fn from(location: Location) -> Self {
+		LocationParameter(location.parents, location.index)
+	}
+}
+
+impl From<LocationParameter> for Location {
+	fn from(LocationParameter(parents, index): LocationParameter) -> Self {
+		Location { parents, index }
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct LocationHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<LocationParameter> for LocationHelper {
+	fn create_asset_id_parameter(id: u32) -> LocationParameter {
+		LocationParameter(1, id)
+	}
+}
+
+/// An instance of the pallet identifying its assets by `Location`.
+impl Config<Instance2> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = Location;
+	type AssetIdParameter = LocationParameter;
+	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = frame_support::traits::Nothing;
 	type AssetDeposit = ConstU64<1>;
 	type AssetAccountDeposit = ConstU64<10>;
 	type MetadataDepositBase = ConstU64<1>;
 	type MetadataDepositPerByte = ConstU64<1>;
+	type DepositPolicy = ConfiguredDeposits<Test, Instance2>;
 	type ApprovalDeposit = ConstU64<1>;
+	type MaxApprovalsPerOwner = ConstU32<5>;
+	type MaxApprovalsPerDelegate = ConstU32<8>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<0>;
 	type StringLimit = ConstU32<50>;
-	type Freezer = TestFreezer;
+	type MetadataPolicy = ();
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Freezer = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Freezer = BenchmarkFreezer<Test, Instance2, 2>;
+	type RuntimeHoldReason = TestHoldReason;
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type WeightInfo = ();
-	type CallbackHandle = AssetsCallbackHandle;
+	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = ConstBool<false>;
+	type AccountMigration = ();
 	type Extra = ();
-	type RemoveItemsLimit = ConstU32<5>;
+	type ExtraDepositor = ();
+	type RemoveAccountsLimit = ConstU32<5>;
+	type RemoveApprovalsLimit = ConstU32<5>;
+	type AssetIdHasher = Twox64Concat;
 	#[cfg(feature = "runtime-benchmarks")]
-	type BenchmarkHelper = ();
+	type BenchmarkHelper = LocationHelper;
+}
+
+/// Reasons for which assets may be placed on hold in tests.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug,
+)]
+pub enum TestHoldReason {
+	Escrow,
+	Staking,
+	Governance,
+}
+
+/// A pallet which only knows about `ReservableCurrency`, used to exercise `CurrencyOf`.
+#[frame_support::pallet(dev_mode)]
+pub mod pallet_deposits {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement::KeepAlive, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Currency: ReservableCurrency<Self::AccountId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		pub fn place_deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::reserve(&who, amount)
+		}
+
+		pub fn release_deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::unreserve(&who, amount);
+			Ok(())
+		}
+
+		pub fn pay(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::transfer(&who, &dest, amount, KeepAlive)
+		}
+	}
+}
+
+parameter_types! {
+	pub const DepositAssetId: u32 = 1984;
+	pub const DepositHoldReason: TestHoldReason = TestHoldReason::Escrow;
+	pub const DustTreasury: u64 = 42;
+}
+
+impl pallet_deposits::Config for Test {
+	type Currency = CurrencyOf<Test, (), DepositAssetId, DepositHoldReason>;
+}
+
+/// A pallet which keeps a tag for asset accounts in their `Extra` data, through `StoredMap`.
+#[frame_support::pallet(dev_mode)]
+pub mod pallet_tags {
+	use frame_support::{pallet_prelude::*, traits::StoredMap};
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Tags: StoredMap<(u32, Self::AccountId), u32>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		pub fn tag(origin: OriginFor<T>, id: u32, tag: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Tags::insert(&(id, who), tag)
+		}
+
+		pub fn untag(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Tags::remove(&(id, who))
+		}
+	}
+}
+
+impl pallet_tags::Config for Test {
+	type Tags = Assets;
+}
+
+/// Identifiers under which assets may be frozen in tests.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug,
+)]
+pub enum TestFreezeId {
+	Vesting,
+	Voting,
+	Governance,
 }
 
 use std::collections::HashMap;
@@ -161,8 +386,241 @@ pub enum Hook {
 	Died(u32, u64),
 }
 parameter_types! {
+	pub static ApprovalDeposit: u64 = 1;
+	pub static AssetDeposit: u64 = 1;
+	pub static AssetAccountDeposit: u64 = 10;
+	pub static MetadataDepositPerByte: u64 = 1;
+	pub static TrackStats: bool = true;
+	pub static MaxHoldingsPerAccount: u32 = 0;
+	pub static MaxOwnedAssets: u32 = 0;
 	static Frozen: HashMap<(u32, u64), u64> = Default::default();
 	static Hooks: Vec<Hook> = Default::default();
+	static SecondFrozen: HashMap<(u32, u64), u64> = Default::default();
+	static SecondHooks: Vec<Hook> = Default::default();
+	static NonCompliant: Vec<u64> = Default::default();
+	static CreditDust: bool = false;
+	static StrictMetadata: bool = false;
+	static AssetCreators: Option<Vec<u64>> = None;
+	static UserDepositFactor: u64 = 10;
+	static ChargeFootprint: bool = false;
+	static ExtraPayer: Option<u64> = None;
+	static CollectiveRole: Option<Role> = None;
+}
+
+/// Accounts created to hold `Extra` data are paid for by the payer set with `set_extra_payer`.
+pub struct TestExtraDepositor;
+impl Convert<(u32, u64), Option<u64>> for TestExtraDepositor {
+	fn convert(_: (u32, u64)) -> Option<u64> {
+		ExtraPayer::get()
+	}
+}
+
+pub(crate) fn set_extra_payer(payer: u64) {
+	ExtraPayer::set(Some(payer));
+}
+
+/// Burns dust, unless `credit_dust_to_treasury` was called.
+pub struct TestDust;
+impl HandleDust<u64, Assets> for TestDust {
+	fn handle_dust(dust: fungibles::Credit<u64, Assets>) -> Option<u64> {
+		if CreditDust::get() {
+			ResolveDustTo::<DustTreasury, Assets>::handle_dust(dust)
+		} else {
+			None
+		}
+	}
+}
+
+pub(crate) fn credit_dust_to_treasury() {
+	CreditDust::set(true);
+}
+
+/// Accepts any metadata, unless `require_readable_metadata` was called.
+pub struct TestMetadataPolicy;
+impl MetadataPolicy for TestMetadataPolicy {
+	fn is_valid(name: &[u8], symbol: &[u8]) -> bool {
+		!StrictMetadata::get() || ReadableMetadata::is_valid(name, symbol)
+	}
+}
+
+pub(crate) fn require_readable_metadata() {
+	StrictMetadata::set(true);
+}
+
+/// An `Account` entry from before accounts had a status, to test lazy migrations with.
+#[derive(Encode, Decode)]
+pub struct OldAssetAccount {
+	pub balance: u64,
+	pub reason: ExistenceReason<u64, u64>,
+	pub extra: u32,
+}
+
+/// Translates `OldAssetAccount`s, all of which are liquid.
+pub struct TestAccountMigration;
+impl migration::lazy::TranslateAccount<Test, ()> for TestAccountMigration {
+	type Old = OldAssetAccount;
+
+	fn translate(_: &u32, _: &u64, old: OldAssetAccount) -> AssetAccountOf<Test, ()> {
+		AssetAccount {
+			balance: old.balance,
+			status: AccountStatus::Liquid,
+			reason: old.reason,
+			extra: old.extra,
+		}
+	}
+}
+
+/// Rewrites the `Account` entry of `who` for the asset `id` as an `OldAssetAccount`.
+pub(crate) fn put_old_account(id: u32, who: u64) {
+	let account = Account::<Test>::get(id, who).expect("the account exists");
+	let old =
+		OldAssetAccount { balance: account.balance, reason: account.reason, extra: account.extra };
+	storage::set(&Account::<Test>::hashed_key_for(id, who), &old.encode());
+}
+
+/// Asset ids which can only be created by `ForceOrigin`.
+pub const RESERVED_ASSET_IDS: sp_std::ops::Range<u32> = 1_000..2_000;
+
+/// Keeps `RESERVED_ASSET_IDS` out of `create`.
+pub struct ReservedAssetIds;
+impl Contains<u32> for ReservedAssetIds {
+	fn contains(id: &u32) -> bool {
+		RESERVED_ASSET_IDS.contains(id)
+	}
+}
+
+/// Signed origins may create assets as long as they are one of the creators set with
+/// `set_asset_creators`, if any.
+pub struct TestCreateOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, u32> for TestCreateOrigin {
+	type Success = u64;
+
+	fn try_origin(o: RuntimeOrigin, _: &u32) -> Result<u64, RuntimeOrigin> {
+		let who = frame_system::EnsureSigned::<u64>::try_origin(o)?;
+		match AssetCreators::get() {
+			Some(creators) if !creators.contains(&who) => Err(RuntimeOrigin::signed(who)),
+			_ => Ok(who),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &u32) -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(
+			AssetCreators::get().and_then(|c| c.first().copied()).unwrap_or(1),
+		))
+	}
+}
+
+pub(crate) fn set_asset_creators(creators: Vec<u64>) {
+	AssetCreators::set(Some(creators));
+}
+
+/// The account standing in for a collective, acting through `TestRoleOrigin`.
+pub const COLLECTIVE: u64 = 1_000;
+
+/// Signed by `COLLECTIVE`, an origin acts in the role set with `set_collective_role`, if any, of
+/// the assets below `USER_ASSET_IDS_START`.
+pub struct TestRoleOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, (u32, Role)> for TestRoleOrigin {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin, (id, role): &(u32, Role)) -> Result<(), RuntimeOrigin> {
+		let who = frame_system::EnsureSigned::<u64>::try_origin(o)?;
+		match CollectiveRole::get() {
+			Some(r) if who == COLLECTIVE && r == *role && *id < USER_ASSET_IDS_START => Ok(()),
+			_ => Err(RuntimeOrigin::signed(who)),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &(u32, Role)) -> Result<RuntimeOrigin, ()> {
+		Err(())
+	}
+}
+
+pub(crate) fn set_collective_role(role: Role) {
+	CollectiveRole::set(Some(role));
+}
+
+/// Asset ids from which deposits are scaled by `UserDepositFactor`.
+pub const USER_ASSET_IDS_START: u32 = 2_000;
+
+/// Prices accounts and approvals at 2 per item and 1 per byte, once `charge_footprint` is called.
+pub type TestFootprintDeposits = FootprintDeposits<Test, ConstU64<2>, ConstU64<1>>;
+
+/// Reserves the configured deposits, or the `TestFootprintDeposits` once `charge_footprint` is
+/// called, scaled up for asset ids from `USER_ASSET_IDS_START`.
+pub struct TestDepositPolicy;
+impl TestDepositPolicy {
+	fn scale(id: &u32, deposit: u64) -> u64 {
+		if *id >= USER_ASSET_IDS_START {
+			deposit * UserDepositFactor::get()
+		} else {
+			deposit
+		}
+	}
+}
+impl DepositPolicy<u32, u64> for TestDepositPolicy {
+	fn asset_deposit(id: &u32) -> u64 {
+		Self::scale(id, ConfiguredDeposits::<Test>::asset_deposit(id))
+	}
+
+	fn account_deposit(id: &u32, footprint: StorageFootprint) -> u64 {
+		let deposit = if ChargeFootprint::get() {
+			TestFootprintDeposits::account_deposit(id, footprint)
+		} else {
+			ConfiguredDeposits::<Test>::account_deposit(id, footprint)
+		};
+		Self::scale(id, deposit)
+	}
+
+	fn metadata_deposit(id: &u32, bytes: u32) -> u64 {
+		Self::scale(id, ConfiguredDeposits::<Test>::metadata_deposit(id, bytes))
+	}
+
+	fn max_metadata_deposit(bytes: u32) -> u64 {
+		let user_asset = USER_ASSET_IDS_START;
+		Self::scale(&user_asset, ConfiguredDeposits::<Test>::max_metadata_deposit(bytes))
+	}
+
+	fn approval_deposit(id: &u32, footprint: StorageFootprint) -> u64 {
+		let deposit = if ChargeFootprint::get() {
+			TestFootprintDeposits::approval_deposit(id, footprint)
+		} else {
+			ConfiguredDeposits::<Test>::approval_deposit(id, footprint)
+		};
+		Self::scale(id, deposit)
+	}
+}
+
+/// Switches `TestDepositPolicy` between the configured deposits and `TestFootprintDeposits`.
+pub(crate) fn charge_footprint(charge: bool) {
+	ChargeFootprint::set(charge);
+}
+
+pub(crate) fn set_user_deposit_factor(factor: u64) {
+	UserDepositFactor::set(factor);
+}
+
+/// The error with which `TestTransferFilter` vetoes transfers.
+pub const NOT_COMPLIANT: DispatchError = DispatchError::Other("NotCompliant");
+
+/// Vetoes every transfer from or to an account marked as non-compliant.
+pub struct TestTransferFilter;
+impl CheckTransfer<u32, u64, u64> for TestTransferFilter {
+	fn check_transfer(_: &u32, source: &u64, dest: &u64, _: u64) -> DispatchResult {
+		let non_compliant = NonCompliant::get();
+		ensure!(!non_compliant.contains(source) && !non_compliant.contains(dest), NOT_COMPLIANT);
+		Ok(())
+	}
+}
+
+pub(crate) fn set_non_compliant(who: u64) {
+	NonCompliant::mutate(|v| v.push(who));
+}
+
+pub(crate) fn clear_non_compliant() {
+	NonCompliant::take();
 }
 
 pub struct TestFreezer;
@@ -179,6 +637,34 @@ impl FrozenBalance<u32, u64, u64> for TestFreezer {
 	}
 }
 
+/// Another freezer with its own storage, configured alongside `TestFreezer`.
+pub struct SecondFreezer;
+impl FrozenBalance<u32, u64, u64> for SecondFreezer {
+	fn frozen_balance(asset: u32, who: &u64) -> Option<u64> {
+		SecondFrozen::get().get(&(asset, *who)).cloned()
+	}
+
+	fn died(asset: u32, who: &u64) {
+		SecondHooks::mutate(|v| v.push(Hook::Died(asset, *who)));
+	}
+}
+
+pub(crate) fn set_second_frozen_balance(asset: u32, who: u64, amount: u64) {
+	SecondFrozen::mutate(|v| {
+		v.insert((asset, who), amount);
+	});
+}
+
+pub(crate) fn clear_second_frozen_balance(asset: u32, who: u64) {
+	SecondFrozen::mutate(|v| {
+		v.remove(&(asset, who));
+	});
+}
+
+pub(crate) fn take_second_hooks() -> Vec<Hook> {
+	SecondHooks::take()
+}
+
 pub(crate) fn set_frozen_balance(asset: u32, who: u64, amount: u64) {
 	Frozen::mutate(|v| {
 		v.insert((asset, who), amount);
@@ -200,9 +686,7 @@ pub(crate) fn take_hooks() -> Vec<Hook> {
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
-
-	let config: pallet_assets::GenesisConfig<Test> = pallet_assets::GenesisConfig {
+	new_test_ext_with(pallet_assets::GenesisConfig {
 		assets: vec![
 			// id, owner, is_sufficient, min_balance
 			(999, 0, true, 1),
@@ -211,17 +695,54 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 			// id, name, symbol, decimals
 			(999, "Token Name".into(), "TOKEN".into(), 10),
 		],
+		reserve_metadata_deposits: false,
 		accounts: vec![
 			// id, account_id, balance
 			(999, 1, 100),
 		],
-	};
+		approvals: vec![
+			// id, owner, delegate, amount
+		],
+		frozen_assets: vec![],
+		frozen_accounts: vec![],
+	})
+}
 
+/// Like `new_test_ext`, but with the given genesis of the `Assets` instance.
+pub(crate) fn new_test_ext_with(
+	config: pallet_assets::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	config.assimilate_storage(&mut storage).unwrap();
 
 	let mut ext: sp_io::TestExternalities = storage.into();
 	// Clear thread local vars for https://github.com/paritytech/substrate/issues/10479.
 	ext.execute_with(|| take_hooks());
+	ext.execute_with(|| take_second_hooks());
+	ext.execute_with(|| Frozen::take());
+	ext.execute_with(|| SecondFrozen::take());
+	ext.execute_with(|| clear_non_compliant());
+	ext.execute_with(|| CreditDust::take());
+	ext.execute_with(|| AssetCreators::take());
+	ext.execute_with(|| UserDepositFactor::take());
+	ext.execute_with(|| ChargeFootprint::take());
+	ext.execute_with(|| ExtraPayer::take());
+	ext.execute_with(|| ApprovalDeposit::set(1));
+	ext.execute_with(|| StrictMetadata::take());
+	ext.execute_with(|| AssetDeposit::set(1));
+	ext.execute_with(|| MetadataDepositPerByte::set(1));
+	ext.execute_with(|| TrackStats::set(true));
+	ext.execute_with(|| CollectiveRole::take());
+	ext.execute_with(|| AssetAccountDeposit::set(10));
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
+
+/// Run `test` with `new_test_ext`, then check the storage invariants of both asset instances.
+pub(crate) fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		Assets::do_try_state().expect("storage invariants should hold");
+		LocationAssets::do_try_state().expect("storage invariants should hold");
+	});
+}
@@ -20,14 +20,23 @@
 use super::*;
 use crate::{mock::*, Error};
 use frame_support::{
-	assert_noop, assert_ok,
+	assert_noop, assert_ok, assert_storage_noop,
 	dispatch::GetDispatchInfo,
-	traits::{fungibles::InspectEnumerable, tokens::Preservation::Protect, Currency},
+	traits::{fungibles::InspectEnumerable, tokens::Preservation::Protect, Currency, Get},
+	weights::Weight,
 };
 use pallet_balances::Error as BalancesError;
 use sp_io::storage;
 use sp_runtime::{traits::ConvertInto, TokenError};
 
+mod balanced;
+mod currency;
+mod flags;
+mod fungibles_conformance;
+mod invariants;
+mod lazy_migration;
+mod location_ids;
+
 fn asset_ids() -> Vec<u32> {
 	let mut s: Vec<_> = Assets::asset_ids().collect();
 	s.sort();
@@ -40,9 +49,15 @@ fn asset_account_counts(asset_id: u32) -> (u32, u32) {
 	(asset.accounts, asset.sufficients)
 }
 
+/// returns tuple of the providers, consumers and sufficients references of `who`
+fn system_refs(who: u64) -> (u32, u32, u32) {
+	let account = frame_system::Account::<Test>::get(who);
+	(account.providers, account.consumers, account.sufficients)
+}
+
 #[test]
 fn transfer_should_never_burn() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		Balances::make_free_balance_be(&2, 100);
@@ -70,7 +85,7 @@ fn transfer_should_never_burn() {
 
 #[test]
 fn basic_minting_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
@@ -98,9 +113,49 @@ fn basic_minting_should_work() {
 	});
 }
 
+#[test]
+fn asset_enumeration_should_work() {
+	build_and_execute(|| {
+		fn owned_assets(who: u64) -> Vec<u32> {
+			let mut s: Vec<_> = Assets::owned_assets(who).collect();
+			s.sort();
+			s
+		}
+
+		// the trait and the pallet agree on the assets in existence.
+		let mut via_trait: Vec<_> = <Assets as InspectEnumerable<u64>>::asset_ids().collect();
+		via_trait.sort();
+		assert_eq!(via_trait, asset_ids());
+		assert_eq!(owned_assets(1), vec![999]);
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_eq!(asset_ids(), vec![0, 1, 999]);
+		// owning an asset doesn't mean holding it.
+		assert_eq!(owned_assets(1), vec![999]);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 2, 100));
+		assert_eq!(owned_assets(1), vec![0, 999]);
+		assert_eq!(owned_assets(2), vec![1]);
+
+		// accounts that die are no longer listed.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 1, 1, 100));
+		assert_eq!(owned_assets(1), vec![0, 1, 999]);
+		assert!(owned_assets(2).is_empty());
+
+		// destroyed assets are gone from both iterators.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(asset_ids(), vec![1, 999]);
+		assert_eq!(owned_assets(1), vec![1, 999]);
+	});
+}
+
 #[test]
 fn minting_too_many_insufficient_assets_fails() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, false, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2, 1, false, 1));
@@ -118,7 +173,7 @@ fn minting_too_many_insufficient_assets_fails() {
 
 #[test]
 fn minting_insufficient_asset_with_deposit_should_work_when_consumers_exhausted() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, false, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2, 1, false, 1));
@@ -136,7 +191,7 @@ fn minting_insufficient_asset_with_deposit_should_work_when_consumers_exhausted(
 
 #[test]
 fn minting_insufficient_assets_with_deposit_without_consumer_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100), TokenError::CannotCreate);
 		Balances::make_free_balance_be(&1, 100);
@@ -149,7 +204,7 @@ fn minting_insufficient_assets_with_deposit_without_consumer_should_work() {
 
 #[test]
 fn refunding_asset_deposit_with_burn_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
@@ -162,7 +217,7 @@ fn refunding_asset_deposit_with_burn_should_work() {
 
 #[test]
 fn refunding_asset_deposit_with_burn_disallowed_should_fail() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
@@ -173,7 +228,7 @@ fn refunding_asset_deposit_with_burn_disallowed_should_fail() {
 
 #[test]
 fn refunding_asset_deposit_without_burn_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100), TokenError::CannotCreate);
 		Balances::make_free_balance_be(&1, 100);
@@ -195,7 +250,7 @@ fn refunding_asset_deposit_without_burn_should_work() {
 /// Refunding reaps an account and calls the `FrozenBalance::died` hook.
 #[test]
 fn refunding_calls_died_hook() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
@@ -210,7 +265,7 @@ fn refunding_calls_died_hook() {
 
 #[test]
 fn refunding_with_sufficient_existence_reason_should_fail() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// create sufficient asset
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
@@ -227,7 +282,7 @@ fn refunding_with_sufficient_existence_reason_should_fail() {
 
 #[test]
 fn refunding_with_deposit_from_should_fail() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		// create asset account `2` with deposit from `1`
@@ -240,8 +295,8 @@ fn refunding_with_deposit_from_should_fail() {
 }
 
 #[test]
-fn refunding_frozen_with_consumer_ref_works() {
-	new_test_ext().execute_with(|| {
+fn refunding_frozen_with_consumer_ref_needs_admin() {
+	build_and_execute(|| {
 		// 1 will be an admin
 		// 2 will be a frozen account
 		Balances::make_free_balance_be(&1, 100);
@@ -260,8 +315,14 @@ fn refunding_frozen_with_consumer_ref_works() {
 		// freeze asset account `2` and asset `0`
 		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
 		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
-		// refund works
-		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, true));
+		// the account cannot refund itself...
+		assert_noop!(Assets::refund(RuntimeOrigin::signed(2), 0, true), Error::<Test>::Frozen);
+		// ...but the admin can
+		assert_noop!(
+			Assets::force_refund(RuntimeOrigin::signed(2), 0, 2, true),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::force_refund(RuntimeOrigin::signed(1), 0, 2, true));
 		assert!(!Account::<Test>::contains_key(0, &2));
 		assert_eq!(System::consumers(&2), 0);
 		assert_eq!(asset_account_counts(0), (1, 0));
@@ -269,8 +330,8 @@ fn refunding_frozen_with_consumer_ref_works() {
 }
 
 #[test]
-fn refunding_frozen_with_deposit_works() {
-	new_test_ext().execute_with(|| {
+fn refunding_frozen_with_deposit_needs_admin() {
+	build_and_execute(|| {
 		// 1 will be an asset admin
 		// 2 will be a frozen account
 		Balances::make_free_balance_be(&1, 100);
@@ -289,11 +350,23 @@ fn refunding_frozen_with_deposit_works() {
 		assert_eq!(Assets::balance(0, 1), 50);
 		assert_eq!(Assets::balance(0, 2), 50);
 		assert_eq!(asset_account_counts(0), (2, 0));
-		// ensure refundable even if asset account and asset is frozen
+		// a frozen account cannot refund itself, whether or not it would burn
 		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+		assert_noop!(Assets::refund(RuntimeOrigin::signed(2), 0, true), Error::<Test>::Frozen);
+		assert_noop!(Assets::refund(RuntimeOrigin::signed(2), 0, false), Error::<Test>::Frozen);
+		// nor can any account of a frozen asset
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 2));
 		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
-		// success
-		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, true));
+		assert_noop!(Assets::refund(RuntimeOrigin::signed(2), 0, true), Error::<Test>::Frozen);
+		// the deposit stays reserved
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		// ensure the admin can refund even if asset account and asset is frozen
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+		assert_noop!(
+			Assets::force_refund(RuntimeOrigin::signed(1), 0, 2, false),
+			Error::<Test>::WouldBurn
+		);
+		assert_ok!(Assets::force_refund(RuntimeOrigin::signed(1), 0, 2, true));
 		assert!(!Account::<Test>::contains_key(0, &2));
 		assert_eq!(Balances::reserved_balance(&2), 0);
 		assert_eq!(System::consumers(&2), 0);
@@ -301,9 +374,175 @@ fn refunding_frozen_with_deposit_works() {
 	});
 }
 
+#[test]
+fn sufficient_accounts_should_release_the_system_account_however_they_die() {
+	build_and_execute(|| {
+		ApprovalDeposit::set(0);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		let fund = |who| {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 100));
+			// the asset is all that keeps `who` on-chain.
+			assert_eq!(system_refs(who), (0, 0, 1));
+		};
+
+		// burned away.
+		fund(2);
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert!(!System::account_exists(&2));
+
+		// transferred away, in full and leaving dust behind.
+		fund(2);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 100));
+		assert!(!System::account_exists(&2));
+		assert_eq!(system_refs(3), (0, 0, 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), 0, 2, 95));
+		assert!(!System::account_exists(&3));
+		assert_eq!(system_refs(2), (0, 0, 1));
+
+		// spent by a delegate.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(2), 0, 4, 100));
+		assert_ok!(Assets::transfer_approved(
+			RuntimeOrigin::signed(4),
+			0,
+			2,
+			3,
+			Assets::balance(0, 2)
+		));
+		assert!(!System::account_exists(&2));
+
+		// destroyed together with the asset.
+		assert_eq!(system_refs(3), (0, 0, 1));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert!(!System::account_exists(&3));
+		assert_eq!(asset_account_counts(0), (0, 0));
+	});
+}
+
+#[test]
+fn consumer_accounts_should_release_their_reference_however_they_die() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 10));
+		for who in 2..=4 {
+			Balances::make_free_balance_be(&who, 100);
+		}
+		let fund = |who| {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 100));
+			assert_eq!(system_refs(who), (1, 1, 0));
+		};
+
+		// burned away.
+		fund(2);
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_eq!(system_refs(2), (1, 0, 0));
+
+		// transferred away, in full and leaving dust behind.
+		fund(2);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 100));
+		assert_eq!(system_refs(2), (1, 0, 0));
+		assert_eq!(system_refs(3), (1, 1, 0));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), 0, 2, 95));
+		assert_eq!(system_refs(3), (1, 0, 0));
+		assert_eq!(system_refs(2), (1, 1, 0));
+
+		// refunded, burning the balance.
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, true));
+		assert_eq!(system_refs(2), (1, 0, 0));
+
+		// refunded, returning the deposit which kept the account alive.
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(4), 0));
+		assert_eq!(system_refs(4), (1, 1, 0));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(4), 0, false));
+		assert_eq!(system_refs(4), (1, 0, 0));
+
+		// destroyed together with the asset.
+		fund(3);
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_eq!(system_refs(3), (1, 0, 0));
+		assert_eq!(asset_account_counts(0), (0, 0));
+	});
+}
+
+#[test]
+fn repair_refs_should_converge_on_the_right_counters() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		for who in 2..=6 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 10));
+		}
+		// corrupt the counters of the asset and the reference of one of its accounts.
+		Asset::<Test>::mutate(0, |maybe_details| {
+			let details = maybe_details.as_mut().unwrap();
+			details.accounts = 2;
+			details.sufficients = 9;
+		});
+		frame_system::Account::<Test>::mutate(3, |account| account.sufficients = 0);
+
+		assert_noop!(
+			Assets::repair_refs(RuntimeOrigin::signed(1), 0, 10),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(Assets::repair_refs(RuntimeOrigin::root(), 1, 10), Error::<Test>::Unknown);
+
+		// the counters are only set once every account was counted.
+		System::reset_events();
+		for _ in 0..2 {
+			assert_ok!(Assets::repair_refs(RuntimeOrigin::root(), 0, 2));
+			assert!(RefRepairs::<Test>::contains_key(0));
+			assert_eq!(asset_account_counts(0), (2, 9));
+		}
+		assert_ok!(Assets::repair_refs(RuntimeOrigin::root(), 0, 2));
+		assert!(!RefRepairs::<Test>::contains_key(0));
+		assert_eq!(asset_account_counts(0), (5, 5));
+		assert_eq!(system_refs(3), (0, 0, 1));
+		let reports: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Assets(crate::Event::RefsRepaired {
+					visited,
+					restored,
+					accounts,
+					sufficients,
+					finished,
+					..
+				}) => Some((visited, restored, accounts, sufficients, finished)),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(reports.iter().map(|report| report.1).sum::<u32>(), 1);
+		assert_eq!(
+			reports.into_iter().map(|(v, _, a, s, f)| (v, a, s, f)).collect::<Vec<_>>(),
+			vec![(2, 2, 2, false), (2, 4, 4, false), (1, 5, 5, true)]
+		);
+
+		// repairing the right counters changes nothing.
+		assert_ok!(Assets::repair_refs(RuntimeOrigin::root(), 0, 10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RefsRepaired {
+			asset_id: 0,
+			visited: 5,
+			restored: 0,
+			accounts: 5,
+			sufficients: 5,
+			finished: true,
+		}));
+		assert_eq!(asset_account_counts(0), (5, 5));
+
+		// a missing consumer reference is restored as well.
+		Balances::make_free_balance_be(&7, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, false, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 7, 10));
+		frame_system::Account::<Test>::mutate(7, |account| account.consumers = 0);
+		Asset::<Test>::mutate(1, |maybe_details| maybe_details.as_mut().unwrap().accounts = 0);
+		assert_ok!(Assets::repair_refs(RuntimeOrigin::root(), 1, 10));
+		assert_eq!(asset_account_counts(1), (1, 0));
+		assert_eq!(system_refs(7), (1, 1, 0));
+	});
+}
+
 #[test]
 fn approval_lifecycle_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// can't approve non-existent token
 		assert_noop!(
 			Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50),
@@ -328,8 +567,162 @@ fn approval_lifecycle_works() {
 }
 
 #[test]
-fn transfer_approved_all_funds() {
+fn approvals_per_owner_are_limited() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		// up to `MaxApprovalsPerOwner` delegates can be approved.
+		for delegate in 10..15 {
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, delegate, 10));
+		}
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 5);
+		assert_noop!(
+			Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 15, 10),
+			Error::<Test>::TooManyApprovals
+		);
+		// topping up an existing approval is still fine, as are approvals of other owners.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 10, 10));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(2), 0, 15, 10));
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 5);
+
+		// cancelling, force cancelling and consuming an approval frees up a slot.
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(1), 0, 10));
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 4);
+		assert_ok!(Assets::force_cancel_approval(RuntimeOrigin::signed(1), 0, 1, 11));
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 3);
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(12), 0, 1, 3, 10));
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 2);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 15, 10));
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 3);
+
+		// destruction clears all counters.
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_eq!(ApprovalCount::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn approvals_are_indexed_by_delegate() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 3, 100));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(3), 1, 2, 30));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 4, 10));
+		let mut approvals = Assets::approvals_for_delegate(&2);
+		approvals.sort();
+		assert_eq!(approvals, vec![(0, 1, 50), (1, 3, 30)]);
+		assert_eq!(DelegateApprovalCount::<Test>::get(2), 2);
+		assert_eq!(Assets::approvals_for_delegate(&4), vec![(0, 1, 10)]);
+
+		// using part of an approval keeps it indexed, using all of it removes it.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 5, 20));
+		assert!(Assets::approvals_for_delegate(&2).contains(&(0, 1, 30)));
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 5, 30));
+		assert_eq!(Assets::approvals_for_delegate(&2), vec![(1, 3, 30)]);
+		assert_eq!(DelegateApprovalCount::<Test>::get(2), 1);
+
+		// cancelling and force cancelling remove it too.
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(3), 1, 2));
+		assert_eq!(Assets::approvals_for_delegate(&2), vec![]);
+		assert!(!DelegateApprovalCount::<Test>::contains_key(2));
+		assert_ok!(Assets::force_cancel_approval(RuntimeOrigin::signed(1), 0, 1, 4));
+		assert_eq!(Assets::approvals_for_delegate(&4), vec![]);
+
+		// so does destroying the asset.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_eq!(DelegateApprovals::<Test>::iter().count(), 0);
+		assert_eq!(DelegateApprovalCount::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn approvals_per_delegate_are_limited() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		for owner in 10..19 {
+			Balances::make_free_balance_be(&owner, 100);
+		}
+
+		// up to `MaxApprovalsPerDelegate` approvals can be given to a delegate, across assets.
+		for owner in 10..14 {
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(owner), 0, 2, 10));
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(owner), 1, 2, 10));
+		}
+		assert_eq!(DelegateApprovalCount::<Test>::get(2), 8);
+		assert_noop!(
+			Assets::approve_transfer(RuntimeOrigin::signed(18), 0, 2, 10),
+			Error::<Test>::TooManyDelegateApprovals
+		);
+		// topping up an existing approval is still fine, as are approvals to other delegates.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(10), 0, 2, 10));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(18), 0, 3, 10));
+		assert_eq!(DelegateApprovalCount::<Test>::get(2), 8);
+
+		// cancelling an approval frees up a slot.
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(10), 0, 2));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(18), 0, 2, 10));
+		assert_eq!(Assets::approvals_for_delegate(&2).len(), 8);
+	});
+}
+
+#[test]
+fn migration_v2_counts_approvals() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
 	new_test_ext().execute_with(|| {
+		let approval = Approval { amount: 10, deposit: 1 };
+		Approvals::<Test>::insert((0, 1, 2), approval.clone());
+		Approvals::<Test>::insert((0, 1, 3), approval.clone());
+		Approvals::<Test>::insert((0, 2, 3), approval.clone());
+		Approvals::<Test>::insert((1, 1, 2), approval);
+		StorageVersion::new(1).put::<Assets>();
+
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 2);
+		assert_eq!(ApprovalCount::<Test>::get(0, 2), 1);
+		assert_eq!(ApprovalCount::<Test>::get(1, 1), 1);
+		assert_eq!(ApprovalCount::<Test>::iter().count(), 3);
+		assert_eq!(StorageVersion::get::<Assets>(), 2);
+	});
+}
+
+#[test]
+fn migration_v3_counts_assets() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		AssetCount::<Test>::kill();
+		StorageVersion::new(2).put::<Assets>();
+
+		migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		// the genesis asset is counted too.
+		assert_eq!(Assets::asset_count(), 3);
+		assert_eq!(StorageVersion::get::<Assets>(), 3);
+	});
+}
+
+#[test]
+fn transfer_approved_all_funds() {
+	build_and_execute(|| {
 		// can't approve non-existent token
 		assert_noop!(
 			Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50),
@@ -354,7 +747,7 @@ fn transfer_approved_all_funds() {
 
 #[test]
 fn approval_deposits_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		let e = BalancesError::<Test>::InsufficientBalance;
@@ -373,9 +766,166 @@ fn approval_deposits_work() {
 	});
 }
 
+#[test]
+fn partial_approved_transfers_should_report_the_remaining_allowance() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		let transferred = |amount, remaining| {
+			System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferredApproved {
+				asset_id: 0,
+				owner: 1,
+				delegate: 2,
+				destination: 3,
+				amount,
+				remaining,
+			}));
+		};
+
+		// the deposit stays reserved in full for as long as any allowance is left.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 20));
+		transferred(20, 30);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 29));
+		transferred(29, 1);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)).unwrap().amount, 1);
+
+		// topping up adds to what remains, without reserving any more.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 12),
+			Error::<Test>::Unapproved
+		);
+
+		// spending the rest returns the deposit and removes the approval.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 11));
+		transferred(11, 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)), None);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert_eq!(Assets::balance(0, 3), 60);
+	});
+}
+
+#[test]
+fn approved_transfer_event_reports_reserved_deposit() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 10);
+
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 50,
+			deposit: 1,
+		}));
+
+		// topping up does not reserve anything more.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 10,
+			deposit: 0,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		// only the difference is reserved after the deposit constant increased.
+		ApprovalDeposit::set(3);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 10,
+			deposit: 2,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 3);
+
+		// nothing is reserved or released after the deposit constant decreased.
+		ApprovalDeposit::set(1);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 10,
+			deposit: 0,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 3);
+	});
+}
+
+#[test]
+fn zero_approval_deposit_should_not_touch_the_currency() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		ApprovalDeposit::set(0);
+		System::reset_events();
+
+		// approvals need no funds to reserve, so the owner needs no native balance either.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 4, 50));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 50,
+			deposit: 0,
+		}));
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)).unwrap().deposit, 0);
+
+		// the approvals are consumed, cancelled and force cancelled.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 5, 20));
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 5, 30));
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Assets::force_cancel_approval(RuntimeOrigin::root(), 0, 1, 4));
+		assert_eq!(Approvals::<Test>::iter().count(), 0);
+
+		// not a single reserve or unreserve was made.
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Balances(pallet_balances::Event::Reserved { .. }) |
+				RuntimeEvent::Balances(pallet_balances::Event::Unreserved { .. }) |
+				RuntimeEvent::Assets(crate::Event::DepositUpdated { .. })
+		)));
+
+		// once the constant is raised, an existing approval is topped up.
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		Balances::make_free_balance_be(&1, 10);
+		ApprovalDeposit::set(1);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 10,
+			deposit: 1,
+		}));
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)).unwrap().deposit, 1);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		// and its deposit is returned once it is used up.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 5, 20));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
 #[test]
 fn cannot_transfer_more_than_approved() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 2);
@@ -387,7 +937,7 @@ fn cannot_transfer_more_than_approved() {
 
 #[test]
 fn cannot_transfer_more_than_exists() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 2);
@@ -399,7 +949,7 @@ fn cannot_transfer_more_than_exists() {
 
 #[test]
 fn cancel_approval_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 2);
@@ -427,9 +977,53 @@ fn cancel_approval_works() {
 	});
 }
 
+#[test]
+fn reject_approval_should_return_the_deposit_to_the_owner() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+		Balances::make_free_balance_be(&2, 2);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		// only the delegate of an existing approval may reject it.
+		assert_noop!(
+			Assets::reject_approval(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::Unknown
+		);
+		assert_noop!(
+			Assets::reject_approval(RuntimeOrigin::signed(3), 0, 1),
+			Error::<Test>::Unknown
+		);
+		assert_noop!(
+			Assets::reject_approval(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::Unknown
+		);
+
+		assert_ok!(Assets::reject_approval(RuntimeOrigin::signed(2), 0, 1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalCancelled {
+			asset_id: 0,
+			owner: 1,
+			delegate: 2,
+			by_delegate: true,
+		}));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert_eq!(Assets::allowance(0, &1, &2), 0);
+		// the deposit went back to the owner, not to the delegate.
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 2);
+		assert_eq!(Balances::free_balance(&2), 2);
+		assert_noop!(
+			Assets::reject_approval(RuntimeOrigin::signed(2), 0, 1),
+			Error::<Test>::Unknown
+		);
+	});
+}
+
 #[test]
 fn force_cancel_approval_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 2);
@@ -459,9 +1053,70 @@ fn force_cancel_approval_works() {
 	});
 }
 
+#[test]
+fn force_cancel_approvals_of_should_page_through_the_approvals() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		for delegate in 3..8 {
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(2), 0, delegate, 10));
+		}
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 10));
+		assert_eq!(Balances::reserved_balance(&2), 5);
+
+		assert_noop!(
+			Assets::force_cancel_approvals_of(RuntimeOrigin::signed(2), 0, 2, 5),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::force_cancel_approvals_of(RuntimeOrigin::signed(1), 1, 2, 5),
+			Error::<Test>::Unknown
+		);
+
+		// more approvals than `max`: the rest are left for the next call.
+		System::reset_events();
+		let post_info = Assets::force_cancel_approvals_of(RuntimeOrigin::signed(1), 0, 2, 2);
+		assert_eq!(
+			post_info.unwrap().actual_weight,
+			Some(<Test as Config>::WeightInfo::force_cancel_approvals_of(2))
+		);
+		let cancelled: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Assets(crate::Event::ApprovalCancelled {
+					asset_id: 0,
+					owner: 2,
+					delegate,
+					by_delegate: false,
+				}) => Some(delegate),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(cancelled.len(), 2);
+		assert_eq!(Assets::approvals_of(0, &2).len(), 3);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 4);
+		assert_eq!(Balances::reserved_balance(&2), 3);
+
+		// the force origin can cancel the rest, and is only charged for what it cancelled.
+		let post_info = Assets::force_cancel_approvals_of(RuntimeOrigin::root(), 0, 2, 100);
+		assert_eq!(
+			post_info.unwrap().actual_weight,
+			Some(<Test as Config>::WeightInfo::force_cancel_approvals_of(3))
+		);
+		assert!(Assets::approvals_of(0, &2).is_empty());
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_ok!(Assets::force_cancel_approvals_of(RuntimeOrigin::signed(1), 0, 2, 5));
+
+		// the approvals of other owners are left alone.
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		assert_eq!(Assets::allowance(0, &1, &3), 10);
+	});
+}
+
 #[test]
 fn lifecycle_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
 		assert_eq!(Balances::reserved_balance(&1), 1);
@@ -479,9 +1134,14 @@ fn lifecycle_should_work() {
 
 		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
 		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
 		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Destroyed {
+			asset_id: 0,
+			owner: 1,
+			deposit_returned: 4,
+		}));
 
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
@@ -501,11 +1161,23 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
+		// this time the metadata is destroyed first, before the accounts are.
 		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
 		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_metadata(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MetadataCleared {
+			asset_id: 0,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
 		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Destroyed {
+			asset_id: 0,
+			owner: 1,
+			deposit_returned: 1,
+		}));
 
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
@@ -515,9 +1187,31 @@ fn lifecycle_should_work() {
 	});
 }
 
+#[test]
+fn destroy_metadata_should_only_work_while_destroying() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_noop!(Assets::destroy_metadata(RuntimeOrigin::signed(1), 0), Error::<Test>::Unknown);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0], vec![0], 12));
+		assert_noop!(
+			Assets::destroy_metadata(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::IncorrectStatus
+		);
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_metadata(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Assets::destroy_metadata(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoMetadata
+		);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+	});
+}
+
 #[test]
 fn destroy_should_refund_approvals() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 10, 100));
@@ -530,8 +1224,19 @@ fn destroy_should_refund_approvals() {
 		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
 
 		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		// approvals must be destroyed before the asset can be.
+		assert_noop!(
+			Assets::finish_destroy(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ApprovalsRemaining
+		);
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalsDestroyed {
+			asset_id: 0,
+			approvals_destroyed: 3,
+			approvals_remaining: 0,
+			deposit_returned: 3,
+		}));
 		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
 
 		assert_eq!(Balances::reserved_balance(&1), 0);
@@ -544,7 +1249,7 @@ fn destroy_should_refund_approvals() {
 
 #[test]
 fn partial_destroy_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
@@ -556,47 +1261,208 @@ fn partial_destroy_should_work() {
 		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
 
 		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
 		// Asset is in use, as all the accounts have not yet been destroyed.
 		// We need to call destroy_accounts or destroy_approvals again until asset is completely
 		// cleaned up.
-		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0), Error::<Test>::InUse);
+		assert_noop!(
+			Assets::finish_destroy(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AccountsRemaining
+		);
 
 		System::assert_has_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
 			asset_id: 0,
 			accounts_destroyed: 5,
 			accounts_remaining: 2,
+			deposit_returned: 0,
 		}));
 		System::assert_has_event(RuntimeEvent::Assets(crate::Event::ApprovalsDestroyed {
 			asset_id: 0,
 			approvals_destroyed: 0,
 			approvals_remaining: 0,
+			deposit_returned: 0,
 		}));
 		// Partially destroyed Asset should continue to exist
 		assert!(Asset::<Test>::contains_key(0));
 
 		// Second call to destroy on PartiallyDestroyed asset
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
 		System::assert_has_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
 			asset_id: 0,
 			accounts_destroyed: 2,
 			accounts_remaining: 0,
+			deposit_returned: 0,
 		}));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
 		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
 
-		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Destroyed { asset_id: 0 }));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Destroyed {
+			asset_id: 0,
+			owner: 1,
+			deposit_returned: 0,
+		}));
 
 		// Destroyed Asset should not exist
 		assert!(!Asset::<Test>::contains_key(0));
 	})
 }
 
+#[test]
+fn destroy_through_trait_should_work() {
+	use frame_support::traits::tokens::fungibles::Destroy;
+
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 5));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 5));
+
+		assert_noop!(
+			<Assets as Destroy<u64>>::start_destroy(0, Some(2)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(<Assets as Destroy<u64>>::start_destroy(0, Some(1)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DestructionStarted {
+			asset_id: 0,
+		}));
+
+		// the number of removed items is reported back, and never exceeds the limit.
+		assert_eq!(<Assets as Destroy<u64>>::destroy_accounts(0, 0), Ok(0));
+		assert_eq!(<Assets as Destroy<u64>>::destroy_accounts(0, 2), Ok(2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
+			asset_id: 0,
+			accounts_destroyed: 2,
+			accounts_remaining: 1,
+			deposit_returned: 0,
+		}));
+		assert_noop!(<Assets as Destroy<u64>>::finish_destroy(0), Error::<Test>::AccountsRemaining);
+		assert_eq!(<Assets as Destroy<u64>>::destroy_accounts(0, 2), Ok(1));
+
+		assert_eq!(<Assets as Destroy<u64>>::destroy_approvals(0, 0), Ok(0));
+		assert_eq!(Approvals::<Test>::iter_prefix((0,)).count(), 2);
+		assert_eq!(<Assets as Destroy<u64>>::destroy_approvals(0, 1), Ok(1));
+		assert_noop!(
+			<Assets as Destroy<u64>>::finish_destroy(0),
+			Error::<Test>::ApprovalsRemaining
+		);
+		assert_eq!(<Assets as Destroy<u64>>::destroy_approvals(0, 1), Ok(1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalsDestroyed {
+			asset_id: 0,
+			approvals_destroyed: 1,
+			approvals_remaining: 0,
+			deposit_returned: 1,
+		}));
+
+		assert_ok!(<Assets as Destroy<u64>>::finish_destroy(0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Destroyed {
+			asset_id: 0,
+			owner: 1,
+			deposit_returned: 0,
+		}));
+		assert!(!Asset::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	})
+}
+
+#[test]
+fn destroy_calls_should_take_the_batch_size_asked_for() {
+	type W = <Test as Config>::WeightInfo;
+
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		for who in 1..=7 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 10));
+		}
+		for delegate in 2..=4 {
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, delegate, 5));
+		}
+
+		// zero only checks the status of the asset.
+		assert_noop!(
+			Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::IncorrectStatus
+		);
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		let post_info = Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, 0);
+		assert_eq!(post_info.unwrap().actual_weight, Some(W::destroy_accounts(0)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
+			asset_id: 0,
+			accounts_destroyed: 0,
+			accounts_remaining: 7,
+			deposit_returned: 0,
+		}));
+
+		let post_info = Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, 3);
+		assert_eq!(post_info.unwrap().actual_weight, Some(W::destroy_accounts(3)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
+			asset_id: 0,
+			accounts_destroyed: 3,
+			accounts_remaining: 4,
+			deposit_returned: 0,
+		}));
+
+		// more than the limit is capped, and only what was removed is charged.
+		let post_info = Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX);
+		assert_eq!(post_info.unwrap().actual_weight, Some(W::destroy_accounts(4)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 0);
+
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalsDestroyed {
+			asset_id: 0,
+			approvals_destroyed: 0,
+			approvals_remaining: 3,
+			deposit_returned: 0,
+		}));
+		let post_info = Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, 1);
+		assert_eq!(post_info.unwrap().actual_weight, Some(W::destroy_approvals(1)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalsDestroyed {
+			asset_id: 0,
+			approvals_destroyed: 1,
+			approvals_remaining: 2,
+			deposit_returned: 1,
+		}));
+		let post_info = Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX);
+		assert_eq!(post_info.unwrap().actual_weight, Some(W::destroy_approvals(2)));
+
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn freezing_and_thawing_during_destruction_should_not_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+
+		let e = Error::<Test>::AssetNotLive;
+		assert_noop!(Assets::freeze(RuntimeOrigin::signed(1), 0, 1), e);
+		assert_noop!(Assets::thaw(RuntimeOrigin::signed(1), 0, 2), e);
+		assert_noop!(Assets::block(RuntimeOrigin::signed(1), 0, 1), e);
+		assert_noop!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0), e);
+		assert_noop!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0), e);
+
+		// destruction proceeds unaffected.
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(Asset::<Test>::get(0).is_none());
+	});
+}
+
 #[test]
 fn non_providing_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 
 		Balances::make_free_balance_be(&0, 100);
@@ -625,7 +1491,7 @@ fn non_providing_should_work() {
 
 #[test]
 fn min_balance_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
@@ -670,7 +1536,7 @@ fn min_balance_should_work() {
 
 #[test]
 fn querying_total_supply_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -688,7 +1554,7 @@ fn querying_total_supply_should_work() {
 
 #[test]
 fn transferring_amount_below_available_balance_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -700,7 +1566,7 @@ fn transferring_amount_below_available_balance_should_work() {
 
 #[test]
 fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -718,7 +1584,7 @@ fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 
 #[test]
 fn transferring_frozen_user_should_not_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -731,7 +1597,7 @@ fn transferring_frozen_user_should_not_work() {
 
 #[test]
 fn transferring_frozen_asset_should_not_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -746,8 +1612,276 @@ fn transferring_frozen_asset_should_not_work() {
 }
 
 #[test]
-fn approve_transfer_frozen_asset_should_not_work() {
+fn asset_status_transitions_should_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		let status = || Asset::<Test>::get(0).unwrap().status;
+
+		// Live -> Frozen -> Live
+		assert_noop!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0), Error::<Test>::NotFrozen);
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_eq!(status(), AssetStatus::Frozen);
+		assert_noop!(
+			Assets::freeze_asset(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AssetNotLive
+		);
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0));
+		assert_eq!(status(), AssetStatus::Live);
+
+		// Live -> Frozen via `force_asset_status` and back.
+		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), 0, 1, 1, 1, 1, 1, true, true));
+		assert_eq!(status(), AssetStatus::Frozen);
+		assert_ok!(Assets::force_asset_status(
+			RuntimeOrigin::root(),
+			0,
+			1,
+			1,
+			1,
+			1,
+			1,
+			true,
+			false
+		));
+		assert_eq!(status(), AssetStatus::Live);
+
+		// Frozen -> Destroying
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(status(), AssetStatus::Destroying);
+
+		// Destroying is final.
+		assert_noop!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0), Error::<Test>::NotFrozen);
+		assert_noop!(
+			Assets::freeze_asset(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AssetNotLive
+		);
+		assert_noop!(
+			Assets::force_asset_status(RuntimeOrigin::root(), 0, 1, 1, 1, 1, 1, true, false),
+			Error::<Test>::IncorrectStatus
+		);
+		assert_noop!(
+			Assets::start_destroy(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AssetNotLive
+		);
+
+		// Live -> Destroying
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Asset::<Test>::get(1).unwrap().status, AssetStatus::Destroying);
+	});
+}
+
+#[test]
+fn migration_v1_folds_is_frozen_into_status() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	// without the genesis assets of `new_test_ext`, which are stored in the current layout and
+	// can't be decoded by the migration.
+	new_test_ext_with(Default::default()).execute_with(|| {
+		let old = |is_frozen| migration::v1::OldAssetDetails::<u64, u64, u64> {
+			owner: 1,
+			issuer: 1,
+			admin: 1,
+			freezer: 1,
+			supply: 0,
+			deposit: 0,
+			min_balance: 1,
+			is_sufficient: false,
+			accounts: 0,
+			sufficients: 0,
+			approvals: 0,
+			is_frozen,
+		};
+		unhashed::put(&Asset::<Test>::hashed_key_for(0), &old(false));
+		unhashed::put(&Asset::<Test>::hashed_key_for(1), &old(true));
+		StorageVersion::new(0).put::<Assets>();
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Asset::<Test>::get(0).unwrap().status, AssetStatus::Live);
+		assert_eq!(Asset::<Test>::get(1).unwrap().status, AssetStatus::Frozen);
+		assert_eq!(StorageVersion::get::<Assets>(), 1);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn migration_v1_try_runtime_checks_should_work() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	// without the genesis assets of `new_test_ext`, which are stored in the current layout and
+	// can't be decoded by the migration.
+	new_test_ext_with(Default::default()).execute_with(|| {
+		let old = migration::v1::OldAssetDetails::<u64, u64, u64> {
+			owner: 1,
+			issuer: 1,
+			admin: 1,
+			freezer: 1,
+			supply: 0,
+			deposit: 0,
+			min_balance: 1,
+			is_sufficient: false,
+			accounts: 0,
+			sufficients: 0,
+			approvals: 0,
+			is_frozen: true,
+		};
+		unhashed::put(&Asset::<Test>::hashed_key_for(0), &old);
+		unhashed::put(&Asset::<Test>::hashed_key_for(1), &old);
+		StorageVersion::new(0).put::<Assets>();
+
+		assert_ok!(migration::v1::MigrateToV1::<Test>::try_on_runtime_upgrade(true));
+		assert_eq!(Asset::<Test>::iter_values().count(), 2);
+
+		// running it again is refused, since the storage is no longer at version 0.
+		assert!(migration::v1::MigrateToV1::<Test>::try_on_runtime_upgrade(true).is_err());
+	});
+}
+
+#[test]
+fn migration_v4_records_metadata_deposits() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 10], vec![0; 10], 12));
+		let old = migration::v4::OldAssetDetails::<u64, u64, u64> {
+			owner: 1,
+			issuer: 1,
+			admin: 1,
+			freezer: 1,
+			supply: 0,
+			deposit: 0,
+			min_balance: 1,
+			is_sufficient: true,
+			accounts: 0,
+			sufficients: 0,
+			approvals: 0,
+			status: AssetStatus::Live,
+		};
+		unhashed::put(&Asset::<Test>::hashed_key_for(0), &old);
+		unhashed::put(&Asset::<Test>::hashed_key_for(1), &old);
+		StorageVersion::new(3).put::<Assets>();
+
+		migration::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Asset::<Test>::get(0).unwrap().metadata_deposit, 21);
+		assert_eq!(Asset::<Test>::get(1).unwrap().metadata_deposit, 0);
+		assert_eq!(Asset::<Test>::get(1).unwrap().owner, 1);
+		assert_eq!(StorageVersion::get::<Assets>(), 4);
+	});
+}
+
+#[test]
+fn migration_v5_indexes_delegate_approvals() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let approval = Approval { amount: 10, deposit: 1 };
+		Approvals::<Test>::insert((0, 1, 2), approval.clone());
+		Approvals::<Test>::insert((0, 1, 3), approval.clone());
+		Approvals::<Test>::insert((0, 2, 3), approval.clone());
+		Approvals::<Test>::insert((1, 1, 2), approval);
+		StorageVersion::new(4).put::<Assets>();
+
+		migration::v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+		assert!(DelegateApprovals::<Test>::contains_key(2, (0, 1)));
+		assert!(DelegateApprovals::<Test>::contains_key(2, (1, 1)));
+		assert!(DelegateApprovals::<Test>::contains_key(3, (0, 1)));
+		assert!(DelegateApprovals::<Test>::contains_key(3, (0, 2)));
+		assert_eq!(DelegateApprovals::<Test>::iter().count(), 4);
+		assert_eq!(DelegateApprovalCount::<Test>::get(2), 2);
+		assert_eq!(DelegateApprovalCount::<Test>::get(3), 2);
+		assert_eq!(StorageVersion::get::<Assets>(), 5);
+	});
+}
+
+#[test]
+fn migration_v6_counts_holdings() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		for id in 0..3 {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 1));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 2, 10));
+		}
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10));
+		put_old_account(1, 2);
+		let _ = HoldingCount::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(5).put::<Assets>();
+
+		migration::v6::MigrateToV6::<Test>::on_runtime_upgrade();
+
+		assert_eq!(HoldingCount::<Test>::get(2), 3);
+		assert_eq!(HoldingCount::<Test>::get(3), 1);
+		assert_eq!(HoldingCount::<Test>::iter().count(), 2);
+		assert_eq!(StorageVersion::get::<Assets>(), 6);
+	});
+}
+
+#[test]
+fn migration_v7_removes_default_metadata() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		for id in 0..3 {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 1));
+		}
+		Metadata::<Test>::insert(0, AssetMetadata::default());
+		// Only entries equal to the default are removed, not merely empty ones.
+		let empty_with_decimals = AssetMetadata { decimals: 6, ..Default::default() };
+		Metadata::<Test>::insert(1, empty_with_decimals.clone());
+		StorageVersion::new(6).put::<Assets>();
+
+		migration::v7::MigrateToV7::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Metadata::<Test>::get(0), None);
+		assert_eq!(Metadata::<Test>::get(1), Some(empty_with_decimals));
+		assert_eq!(Metadata::<Test>::get(2), None);
+		assert_eq!(StorageVersion::get::<Assets>(), 7);
+	});
+}
+
+#[test]
+fn migration_v8_indexes_owners() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
 	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2, 2, true, 1));
+		let _ = OwnedAssets::<Test>::clear(u32::MAX, None);
+		let _ = OwnedAssetCount::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(7).put::<Assets>();
+
+		migration::v8::MigrateToV8::<Test>::on_runtime_upgrade();
+
+		assert!(OwnedAssets::<Test>::contains_key(1, 0));
+		assert!(OwnedAssets::<Test>::contains_key(1, 1));
+		assert!(OwnedAssets::<Test>::contains_key(2, 2));
+		assert!(OwnedAssets::<Test>::contains_key(0, 999));
+		assert_eq!(OwnedAssets::<Test>::iter().count(), 4);
+		assert_eq!(OwnedAssetCount::<Test>::get(0), 1);
+		assert_eq!(OwnedAssetCount::<Test>::get(1), 2);
+		assert_eq!(OwnedAssetCount::<Test>::get(2), 1);
+		assert_eq!(StorageVersion::get::<Assets>(), 8);
+	});
+}
+
+#[test]
+fn approve_transfer_frozen_asset_should_not_work() {
+	build_and_execute(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
@@ -762,9 +1896,53 @@ fn approve_transfer_frozen_asset_should_not_work() {
 	});
 }
 
+#[test]
+fn approve_transfer_frozen_account_should_not_work() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+
+		// neither a new approval nor an increase of an existing one can be made while frozen...
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 1));
+		assert_noop!(
+			Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 50),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+			Error::<Test>::Frozen
+		);
+		// ...and the existing approval cannot be spent either.
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 20),
+			Error::<Test>::Frozen
+		);
+
+		// the same goes for a blocked account.
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Assets::block(RuntimeOrigin::signed(1), 0, 1));
+		assert_noop!(
+			Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 50),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 20),
+			Error::<Test>::Frozen
+		);
+
+		// once thawed, everything works again.
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 60));
+		assert_eq!(Assets::balance(0, 3), 60);
+	});
+}
+
 #[test]
 fn transferring_from_blocked_account_should_not_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -779,7 +1957,7 @@ fn transferring_from_blocked_account_should_not_work() {
 
 #[test]
 fn transferring_to_blocked_account_should_not_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
@@ -793,9 +1971,40 @@ fn transferring_to_blocked_account_should_not_work() {
 	});
 }
 
+#[test]
+fn transfer_filter_should_veto_transfers() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		set_non_compliant(3);
+
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10), NOT_COMPLIANT);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 3, 10),
+			NOT_COMPLIANT
+		);
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 10),
+			NOT_COMPLIANT
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 4, 10));
+
+		// the admin can still force transfers, and minting and burning are unaffected.
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 3, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 3, 5));
+		assert_eq!(Assets::balance(0, 3), 15);
+		// the account can't transfer its funds away either.
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(3), 0, 1, 5), NOT_COMPLIANT);
+	});
+}
+
 #[test]
 fn origin_guards_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_noop!(
@@ -829,7 +2038,7 @@ fn origin_guards_should_work() {
 
 #[test]
 fn transfer_owner_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		Balances::make_free_balance_be(&1, 100);
 		Balances::make_free_balance_be(&2, 100);
 		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
@@ -861,25 +2070,78 @@ fn transfer_owner_should_work() {
 }
 
 #[test]
-fn set_team_should_work() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::set_team(RuntimeOrigin::signed(1), 0, 2, 3, 4));
-
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(2), 0, 2, 100));
-		assert_ok!(Assets::freeze(RuntimeOrigin::signed(4), 0, 2));
-		assert_ok!(Assets::thaw(RuntimeOrigin::signed(3), 0, 2));
-		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(3), 0, 2, 3, 100));
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(3), 0, 3, 100));
-	});
-}
+fn transfer_owner_should_move_only_reserved_deposits() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
 
-#[test]
-fn transferring_from_frozen_account_should_not_work() {
-	new_test_ext().execute_with(|| {
+		// a force-created asset with force-set metadata has no deposits at all.
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![0u8; 10],
+			vec![0u8; 10],
+			12,
+			false
+		));
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+
+		// an owner whose deposits were partially slashed can still hand the asset over.
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 1, 1, 1));
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			1,
+			vec![0u8; 10],
+			vec![0u8; 10],
+			12
+		));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+		let _ = Balances::slash_reserved(&1, 10);
+		assert_eq!(Balances::reserved_balance(&1), 12);
+
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 12);
+		// the deposits now record what the new owner actually holds.
+		assert_eq!(Asset::<Test>::get(1).unwrap().deposit, 1);
+		assert_eq!(Metadata::<Test>::get(1).unwrap().deposit, 11);
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::DepositUpdated {
+			asset_id: 1,
+			who: 2,
+			kind: DepositKind::Metadata,
+			old: 0,
+			new: 11,
+		}));
+
+		// clearing the metadata returns exactly what was moved.
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+	});
+}
+
+#[test]
+fn set_team_should_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::set_team(RuntimeOrigin::signed(1), 0, 2, 3, 4));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(2), 0, 2, 100));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(4), 0, 2));
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(3), 0, 2));
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(3), 0, 2, 3, 100));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(3), 0, 3, 100));
+	});
+}
+
+#[test]
+fn transferring_from_frozen_account_should_not_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_eq!(Assets::balance(0, 2), 100);
 		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
@@ -894,7 +2156,7 @@ fn transferring_from_frozen_account_should_not_work() {
 
 #[test]
 fn touching_and_freezing_account_with_zero_asset_balance_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// need some deposit for the touch
 		Balances::make_free_balance_be(&2, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
@@ -917,7 +2179,7 @@ fn touching_and_freezing_account_with_zero_asset_balance_should_work() {
 
 #[test]
 fn touch_other_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// 1 will be admin
 		// 2 will be freezer
 		// 4 will be an account attempting to execute `touch_other`
@@ -948,7 +2210,7 @@ fn touch_other_works() {
 
 #[test]
 fn touch_other_and_freeze_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
@@ -971,7 +2233,7 @@ fn touch_other_and_freeze_works() {
 
 #[test]
 fn account_with_deposit_not_destroyed() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// 1 will be the asset admin
 		// 2 will exist without balance but with deposit
 		Balances::make_free_balance_be(&1, 100);
@@ -1004,7 +2266,7 @@ fn account_with_deposit_not_destroyed() {
 
 #[test]
 fn refund_other_should_fails() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// 1 will be the asset admin
 		// 2 will be the asset freezer
 		// 3 will be created with deposit of 2
@@ -1049,7 +2311,7 @@ fn refund_other_should_fails() {
 			Assets::refund_other(RuntimeOrigin::signed(2), 10, 3),
 			Error::<Test>::AssetNotLive
 		);
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 10));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 10, u32::MAX));
 		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 10));
 		// fail case; account is frozen
 		assert_ok!(Assets::freeze(RuntimeOrigin::signed(2), 0, 3));
@@ -1072,7 +2334,7 @@ fn refund_other_should_fails() {
 
 #[test]
 fn refund_other_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// 1 will be the asset admin
 		// 2 will be the asset freezer
 		// 3 will be created with deposit of 2
@@ -1103,9 +2365,304 @@ fn refund_other_works() {
 	})
 }
 
+#[test]
+fn deposits_are_returned_to_the_recorded_payer() {
+	build_and_execute(|| {
+		// 1 will be the asset admin
+		// 2 will be the asset freezer
+		// 3 and 4 will be created with deposit of 2, 5 will pay its own deposit
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&5, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::set_team(RuntimeOrigin::signed(1), 0, 1, 1, 2));
+		assert_ok!(Assets::touch_other(RuntimeOrigin::signed(2), 0, 3));
+		assert_ok!(Assets::touch_other(RuntimeOrigin::signed(2), 0, 4));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(5), 0));
+		assert_eq!(Balances::reserved_balance(&2), 20);
+		assert_eq!(Balances::reserved_balance(&5), 10);
+
+		// the admin refunds, but the deposit goes back to the freezer which paid it.
+		assert_ok!(Assets::refund_other(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 10);
+
+		// destruction returns every deposit to its payer.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::reserved_balance(&4), 0);
+		assert_eq!(Balances::reserved_balance(&5), 0);
+		assert_eq!(Balances::free_balance(&2), 100);
+		assert_eq!(Balances::free_balance(&5), 100);
+	});
+}
+
+#[test]
+fn existence_reason_should_work() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_eq!(Assets::existence_reason(0, 1), None);
+
+		// touching reserves a deposit from the account itself.
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::existence_reason(0, 1), Some(ExistenceReason::DepositHeld(10)));
+
+		// touching on behalf of another account records the depositor.
+		assert_ok!(Assets::touch_other(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Assets::existence_reason(0, 3), Some(ExistenceReason::DepositFrom(1, 10)));
+
+		// transferring an insufficient asset to an account with a provider takes a consumer ref.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::existence_reason(0, 2), Some(ExistenceReason::Consumer));
+
+		// minting a sufficient asset makes the account exist by itself.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 4, 100));
+		assert_eq!(Assets::existence_reason(1, 4), Some(ExistenceReason::Sufficient));
+	});
+}
+
+#[test]
+fn deposit_updated_events_track_reserved_balance() {
+	build_and_execute(|| {
+		// the sum of all deposit changes reported for `who` so far.
+		let reported = |who: u64| -> u64 {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Assets(crate::Event::DepositUpdated {
+						who: w, old, new, ..
+					}) if w == who => Some(new as i64 - old as i64),
+					_ => None,
+				})
+				.sum::<i64>() as u64
+		};
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 10],
+			vec![0u8; 10],
+			12
+		));
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 5],
+			vec![0u8; 5],
+			12
+		));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Balances::reserved_balance(&1), 13);
+		assert_eq!(reported(1), 13);
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		assert_eq!(reported(2), 10);
+
+		// spending the approval entirely releases its deposit.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 2, 50));
+		assert_eq!(Balances::reserved_balance(&1), 12);
+		assert_eq!(reported(1), 12);
+
+		// the asset and metadata deposits move along with the ownership.
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(reported(1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 22);
+		assert_eq!(reported(2), 22);
+
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, true));
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		assert_eq!(reported(2), 1);
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(2), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(2), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(reported(2), 0);
+	});
+}
+
+#[test]
+fn max_holders_should_work() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_noop!(
+			Assets::set_max_holders(RuntimeOrigin::signed(2), 0, Some(2)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_max_holders(RuntimeOrigin::signed(1), 0, Some(2)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MaxHoldersSet {
+			asset_id: 0,
+			max_holders: Some(2),
+		}));
+
+		// two transfers to new accounts in the same block; only the first fits.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10),
+			Error::<Test>::TooManyHolders
+		);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10),
+			Error::<Test>::TooManyHolders
+		);
+		assert_noop!(Assets::touch(RuntimeOrigin::signed(3), 0), Error::<Test>::TooManyHolders);
+		assert_eq!(asset_account_counts(0), (2, 2));
+
+		// existing holders can still receive funds, and a holder may hand over all of its funds.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 20));
+		assert_eq!(asset_account_counts(0), (2, 2));
+
+		// the admin is not limited.
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 4, 10));
+		assert_eq!(asset_account_counts(0), (3, 3));
+
+		// removing the limit allows new holders again.
+		assert_ok!(Assets::set_max_holders(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 5, 10));
+		assert_eq!(asset_account_counts(0), (4, 4));
+	});
+}
+
+#[test]
+fn max_holdings_per_account_should_work() {
+	build_and_execute(|| {
+		MaxHoldingsPerAccount::set(2);
+		Balances::make_free_balance_be(&2, 100);
+		for id in 0..4 {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 1));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 1, 100));
+		}
+
+		// account 2 reaches the limit with a touch and a transfer in.
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 10));
+		assert_eq!(HoldingCount::<Test>::get(2), 2);
+		assert_noop!(Assets::touch(RuntimeOrigin::signed(2), 2), Error::<Test>::TooManyHoldings);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 2, 2, 10),
+			Error::<Test>::TooManyHoldings
+		);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 2, 2, 10),
+			Error::<Test>::TooManyHoldings
+		);
+
+		// assets already held can still be received.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 10));
+
+		// the issuer and the admin are not limited.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 2, 2, 10));
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 3, 1, 2, 10));
+		assert_eq!(HoldingCount::<Test>::get(2), 4);
+
+		// dropping an asset makes room for another one once below the limit.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 2, 1, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 3, 1, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 1, 1, 20));
+		assert_eq!(HoldingCount::<Test>::get(2), 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 3, 2, 10));
+		assert_eq!(HoldingCount::<Test>::get(2), 2);
+
+		// no limit.
+		MaxHoldingsPerAccount::set(0);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 2, 2, 10));
+		assert_eq!(HoldingCount::<Test>::get(2), 3);
+	});
+}
+
+fn assets_owned_by(who: u64) -> Vec<u32> {
+	let mut ids = Assets::assets_owned_by(&who);
+	ids.sort();
+	ids
+}
+
+#[test]
+fn owned_assets_should_follow_the_owner() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_eq!(assets_owned_by(0), vec![999]);
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_eq!(assets_owned_by(1), vec![0, 1]);
+		assert_eq!(OwnedAssetCount::<Test>::get(1), 2);
+
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(assets_owned_by(1), vec![1]);
+		assert_eq!(assets_owned_by(2), vec![0]);
+
+		assert_ok!(Assets::force_asset_status(
+			RuntimeOrigin::root(),
+			1,
+			2,
+			1,
+			1,
+			1,
+			1,
+			true,
+			false
+		));
+		assert_eq!(assets_owned_by(1), Vec::<u32>::new());
+		assert_eq!(assets_owned_by(2), vec![0, 1]);
+		assert_eq!(OwnedAssetCount::<Test>::get(1), 0);
+		assert_eq!(OwnedAssetCount::<Test>::get(2), 2);
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(2), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(2), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(2), 0));
+		assert_eq!(assets_owned_by(2), vec![1]);
+		assert_eq!(OwnedAssetCount::<Test>::get(2), 1);
+	});
+}
+
+#[test]
+fn max_owned_assets_should_work() {
+	build_and_execute(|| {
+		MaxOwnedAssets::set(2);
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 1, 1, 1));
+		assert_noop!(
+			Assets::create(RuntimeOrigin::signed(1), 2, 1, 1),
+			Error::<Test>::TooManyOwnedAssets
+		);
+
+		// only `create` is limited.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2, 1, true, 1));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(2), 3, 2, 1));
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(2), 3, 1));
+		assert_eq!(OwnedAssetCount::<Test>::get(1), 4);
+
+		// no limit.
+		MaxOwnedAssets::set(0);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 4, 1, 1));
+		assert_eq!(assets_owned_by(1), vec![0, 1, 2, 3, 4]);
+	});
+}
+
 #[test]
 fn transferring_amount_more_than_available_balance_should_not_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -1126,20 +2683,136 @@ fn transferring_amount_more_than_available_balance_should_not_work() {
 }
 
 #[test]
-fn transferring_less_than_one_unit_is_fine() {
-	new_test_ext().execute_with(|| {
+fn transferring_to_self_should_not_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 100));
+		let supply = Assets::total_supply(0);
+		let counts = asset_account_counts(0);
+
+		// none of these would leave any dust behind or touch the counters.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 1, 95),
+			Error::<Test>::SelfTransfer
+		);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 1, 90),
+			Error::<Test>::SelfTransfer
+		);
+		assert_noop!(
+			Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 1, 100),
+			Error::<Test>::SelfTransfer
+		);
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 1, 95),
+			Error::<Test>::SelfTransfer
+		);
+		// the balance is still validated first.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 1, 101),
+			Error::<Test>::BalanceLow
+		);
+
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::total_supply(0), supply);
+		assert_eq!(asset_account_counts(0), counts);
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)).map(|a| a.amount), Some(100));
+	});
+}
+
+#[test]
+fn failed_transfers_should_not_write_storage() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::set_max_holders(RuntimeOrigin::signed(1), 0, Some(2)));
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			bypass_filter: false,
+		};
+
+		// the balance is checked before the admin rights.
+		assert_storage_noop!(assert_eq!(
+			Assets::do_transfer(0, &1, &2, 101, Some(9), f),
+			Err(Error::<Test>::BalanceLow.into())
+		));
+		assert_storage_noop!(assert_eq!(
+			Assets::do_transfer(0, &1, &2, 50, Some(9), f),
+			Err(Error::<Test>::NoPermission.into())
+		));
+		// nothing is written when the destination cannot be created either.
+		assert_storage_noop!(assert_eq!(
+			Assets::do_transfer(0, &1, &3, 5, None, f),
+			Err(TokenError::BelowMinimum.into())
+		));
+		assert_storage_noop!(assert_eq!(
+			Assets::do_transfer(0, &1, &3, 50, None, f),
+			Err(Error::<Test>::TooManyHolders.into())
+		));
+		assert_storage_noop!(assert_eq!(
+			Assets::do_transfer(1, &1, &2, 50, None, f),
+			Err(Error::<Test>::Unknown.into())
+		));
+
+		// both accounts and the details are updated once the transfer goes through.
+		assert_ok!(Assets::do_transfer(0, &1, &2, 100, None, f));
+		assert!(!Account::<Test>::contains_key(0, 1));
+		assert_eq!(Assets::balance(0, 2), 200);
+		assert_eq!(asset_account_counts(0), (1, 1));
+		assert_eq!(Assets::total_supply(0), 200);
+	});
+}
+
+#[test]
+fn transferring_less_than_one_unit_should_not_work() {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 0));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 0),
+			Error::<Test>::AmountZero.with_weight(Weight::zero())
+		);
 		// `ForceCreated` and `Issued` but no `Transferred` event.
 		assert_eq!(System::events().len(), 2);
 	});
 }
 
+#[test]
+fn zero_amounts_should_not_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 100);
+
+		// all of the call's weight is refunded.
+		let err = Error::<Test>::AmountZero.with_weight(Weight::zero());
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 0), err);
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(1), 0, 1, 0), err);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 0), err);
+		assert_noop!(Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 2, 0), err);
+		assert_noop!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 0), err);
+		assert_noop!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 0), err);
+
+		// the `fungibles` implementation keeps treating zero amounts as a no-op.
+		use frame_support::traits::{
+			fungibles::Mutate,
+			tokens::{Fortitude::Polite, Precision::Exact},
+		};
+		assert_ok!(<Assets as Mutate<u64>>::mint_into(0, &2, 0), 0);
+		assert_ok!(<Assets as Mutate<u64>>::burn_from(0, &1, 0, Exact, Polite), 0);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert!(!Account::<Test>::contains_key(0, &2));
+	});
+}
+
 #[test]
 fn transferring_more_units_than_total_supply_should_not_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -1152,7 +2825,7 @@ fn transferring_more_units_than_total_supply_should_not_work() {
 
 #[test]
 fn burning_asset_balance_with_positive_balance_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
@@ -1167,23 +2840,285 @@ fn burning_asset_balance_with_positive_balance_should_work() {
 }
 
 #[test]
-fn burning_asset_balance_with_zero_balance_does_nothing() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 2), 0);
-		assert_noop!(
-			Assets::burn(RuntimeOrigin::signed(1), 0, 2, u64::MAX),
-			Error::<Test>::NoAccount
-		);
-		assert_eq!(Assets::balance(0, 2), 0);
-		assert_eq!(Assets::total_supply(0), 100);
-	});
+fn admin_burns_of_frozen_funds_should_follow_the_policy() {
+	use frame_support::traits::tokens::{Fortitude::Polite, Precision::BestEffort};
+
+	for account_frozen in [false, true] {
+		for asset_frozen in [false, true] {
+			for allow in [true, false] {
+				new_test_ext().execute_with(|| {
+					assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+					assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+					assert_ok!(Assets::set_allow_admin_burn_when_frozen(
+						RuntimeOrigin::signed(1),
+						0,
+						allow
+					));
+					if account_frozen {
+						assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+					}
+					if asset_frozen {
+						assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+					}
+					let case = format!(
+						"account frozen: {}, asset frozen: {}, allowed: {}",
+						account_frozen, asset_frozen, allow
+					);
+
+					let expected: Result<(), DispatchError> =
+						match (allow, account_frozen, asset_frozen) {
+							(true, _, _) | (false, false, false) => Ok(()),
+							(false, _, true) => Err(Error::<Test>::AssetNotLive.into()),
+							(false, true, false) => Err(Error::<Test>::Frozen.into()),
+						};
+					assert_eq!(
+						Assets::burn(RuntimeOrigin::signed(1), 0, 2, 10)
+							.map(|_| ())
+							.map_err(|e| e.error),
+						expected,
+						"{}",
+						case
+					);
+					let balance = if expected.is_ok() { 90 } else { 100 };
+					assert_eq!(Assets::balance(0, 2), balance, "{}", case);
+
+					// lifting the freezes doesn't lift the need to be the Admin.
+					if expected.is_ok() {
+						assert_eq!(
+							Assets::burn(RuntimeOrigin::signed(3), 0, 2, 10).map_err(|e| e.error),
+							Err(Error::<Test>::NoPermission.into()),
+							"{}",
+							case
+						);
+					}
+
+					// burns without the Admin always respect the freezes.
+					if account_frozen || asset_frozen {
+						let flags = DebitFlags { keep_alive: false, best_effort: true };
+						assert!(
+							Assets::burn_with_flags(0, &2, 10, None, flags).is_err(),
+							"{}",
+							case
+						);
+						assert!(
+							<Assets as fungibles::Mutate<_>>::burn_from(
+								0, &2, 10, BestEffort, Polite
+							)
+							.is_err(),
+							"{}",
+							case
+						);
+						assert_eq!(Assets::balance(0, 2), balance, "{}", case);
+					}
+
+					// the freezes are left in place.
+					let account = Account::<Test>::get(0, 2).unwrap();
+					assert_eq!(account.status.is_frozen(), account_frozen, "{}", case);
+					let details = Asset::<Test>::get(0).unwrap();
+					assert_eq!(details.status == AssetStatus::Frozen, asset_frozen, "{}", case);
+					assert_ok!(Assets::do_try_state());
+				});
+			}
+		}
+	}
+}
+
+#[test]
+fn admin_should_be_able_to_confiscate_frozen_accounts() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+
+		assert_noop!(
+			Assets::set_allow_admin_burn_when_frozen(RuntimeOrigin::signed(2), 0, false),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_allow_admin_burn_when_frozen(RuntimeOrigin::signed(1), 0, false));
+		System::assert_last_event(RuntimeEvent::Assets(
+			crate::Event::AllowAdminBurnWhenFrozenSet { asset_id: 0, allow: false },
+		));
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, u64::MAX), Error::<Test>::Frozen);
+
+		// only the non-default setting is stored.
+		assert_ok!(Assets::set_allow_admin_burn_when_frozen(RuntimeOrigin::signed(1), 0, true));
+		assert!(!AllowAdminBurnWhenFrozen::<Test>::contains_key(0));
+
+		// the whole balance is burned, and the frozen account is gone.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, u64::MAX));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().supply, 0);
+	});
+}
+
+#[test]
+fn burning_below_min_balance_reports_dust() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+
+		// no dust left behind.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 1, 50));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Assets(crate::Event::DustBurned { .. })
+		)));
+		assert_eq!(Assets::total_supply(0), 150);
+
+		// the remainder of 5 is below the minimum balance and collapses.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 95));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Burned {
+			asset_id: 0,
+			owner: 2,
+			balance: 100,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DustBurned {
+			asset_id: 0,
+			who: 2,
+			amount: 5,
+		}));
+		assert_eq!(Assets::total_supply(0), 150 - 95 - 5);
+		assert_eq!(Assets::balance(0, 2), 0);
+	});
+}
+
+#[test]
+fn transfer_burning_dust_reports_dust() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: true,
+			bypass_filter: false,
+		};
+
+		// no dust left behind.
+		assert_ok!(Assets::do_transfer(0, &1, &2, 50, None, f));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 1,
+			to: 2,
+			amount: 50,
+		}));
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// the remainder of 5 is below the minimum balance and gets burned.
+		assert_ok!(Assets::do_transfer(0, &1, &3, 45, None, f));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 1,
+			to: 3,
+			amount: 45,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DustBurned {
+			asset_id: 0,
+			who: 1,
+			amount: 5,
+		}));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 3), 45);
+		assert_eq!(Assets::total_supply(0), 100 - 5);
+	});
+}
+
+#[test]
+fn refunding_with_burn_should_reduce_supply() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), 0, true));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DustBurned {
+			asset_id: 0,
+			who: 1,
+			amount: 100,
+		}));
+		assert_eq!(Assets::total_supply(0), 0);
+	});
+}
+
+#[test]
+fn dust_can_be_credited_to_an_account() {
+	build_and_execute(|| {
+		credit_dust_to_treasury();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+
+		// dust which can't create the treasury account is still burned.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 95));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DustBurned {
+			asset_id: 0,
+			who: 2,
+			amount: 5,
+		}));
+		assert_eq!(Assets::balance(0, DustTreasury::get()), 0);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, DustTreasury::get(), 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		// burning.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 95));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DustCredited {
+			asset_id: 0,
+			who: 2,
+			to: DustTreasury::get(),
+			amount: 5,
+		}));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::balance(0, DustTreasury::get()), 15);
+		assert_eq!(Assets::total_supply(0), 115);
+
+		// transferring.
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: true,
+			bypass_filter: false,
+		};
+		assert_ok!(Assets::do_transfer(0, &1, &3, 95, None, f));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 3), 95);
+		assert_eq!(Assets::balance(0, DustTreasury::get()), 20);
+		assert_eq!(Assets::total_supply(0), 115);
+
+		// refunding.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&DustTreasury::get(), 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, DustTreasury::get(), 10));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), 1, true));
+		assert_eq!(Assets::balance(1, 1), 0);
+		assert_eq!(Assets::balance(1, DustTreasury::get()), 60);
+		assert_eq!(Assets::total_supply(1), 60);
+	});
+}
+
+#[test]
+fn burning_asset_balance_with_zero_balance_does_nothing() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_noop!(
+			Assets::burn(RuntimeOrigin::signed(1), 0, 2, u64::MAX),
+			Error::<Test>::NoAccount
+		);
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::total_supply(0), 100);
+	});
 }
 
 #[test]
 fn set_metadata_should_work() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		// Cannot add metadata to unknown asset
 		assert_noop!(
 			Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12),
@@ -1216,6 +3151,13 @@ fn set_metadata_should_work() {
 			12
 		));
 		assert_eq!(Balances::free_balance(&1), 9);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MetadataSet {
+			asset_id: 0,
+			name: vec![0u8; 10].try_into().unwrap(),
+			symbol: vec![0u8; 10].try_into().unwrap(),
+			decimals: 12,
+			is_frozen: false,
+		}));
 
 		// Update deposit
 		assert_ok!(Assets::set_metadata(
@@ -1253,525 +3195,2966 @@ fn set_metadata_should_work() {
 	});
 }
 
-/// Destroying an asset calls the `FrozenBalance::died` hooks of all accounts.
 #[test]
-fn destroy_accounts_calls_died_hooks() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 50));
-		// Create account 1 and 2.
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
-		// Destroy the accounts.
-		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+fn readable_metadata_should_accept_text_in_any_script() {
+	build_and_execute(|| {
+		require_readable_metadata();
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 
-		// Accounts 1 and 2 died.
-		assert_eq!(hooks(), vec![Hook::Died(0, 1), Hook::Died(0, 2)]);
-	})
+		for name in ["日本円", "Рубль", "Ελληνικό", "ديرهم", "Token 2.0 (wrapped)", ""]
+		{
+			assert_ok!(Assets::set_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				name.as_bytes().to_vec(),
+				b"TKN".to_vec(),
+				12
+			));
+			assert_eq!(
+				Metadata::<Test>::get(0).unwrap().name.into_inner(),
+				name.as_bytes().to_vec()
+			);
+		}
+		for symbol in ["DOT", "wBTC.e", "LP-0_1", ""] {
+			assert_ok!(Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				0,
+				b"Token".to_vec(),
+				symbol.as_bytes().to_vec(),
+				12,
+				false
+			));
+			assert_eq!(
+				Metadata::<Test>::get(0).unwrap().symbol.into_inner(),
+				symbol.as_bytes().to_vec()
+			);
+		}
+	});
 }
 
-/// Destroying an asset calls the `FrozenBalance::died` hooks of all accounts.
 #[test]
-fn finish_destroy_asset_destroys_asset() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 50));
-		// Destroy the accounts.
-		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+fn readable_metadata_should_reject_hidden_characters() {
+	build_and_execute(|| {
+		require_readable_metadata();
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 
-		// Asset is gone
-		assert!(Asset::<Test>::get(0).is_none());
-	})
+		let bad_names: Vec<Vec<u8>> = vec![
+			vec![0xff, 0xfe],
+			b"\xe6\x97".to_vec(),
+			b"Token\n".to_vec(),
+			b"Tok\0en".to_vec(),
+			b"\x1b[31mToken".to_vec(),
+			"Token\u{7f}".as_bytes().to_vec(),
+			"Token\u{85}".as_bytes().to_vec(),
+			"\u{202E}nekoT".as_bytes().to_vec(),
+			"Token\u{2066}".as_bytes().to_vec(),
+			"To\u{200F}ken".as_bytes().to_vec(),
+			"\u{061C}Token".as_bytes().to_vec(),
+		];
+		for name in bad_names {
+			assert_noop!(
+				Assets::set_metadata(
+					RuntimeOrigin::signed(1),
+					0,
+					name.clone(),
+					b"TKN".to_vec(),
+					12
+				),
+				Error::<Test>::BadMetadata,
+			);
+			assert_noop!(
+				Assets::force_set_metadata(
+					RuntimeOrigin::root(),
+					0,
+					name,
+					b"TKN".to_vec(),
+					12,
+					false
+				),
+				Error::<Test>::BadMetadata,
+			);
+		}
+
+		for symbol in ["DΟT", "T K N", "TKN\n", "$TKN", "\u{202E}NKT", "円"] {
+			assert_noop!(
+				Assets::set_metadata(
+					RuntimeOrigin::signed(1),
+					0,
+					b"Token".to_vec(),
+					symbol.as_bytes().to_vec(),
+					12
+				),
+				Error::<Test>::BadMetadata,
+			);
+			assert_noop!(
+				Assets::force_set_metadata(
+					RuntimeOrigin::root(),
+					0,
+					b"Token".to_vec(),
+					symbol.as_bytes().to_vec(),
+					12,
+					false
+				),
+				Error::<Test>::BadMetadata,
+			);
+		}
+		assert!(!Metadata::<Test>::contains_key(0));
+	});
 }
 
 #[test]
-fn freezer_should_work() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
+fn unit_metadata_policy_should_accept_any_bytes() {
+	assert!(<() as MetadataPolicy>::is_valid(&[0xff, 0], "\u{202E}".as_bytes()));
+	assert!(!ReadableMetadata::is_valid(&[0xff, 0], b"TKN"));
+	assert!(!ReadableMetadata::is_valid(b"Token", "\u{202E}".as_bytes()));
+}
 
-		// freeze 50 of it.
-		set_frozen_balance(0, 1, 50);
+#[test]
+fn clearing_metadata_never_set_should_fail() {
+	use frame_support::traits::fungibles::metadata::Inspect;
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_eq!(Metadata::<Test>::get(0), None);
+		assert_eq!(<Assets as Inspect<u64>>::name(0), Vec::<u8>::new());
+		assert_eq!(<Assets as Inspect<u64>>::decimals(0), 0);
 
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
-		// cannot transfer another 21 away as this would take the non-frozen balance (30) to below
-		// the minimum balance (10).
 		assert_noop!(
-			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 21),
-			Error::<Test>::BalanceLow
+			Assets::clear_metadata(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoMetadata
+		);
+		assert_noop!(
+			Assets::force_clear_metadata(RuntimeOrigin::root(), 0),
+			Error::<Test>::NoMetadata
 		);
 
-		// create an approved transfer...
-		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
-		let e = Error::<Test>::BalanceLow;
-		// ...but that wont work either:
-		assert_noop!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 2, 21), e);
-		// a force transfer won't work also.
-		let e = Error::<Test>::BalanceLow;
-		assert_noop!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 21), e);
-
-		// reduce it to only 49 frozen...
-		set_frozen_balance(0, 1, 49);
-		// ...and it's all good:
-		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 21));
-
-		// and if we clear it, we can remove the account completely.
-		clear_frozen_balance(0, 1);
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50));
-		assert_eq!(hooks(), vec![Hook::Died(0, 1)]);
+		// Once cleared, the metadata is gone again.
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![], vec![], 0));
+		assert!(Metadata::<Test>::contains_key(0));
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Assets::clear_metadata(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoMetadata
+		);
 	});
 }
 
 #[test]
-fn imbalances_should_work() {
-	use frame_support::traits::tokens::fungibles::Balanced;
-
-	new_test_ext().execute_with(|| {
+fn shrinking_metadata_should_release_the_excess_deposit() {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		Balances::make_free_balance_be(&1, 100);
 
-		let imb = Assets::issue(0, 100);
-		assert_eq!(Assets::total_supply(0), 100);
-		assert_eq!(imb.peek(), 100);
+		// The deposit is 1 + the number of bytes.
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 5],
+			vec![0u8; 5],
+			12
+		));
+		assert_eq!(Balances::reserved_balance(&1), 11);
 
-		let (imb1, imb2) = imb.split(30);
-		assert_eq!(imb1.peek(), 30);
-		assert_eq!(imb2.peek(), 70);
+		// Growing the metadata reserves the difference...
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 30],
+			vec![0u8; 20],
+			12
+		));
+		assert_eq!(Balances::reserved_balance(&1), 51);
+		assert_eq!(Asset::<Test>::get(0).unwrap().metadata_deposit, 51);
 
-		drop(imb2);
-		assert_eq!(Assets::total_supply(0), 30);
+		// ...and shrinking it back releases all of it.
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 5],
+			vec![0u8; 5],
+			12
+		));
+		assert_eq!(Balances::reserved_balance(&1), 11);
+		assert_eq!(Balances::free_balance(&1), 89);
+		assert_eq!(Metadata::<Test>::get(0).unwrap().deposit, 11);
+		assert_eq!(Asset::<Test>::get(0).unwrap().metadata_deposit, 11);
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::DepositUpdated {
+			asset_id: 0,
+			who: 1,
+			kind: DepositKind::Metadata,
+			old: 51,
+			new: 11,
+		}));
 
-		assert!(Assets::resolve(&1, imb1).is_ok());
-		assert_eq!(Assets::balance(0, 1), 30);
-		assert_eq!(Assets::total_supply(0), 30);
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 100);
 	});
 }
 
 #[test]
-fn force_metadata_should_work() {
-	new_test_ext().execute_with(|| {
-		// force set metadata works
+fn force_set_metadata_should_adjust_the_owner_deposit() {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::force_set_metadata(
-			RuntimeOrigin::root(),
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
 			0,
 			vec![0u8; 10],
 			vec![0u8; 10],
+			12
+		));
+		assert_eq!(Balances::reserved_balance(&1), 21);
+
+		// Forcing longer metadata reserves the difference from the owner...
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![1u8; 30],
+			vec![1u8; 20],
 			8,
 			false
 		));
-		assert!(Metadata::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(&1), 51);
 
-		// overwrites existing metadata
-		let asset_original_metadata = Metadata::<Test>::get(0);
+		// ...and forcing shorter metadata releases the excess.
 		assert_ok!(Assets::force_set_metadata(
 			RuntimeOrigin::root(),
 			0,
-			vec![1u8; 10],
-			vec![1u8; 10],
+			vec![1u8; 2],
+			vec![1u8; 3],
 			8,
 			false
 		));
-		assert_ne!(Metadata::<Test>::get(0), asset_original_metadata);
-
-		// attempt to set metadata for non-existent asset class
-		assert_noop!(
-			Assets::force_set_metadata(
-				RuntimeOrigin::root(),
-				1,
-				vec![0u8; 10],
-				vec![0u8; 10],
-				8,
-				false
-			),
-			Error::<Test>::Unknown
-		);
+		assert_eq!(Balances::reserved_balance(&1), 6);
+		assert_eq!(Balances::free_balance(&1), 94);
+		assert_eq!(Metadata::<Test>::get(0).unwrap().deposit, 6);
+		assert_eq!(Asset::<Test>::get(0).unwrap().metadata_deposit, 6);
 
-		// string length limit check
-		let limit = 50usize;
+		// The owner cannot be made to reserve more than it has.
+		Balances::make_free_balance_be(&1, 10);
 		assert_noop!(
 			Assets::force_set_metadata(
 				RuntimeOrigin::root(),
 				0,
-				vec![0u8; limit + 1],
-				vec![0u8; 10],
+				vec![1u8; 30],
+				vec![1u8; 20],
 				8,
 				false
 			),
-			Error::<Test>::BadMetadata
-		);
-		assert_noop!(
-			Assets::force_set_metadata(
-				RuntimeOrigin::root(),
-				0,
-				vec![0u8; 10],
-				vec![0u8; limit + 1],
-				8,
-				false
-			),
-			Error::<Test>::BadMetadata
+			BalancesError::<Test, _>::InsufficientBalance,
 		);
 
-		// force clear metadata works
-		assert!(Metadata::<Test>::contains_key(0));
-		assert_ok!(Assets::force_clear_metadata(RuntimeOrigin::root(), 0));
-		assert!(!Metadata::<Test>::contains_key(0));
+		// Metadata forced without a deposit stays deposit-free.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			1,
+			vec![1u8; 30],
+			vec![1u8; 20],
+			8,
+			false
+		));
+		assert_eq!(Metadata::<Test>::get(1).unwrap().deposit, 0);
+		assert_eq!(Balances::reserved_balance(&1), 6);
+	});
+}
 
-		// Error handles clearing non-existent asset class
+/// Destroying an asset calls the `FrozenBalance::died` hooks of all accounts.
+#[test]
+fn destroy_accounts_calls_died_hooks() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 50));
+		// Create account 1 and 2.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		// Destroy the accounts.
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+
+		// Accounts 1 and 2 died.
+		assert_eq!(hooks(), vec![Hook::Died(0, 1), Hook::Died(0, 2)]);
+	})
+}
+
+/// Destroying an asset calls the `FrozenBalance::died` hooks of all accounts.
+#[test]
+fn stats_should_count_transfers_mints_and_burns() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::transfer_keep_alive(RuntimeOrigin::signed(2), 0, 3, 20));
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 3, 1, 5));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 3, 10));
+		// Failed calls are not counted.
 		assert_noop!(
-			Assets::force_clear_metadata(RuntimeOrigin::root(), 1),
-			Error::<Test>::Unknown
+			Assets::transfer(RuntimeOrigin::signed(3), 0, 1, 1_000),
+			Error::<Test>::BalanceLow
+		);
+
+		assert_eq!(
+			Assets::stats(0),
+			AssetStatistics { transfers: 3, volume: 55, mints: 2, burns: 1 }
 		);
+
+		// The counters go with the asset.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(!AssetStats::<Test>::contains_key(0));
 	});
 }
 
 #[test]
-fn force_asset_status_should_work() {
-	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&1, 10);
-		Balances::make_free_balance_be(&2, 10);
-		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 30));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 50));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 150));
-
-		// force asset status to change min_balance > balance
-		assert_ok!(Assets::force_asset_status(
-			RuntimeOrigin::root(),
+fn stats_should_saturate() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		AssetStats::<Test>::insert(
 			0,
-			1,
-			1,
-			1,
-			1,
-			100,
-			true,
-			false
-		));
-		assert_eq!(Assets::balance(0, 1), 50);
+			AssetStatistics {
+				transfers: u64::MAX,
+				volume: u64::MAX - 1,
+				mints: u64::MAX,
+				burns: 0,
+			},
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10));
+		assert_eq!(
+			Assets::stats(0),
+			AssetStatistics { transfers: u64::MAX, volume: u64::MAX, mints: u64::MAX, burns: 0 }
+		);
+	});
+}
 
-		// account can recieve assets for balance < min_balance
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 1));
-		assert_eq!(Assets::balance(0, 1), 51);
+#[test]
+fn stats_should_not_be_tracked_unless_configured() {
+	build_and_execute(|| {
+		TrackStats::set(false);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 10));
 
-		// account on outbound transfer will cleanup for balance < min_balance
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1));
-		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::stats(0), AssetStatistics::default());
+		assert!(!AssetStats::<Test>::contains_key(0));
+	});
+}
 
-		// won't create new account with balance below min_balance
+#[test]
+fn finish_destroy_asset_destroys_asset() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 50));
+		// An asset can only be finished once its destruction has started, frozen or not.
 		assert_noop!(
-			Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50),
-			TokenError::BelowMinimum
+			Assets::finish_destroy(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::IncorrectStatus
 		);
-
-		// force asset status will not execute for non-existent class
+		// Destroy the accounts.
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
 		assert_noop!(
-			Assets::force_asset_status(RuntimeOrigin::root(), 1, 1, 1, 1, 1, 90, true, false),
-			Error::<Test>::Unknown
+			Assets::finish_destroy(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::IncorrectStatus
 		);
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
 
-		// account drains to completion when funds dip below min_balance
-		assert_ok!(Assets::force_asset_status(
-			RuntimeOrigin::root(),
-			0,
-			1,
-			1,
-			1,
-			1,
-			110,
-			true,
-			false
-		));
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 110));
-		assert_eq!(Assets::balance(0, 1), 200);
-		assert_eq!(Assets::balance(0, 2), 0);
-		assert_eq!(Assets::total_supply(0), 200);
-	});
+		// Asset is gone
+		assert!(Asset::<Test>::get(0).is_none());
+	})
 }
 
 #[test]
-fn set_min_balance_should_work() {
-	new_test_ext().execute_with(|| {
-		let id = 42;
-		Balances::make_free_balance_be(&1, 10);
-		assert_ok!(Assets::create(RuntimeOrigin::signed(1), id, 1, 30));
+fn combined_freezers_should_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
 
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 1, 100));
-		// Won't execute because there is an asset holder.
+		// the largest of the frozen amounts applies, they don't add up.
+		set_frozen_balance(0, 1, 30);
+		set_second_frozen_balance(0, 1, 50);
 		assert_noop!(
-			Assets::set_min_balance(RuntimeOrigin::signed(1), id, 50),
-			Error::<Test>::NoPermission
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 41),
+			Error::<Test>::BalanceLow
 		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
 
-		// Force asset status to make this a sufficient asset.
-		assert_ok!(Assets::force_asset_status(
-			RuntimeOrigin::root(),
-			id,
-			1,
-			1,
-			1,
-			1,
-			30,
-			true,
-			false
-		));
+		// either freezer alone keeps the account alive.
+		clear_second_frozen_balance(0, 1);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 21),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
 
-		// Won't execute because there is an account holding the asset and the asset is marked as
-		// sufficient.
+		// once neither freezes anything, the account can go and both freezers are told.
+		clear_frozen_balance(0, 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_eq!(hooks(), vec![Hook::Died(0, 1)]);
+		assert_eq!(take_second_hooks(), vec![Hook::Died(0, 1)]);
+	});
+}
+
+#[test]
+fn freezer_should_work() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+
+		// freeze 50 of it.
+		set_frozen_balance(0, 1, 50);
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
+		// cannot transfer another 21 away as this would take the non-frozen balance (30) to below
+		// the minimum balance (10).
 		assert_noop!(
-			Assets::set_min_balance(RuntimeOrigin::signed(1), id, 10),
-			Error::<Test>::NoPermission
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 21),
+			Error::<Test>::BalanceLow
 		);
 
-		// Make the asset not sufficient.
-		assert_ok!(Assets::force_asset_status(
-			RuntimeOrigin::root(),
-			id,
-			1,
-			1,
-			1,
-			1,
-			60,
-			false,
-			false
-		));
+		// create an approved transfer...
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		let e = Error::<Test>::BalanceLow;
+		// ...but that wont work either:
+		assert_noop!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 2, 21), e);
+		// a force transfer won't work also.
+		let e = Error::<Test>::BalanceLow;
+		assert_noop!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 21), e);
 
-		// Will execute because the new value of min_balance is less than the
-		// old value. 10 < 30
-		assert_ok!(Assets::set_min_balance(RuntimeOrigin::signed(1), id, 10));
-		assert_eq!(Asset::<Test>::get(id).unwrap().min_balance, 10);
+		// reduce it to only 49 frozen...
+		set_frozen_balance(0, 1, 49);
+		// ...and it's all good:
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 21));
 
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), id, 1, 100));
+		// and if we clear it, we can remove the account completely.
+		clear_frozen_balance(0, 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(hooks(), vec![Hook::Died(0, 1)]);
+	});
+}
 
-		assert_ok!(Assets::set_min_balance(RuntimeOrigin::signed(1), id, 50));
-		assert_eq!(Asset::<Test>::get(id).unwrap().min_balance, 50);
+#[test]
+fn burn_should_respect_freezer() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		set_frozen_balance(0, 1, 50);
+
+		// burning takes what it can without touching the frozen funds or the minimum balance.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::total_supply(0), 60);
+		assert_eq!(hooks(), vec![]);
+
+		// once nothing is frozen, the account can be burned away, and the freezer is told.
+		clear_frozen_balance(0, 1);
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_eq!(hooks(), vec![Hook::Died(0, 1)]);
 	});
 }
 
 #[test]
-fn balance_conversion_should_work() {
-	new_test_ext().execute_with(|| {
-		use frame_support::traits::tokens::ConversionToAssetBalance;
+fn died_should_be_called_once_per_reaped_account() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 10));
+		for who in 1..=6 {
+			Balances::make_free_balance_be(&who, 100);
+			if who == 4 {
+				assert_ok!(Assets::touch(RuntimeOrigin::signed(who), 0));
+			}
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 100));
+		}
+
+		// reaped by `transfer`, `burn` and `refund`, in that order.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 100));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 3, 100));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(4), 0, true));
+		assert_eq!(take_hooks(), vec![Hook::Died(0, 2), Hook::Died(0, 3), Hook::Died(0, 4)]);
 
-		let id = 42;
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 10));
-		let not_sufficient = 23;
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), not_sufficient, 1, false, 10));
-		assert_eq!(asset_ids(), vec![23, 42, 999]);
-		assert_eq!(
-			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(100, 1234),
-			Err(ConversionError::AssetMissing)
+		// failing or leaving the account alive tells the freezer nothing.
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(5), 0, 1, 100),
+			Error::<Test>::BalanceLow
 		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(5), 0, 1, 90));
+		assert_eq!(take_hooks(), vec![]);
+
+		// destruction reaps the rest, and nobody twice.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		let mut destroyed = take_hooks();
+		destroyed.sort_by_key(|Hook::Died(_, who)| *who);
+		assert_eq!(destroyed, vec![Hook::Died(0, 1), Hook::Died(0, 5), Hook::Died(0, 6)]);
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(take_hooks(), vec![]);
+	});
+}
+
+#[test]
+fn reducible_balance_should_respect_freezer() {
+	use frame_support::traits::tokens::{
+		fungibles::Inspect,
+		Fortitude::Polite,
+		Preservation::{Expendable, Preserve},
+	};
+
+	// (frozen amount, reducible balance); the latter is the same whether or not the account
+	// must be kept alive, since a frozen account may never die.
+	for (frozen, reducible) in [(0, 90), (30, 60), (90, 0), (95, 0), (200, 0)] {
+		build_and_execute(|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+			set_frozen_balance(0, 1, frozen);
+
+			assert_eq!(
+				<Assets as Inspect<u64>>::reducible_balance(0, &1, Expendable, Polite),
+				reducible
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::reducible_balance(0, &1, Preserve, Polite),
+				reducible
+			);
+
+			// whatever is reported as reducible can actually be moved, but not a unit more.
+			let e = if frozen > 100 { Error::<Test>::Frozen } else { Error::<Test>::BalanceLow };
+			assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, reducible + 1), e);
+			if reducible > 0 {
+				assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, reducible));
+				assert_eq!(Assets::balance(0, 1), 100 - reducible);
+			}
+		});
+	}
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// without a freezer, only keeping the account alive holds back the minimum balance.
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, Expendable, Polite), 100);
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, Preserve, Polite), 90);
+	});
+}
+
+#[test]
+fn freezer_exceeding_balance_should_freeze_everything() {
+	use frame_support::traits::tokens::{
+		fungibles::Inspect, Fortitude::Polite, Preservation::Expendable, WithdrawConsequence,
+	};
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		set_frozen_balance(0, 1, u64::MAX);
+
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, Expendable, Polite), 0);
+		assert_eq!(Assets::can_withdraw(0, &1, 1), WithdrawConsequence::Frozen);
+		assert_eq!(Assets::can_withdraw(0, &1, 100), WithdrawConsequence::Frozen);
+
+		// every way of taking funds out reports the freeze rather than an arithmetic error.
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1), Error::<Test>::Frozen);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 2, 1),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 1),
+			Error::<Test>::Frozen
+		);
+
+		// receiving funds is unaffected.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 50));
+		assert_eq!(Assets::balance(0, 1), 150);
+
+		clear_frozen_balance(0, 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 150));
+	});
+}
+
+#[test]
+fn withdraw_and_deposit_consequences_should_be_specific() {
+	use frame_support::traits::tokens::{
+		fungibles::Inspect, DepositConsequence, Provenance::Extant, WithdrawConsequence,
+	};
+
+	// (asset frozen, account frozen, freezer minimum, insufficient balance)
+	for asset_frozen in [false, true] {
+		for account_frozen in [false, true] {
+			for freezer_min in [false, true] {
+				for insufficient in [false, true] {
+					build_and_execute(|| {
+						assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+						assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+						if asset_frozen {
+							assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+						}
+						if account_frozen {
+							assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 1));
+						}
+						if freezer_min {
+							set_frozen_balance(0, 1, 50);
+						}
+						let amount = if insufficient { 101 } else { 45 };
+
+						let expected = if asset_frozen || account_frozen {
+							WithdrawConsequence::Frozen
+						} else if insufficient {
+							WithdrawConsequence::BalanceLow
+						} else if freezer_min {
+							WithdrawConsequence::Frozen
+						} else {
+							WithdrawConsequence::Success
+						};
+						assert_eq!(
+							Assets::can_withdraw(0, &1, amount),
+							expected,
+							"asset_frozen: {}, account_frozen: {}, freezer_min: {}, insufficient: {}",
+							asset_frozen,
+							account_frozen,
+							freezer_min,
+							insufficient,
+						);
+						assert_eq!(Assets::can_decrease(0, &1, amount, true), expected);
+					});
+				}
+			}
+		}
+	}
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// taking the balance below the minimum either kills the account or is refused.
+		assert_eq!(Assets::can_decrease(0, &1, 95, false), WithdrawConsequence::ReducedToZero(5));
+		assert_eq!(Assets::can_decrease(0, &1, 95, true), WithdrawConsequence::WouldDie);
+		// a freezer keeps the account alive, even if it doesn't hold anything.
+		set_frozen_balance(0, 1, 0);
+		assert_eq!(Assets::can_decrease(0, &1, 95, false), WithdrawConsequence::WouldDie);
+		assert_eq!(Assets::can_decrease(0, &1, 100, false), WithdrawConsequence::WouldDie);
+		clear_frozen_balance(0, 1);
+
+		// nothing can be credited to a frozen asset, nor moved in an asset being destroyed.
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::can_deposit(0, &2, 50, Extant), DepositConsequence::Blocked);
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::can_deposit(0, &2, 50, Extant), DepositConsequence::UnknownAsset);
+		assert_eq!(Assets::can_withdraw(0, &1, 50), WithdrawConsequence::UnknownAsset);
+	});
+}
+
+#[test]
+fn imbalances_should_work() {
+	use frame_support::traits::tokens::fungibles::Balanced;
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+
+		let imb = Assets::issue(0, 100);
+		assert_eq!(Assets::total_supply(0), 100);
+		assert_eq!(imb.peek(), 100);
+
+		let (imb1, imb2) = imb.split(30);
+		assert_eq!(imb1.peek(), 30);
+		assert_eq!(imb2.peek(), 70);
+
+		drop(imb2);
+		assert_eq!(Assets::total_supply(0), 30);
+
+		assert!(Assets::resolve(&1, imb1).is_ok());
+		assert_eq!(Assets::balance(0, 1), 30);
+		assert_eq!(Assets::total_supply(0), 30);
+	});
+}
+
+#[test]
+fn force_metadata_should_work() {
+	build_and_execute(|| {
+		// force set metadata works
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![0u8; 10],
+			vec![0u8; 10],
+			8,
+			false
+		));
+		assert!(Metadata::<Test>::contains_key(0));
+
+		// overwrites existing metadata
+		let asset_original_metadata = Metadata::<Test>::get(0);
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![1u8; 10],
+			vec![1u8; 10],
+			8,
+			false
+		));
+		assert_ne!(Metadata::<Test>::get(0), asset_original_metadata);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MetadataSet {
+			asset_id: 0,
+			name: vec![1u8; 10].try_into().unwrap(),
+			symbol: vec![1u8; 10].try_into().unwrap(),
+			decimals: 8,
+			is_frozen: false,
+		}));
+
+		// attempt to set metadata for non-existent asset class
+		assert_noop!(
+			Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				1,
+				vec![0u8; 10],
+				vec![0u8; 10],
+				8,
+				false
+			),
+			Error::<Test>::Unknown
+		);
+
+		// string length limit check
+		let limit = 50usize;
+		assert_noop!(
+			Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				0,
+				vec![0u8; limit + 1],
+				vec![0u8; 10],
+				8,
+				false
+			),
+			Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				0,
+				vec![0u8; 10],
+				vec![0u8; limit + 1],
+				8,
+				false
+			),
+			Error::<Test>::BadMetadata
+		);
+
+		// force clear metadata works
+		assert!(Metadata::<Test>::contains_key(0));
+		assert_ok!(Assets::force_clear_metadata(RuntimeOrigin::root(), 0));
+		assert!(!Metadata::<Test>::contains_key(0));
+
+		// Error handles clearing non-existent asset class
+		assert_noop!(
+			Assets::force_clear_metadata(RuntimeOrigin::root(), 1),
+			Error::<Test>::Unknown
+		);
+	});
+}
+
+#[test]
+fn force_asset_status_should_work() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 10);
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 30));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 150));
+
+		// force asset status to change min_balance > balance
+		assert_ok!(Assets::force_asset_status(
+			RuntimeOrigin::root(),
+			0,
+			1,
+			1,
+			1,
+			1,
+			100,
+			true,
+			false
+		));
+		assert_eq!(Assets::balance(0, 1), 50);
+
+		// account can recieve assets for balance < min_balance
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 1));
+		assert_eq!(Assets::balance(0, 1), 51);
+
+		// account on outbound transfer will cleanup for balance < min_balance
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1));
+		assert_eq!(Assets::balance(0, 1), 0);
+
+		// won't create new account with balance below min_balance
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50),
+			TokenError::BelowMinimum
+		);
+
+		// force asset status will not execute for non-existent class
+		assert_noop!(
+			Assets::force_asset_status(RuntimeOrigin::root(), 1, 1, 1, 1, 1, 90, true, false),
+			Error::<Test>::Unknown
+		);
+
+		// account drains to completion when funds dip below min_balance
+		assert_ok!(Assets::force_asset_status(
+			RuntimeOrigin::root(),
+			0,
+			1,
+			1,
+			1,
+			1,
+			110,
+			true,
+			false
+		));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 110));
+		assert_eq!(Assets::balance(0, 1), 200);
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::total_supply(0), 200);
+	});
+}
+
+#[test]
+fn force_asset_status_should_not_revive_a_destroying_asset() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		for who in 1..=7 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 10));
+		}
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 2);
+
+		for is_frozen in [false, true] {
+			assert_noop!(
+				Assets::force_asset_status(
+					RuntimeOrigin::root(),
+					0,
+					2,
+					2,
+					2,
+					2,
+					1,
+					true,
+					is_frozen
+				),
+				Error::<Test>::IncorrectStatus
+			);
+		}
+
+		// nothing can be moved, and the destruction carries on where it stopped.
+		assert_eq!(
+			Assets::transfer(RuntimeOrigin::signed(6), 0, 1, 5).map_err(|e| e.error),
+			Err(Error::<Test>::AssetNotLive.into())
+		);
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(!Asset::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn set_min_balance_should_work() {
+	build_and_execute(|| {
+		let id = 42;
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), id, 1, 30));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 1, 100));
+		// Won't execute because there is an asset holder.
+		assert_noop!(
+			Assets::set_min_balance(RuntimeOrigin::signed(1), id, 50),
+			Error::<Test>::NoPermission
+		);
+
+		// Force asset status to make this a sufficient asset.
+		assert_ok!(Assets::force_asset_status(
+			RuntimeOrigin::root(),
+			id,
+			1,
+			1,
+			1,
+			1,
+			30,
+			true,
+			false
+		));
+
+		// Won't execute because there is an account holding the asset and the asset is marked as
+		// sufficient.
+		assert_noop!(
+			Assets::set_min_balance(RuntimeOrigin::signed(1), id, 10),
+			Error::<Test>::NoPermission
+		);
+
+		// Make the asset not sufficient.
+		assert_ok!(Assets::force_asset_status(
+			RuntimeOrigin::root(),
+			id,
+			1,
+			1,
+			1,
+			1,
+			60,
+			false,
+			false
+		));
+
+		// Will execute because the new value of min_balance is less than the
+		// old value. 10 < 30
+		assert_ok!(Assets::set_min_balance(RuntimeOrigin::signed(1), id, 10));
+		assert_eq!(Asset::<Test>::get(id).unwrap().min_balance, 10);
+
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), id, 1, 100));
+
+		assert_ok!(Assets::set_min_balance(RuntimeOrigin::signed(1), id, 50));
+		assert_eq!(Asset::<Test>::get(id).unwrap().min_balance, 50);
+	});
+}
+
+#[test]
+fn balance_conversion_should_work() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::ConversionToAssetBalance;
+
+		let id = 42;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 10));
+		let not_sufficient = 23;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), not_sufficient, 1, false, 10));
+		assert_eq!(asset_ids(), vec![23, 42, 999]);
+		assert_eq!(
+			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(100, 1234),
+			Err(ConversionError::AssetMissing)
+		);
+		assert_eq!(
+			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(
+				100,
+				not_sufficient
+			),
+			Err(ConversionError::AssetNotSufficient)
+		);
+		// 10 / 1 == 10 -> the conversion should 10x the value
+		assert_eq!(
+			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(100, id),
+			Ok(100 * 10)
+		);
+	});
+}
+
+#[test]
+fn assets_from_genesis_should_exist() {
+	build_and_execute(|| {
+		assert_eq!(asset_ids(), vec![999]);
+		assert!(Metadata::<Test>::contains_key(999));
+		assert_eq!(Assets::balance(999, 1), 100);
+		assert_eq!(Assets::total_supply(999), 100);
+	});
+}
+
+#[test]
+fn approvals_from_genesis_should_be_usable() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		accounts: vec![(0, 1, 100)],
+		approvals: vec![(0, 1, 2, 50), (0, 1, 3, 10)],
+		..Default::default()
+	};
+	new_test_ext_with(config).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 2);
+		assert_eq!(ApprovalCount::<Test>::get(0, 1), 2);
+		assert_eq!(Assets::approvals_for_delegate(&2), vec![(0, 1, 50)]);
+		assert_ok!(Assets::do_try_state());
+
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 4, 50));
+		assert_eq!(Assets::balance(0, 4), 50);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		// no deposit was taken, so none is returned.
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+#[should_panic(expected = "approvals[0]: the owner has no account of the asset")]
+fn approvals_from_genesis_need_an_owner_account() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		approvals: vec![(0, 1, 2, 50)],
+		..Default::default()
+	};
+	new_test_ext_with(config);
+}
+
+#[test]
+fn freezes_from_genesis_should_apply() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1), (1, 1, true, 1)],
+		accounts: vec![(0, 1, 100), (0, 2, 100), (1, 1, 100)],
+		frozen_assets: vec![1],
+		frozen_accounts: vec![(0, 2)],
+		..Default::default()
+	};
+	new_test_ext_with(config).execute_with(|| {
+		System::set_block_number(1);
+
+		// the frozen account can still receive, but not send.
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 10), Error::<Test>::Frozen);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 120);
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 10));
+
+		// the frozen asset can be neither transferred nor minted.
+		assert_eq!(Asset::<Test>::get(1).unwrap().status, AssetStatus::Frozen);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 10),
+			Error::<Test>::AssetNotLive
+		);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 1, 2, 10), Error::<Test>::AssetNotLive);
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 10));
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+fn mint_when_frozen_policy_should_be_respected() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+
+		// allowed by default.
+		assert!(AllowMintWhenFrozen::<Test>::get(0));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 110);
+
+		assert_noop!(
+			Assets::set_allow_mint_when_frozen(RuntimeOrigin::signed(2), 0, false),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_allow_mint_when_frozen(RuntimeOrigin::signed(1), 0, false));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AllowMintWhenFrozenSet {
+			asset_id: 0,
+			allow: false,
+		}));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10), Error::<Test>::Frozen);
+		assert_noop!(<Assets as fungibles::Mutate<_>>::mint_into(0, &2, 10), Error::<Test>::Frozen);
+
+		// other accounts, and transfers into the frozen one, are unaffected.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), 0, 2, 5));
+		assert_eq!(Assets::balance(0, 2), 115);
+
+		// blocked accounts are frozen too.
+		assert_ok!(Assets::block(RuntimeOrigin::signed(1), 0, 3));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 10), Error::<Test>::Frozen);
+
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 125);
+
+		// only the non-default setting is stored.
+		assert_ok!(Assets::set_allow_mint_when_frozen(RuntimeOrigin::signed(1), 0, true));
+		assert!(!AllowMintWhenFrozen::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn mint_into_frozen_asset_should_fail_whatever_the_policy() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		for allow in [true, false] {
+			assert_ok!(Assets::set_allow_mint_when_frozen(RuntimeOrigin::signed(1), 0, allow));
+			assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+			assert_eq!(
+				Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10).map_err(|e| e.error),
+				Err(Error::<Test>::AssetNotLive.into())
+			);
+			assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+			assert_eq!(
+				Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10).map_err(|e| e.error),
+				Err(Error::<Test>::AssetNotLive.into())
+			);
+			assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 2));
+			assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0));
+		}
+		assert_eq!(Assets::balance(0, 2), 100);
+	});
+}
+
+#[test]
+fn mint_when_frozen_policy_should_apply_to_genesis_accounts() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		accounts: vec![(0, 1, 100), (0, 2, 100)],
+		frozen_accounts: vec![(0, 2)],
+		..Default::default()
+	};
+	new_test_ext_with(config).execute_with(|| {
+		System::set_block_number(1);
+
+		// the genesis account was frozen after being funded.
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10));
+
+		assert_ok!(Assets::set_allow_mint_when_frozen(RuntimeOrigin::signed(1), 0, false));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10), Error::<Test>::Frozen);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10));
+		assert_eq!(Assets::balance(0, 1), 110);
+		assert_eq!(Assets::balance(0, 2), 110);
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+#[should_panic(expected = "frozen_accounts[0]: the account does not exist")]
+fn freezes_from_genesis_need_an_account() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		frozen_accounts: vec![(0, 2)],
+		..Default::default()
+	};
+	new_test_ext_with(config);
+}
+
+#[test]
+fn metadata_from_genesis_can_be_deposit_free() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		metadata: vec![(0, vec![0; 10], vec![0; 10], 12)],
+		..Default::default()
+	};
+	new_test_ext_with(config).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Metadata::<Test>::get(0).unwrap().deposit, 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().metadata_deposit, 0);
+
+		// shrinking the metadata takes the whole new deposit, since none was reserved before.
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 2], vec![0; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 13);
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+fn metadata_from_genesis_can_reserve_its_deposit() {
+	use sp_runtime::BuildStorage;
+
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		metadata: vec![(0, vec![0; 10], vec![0; 10], 12)],
+		reserve_metadata_deposits: true,
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Balances::reserved_balance(&1), 21);
+		assert_eq!(Metadata::<Test>::get(0).unwrap().deposit, 21);
+		assert_eq!(Asset::<Test>::get(0).unwrap().metadata_deposit, 21);
+
+		// shrinking the metadata returns the difference, clearing it returns the rest.
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 2], vec![0; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 13);
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 100);
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+#[should_panic(expected = "Asset owner cannot reserve the metadata deposit")]
+fn metadata_from_genesis_needs_the_owner_to_afford_its_deposit() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1)],
+		metadata: vec![(0, vec![0; 10], vec![0; 10], 12)],
+		reserve_metadata_deposits: true,
+		..Default::default()
+	};
+	new_test_ext_with(config);
+}
+
+#[test]
+fn genesis_config_problems_should_all_be_reported() {
+	let problem = |list, index, problem| GenesisProblem { list, index, problem };
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1), (0, 1, true, 0), (1, 1, true, 1)],
+		metadata: vec![(2, vec![0], vec![0], 12), (1, vec![0; 51], vec![0], 12)],
+		accounts: vec![
+			(0, 1, 100),
+			(0, 1, 100),
+			(1, 1, u64::MAX),
+			(1, 2, 1),
+			(3, 1, 10),
+			(0, 2, 0),
+		],
+		approvals: vec![(0, 3, 2, 10), (0, 1, 2, 10), (0, 1, 2, 20)],
+		frozen_assets: vec![4],
+		frozen_accounts: vec![(1, 3)],
+		..Default::default()
+	};
+	assert_eq!(
+		config.validate(),
+		vec![
+			problem("assets", 1, "the asset id is already in use"),
+			problem("assets", 1, "the min balance is zero"),
+			problem("metadata", 0, "the asset does not exist"),
+			problem("metadata", 1, "the name is too long"),
+			problem("accounts", 1, "the account is already listed for the asset"),
+			problem("accounts", 3, "the supply of the asset overflows"),
+			problem("accounts", 4, "the asset does not exist"),
+			problem("accounts", 5, "the balance is below the min balance"),
+			problem("approvals", 0, "the owner has no account of the asset"),
+			problem("approvals", 2, "the approval is already listed"),
+			problem("frozen_assets", 0, "the asset does not exist"),
+			problem("frozen_accounts", 0, "the account does not exist"),
+		]
+	);
+}
+
+#[test]
+#[should_panic(expected = "Invalid assets genesis config:\nassets[1]: the min balance is zero\n\
+	accounts[1]: the account is already listed for the asset")]
+fn genesis_config_problems_should_fail_the_build() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1), (1, 1, true, 0)],
+		accounts: vec![(0, 1, 100), (0, 1, 100)],
+		..Default::default()
+	};
+	new_test_ext_with(config);
+}
+
+#[test]
+fn asset_types_should_serialize_for_chain_specs() {
+	type MetadataOf =
+		AssetMetadata<u64, frame_support::BoundedVec<u8, <Test as Config>::StringLimit>>;
+
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 999, 2, 50));
+
+		let details = Asset::<Test>::get(999).unwrap();
+		let json = serde_json::to_value(&details).unwrap();
+		assert_eq!(json["owner"], 0);
+		assert_eq!(json["supply"], "100");
+		assert_eq!(json["minBalance"], "1");
+		assert_eq!(json["metadataDeposit"], "0");
+		assert_eq!(json["status"], "live");
+		assert_eq!(serde_json::from_value::<AssetDetailsOf<Test, ()>>(json).unwrap(), details);
+
+		let metadata = Metadata::<Test>::get(999).unwrap();
+		let json = serde_json::to_value(&metadata).unwrap();
+		assert_eq!(json["name"], "0x546f6b656e204e616d65");
+		assert_eq!(json["symbol"], "0x544f4b454e");
+		assert_eq!(json["deposit"], "0");
+		assert_eq!(serde_json::from_value::<MetadataOf>(json).unwrap(), metadata);
+
+		let account = Account::<Test>::get(999, 1).unwrap();
+		let json = serde_json::to_value(&account).unwrap();
+		assert_eq!(json["balance"], "100");
+		assert_eq!(json["status"], "liquid");
+		assert_eq!(json["reason"], "sufficient");
+		assert_eq!(serde_json::from_value::<AssetAccountOf<Test, ()>>(json).unwrap(), account);
+
+		let approval = Approvals::<Test>::get((999, 1, 2)).unwrap();
+		let json = serde_json::to_value(&approval).unwrap();
+		assert_eq!(json["amount"], "50");
+		assert_eq!(json["deposit"], "1");
+		assert_eq!(serde_json::from_value::<Approval<u64, u64>>(json).unwrap(), approval);
+
+		// balances are strings, so even the largest `u128` survives the trip.
+		let reason = ExistenceReason::<u128, u64>::DepositFrom(3, u128::MAX);
+		let json = serde_json::to_string(&reason).unwrap();
+		assert_eq!(json, r#"{"depositFrom":[3,"340282366920938463463374607431768211455"]}"#);
+		assert_eq!(serde_json::from_str::<ExistenceReason<u128, u64>>(&json).unwrap(), reason);
+
+		// names and symbols longer than `StringLimit` are rejected.
+		let long = format!(
+			r#"{{"deposit":"0","name":"0x{}","symbol":"0x","decimals":0,"isFrozen":false}}"#,
+			"00".repeat(51)
+		);
+		assert!(serde_json::from_str::<MetadataOf>(&long).is_err());
+	});
+}
+
+#[test]
+fn genesis_config_should_round_trip_through_json() {
+	let config = GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 1), (1, 2, false, 10)],
+		metadata: vec![(0, b"Token".to_vec(), b"TKN".to_vec(), 12)],
+		reserve_metadata_deposits: true,
+		accounts: vec![(0, 1, 100), (1, 2, 1_000)],
+		approvals: vec![(0, 1, 3, 50)],
+		frozen_assets: vec![1],
+		frozen_accounts: vec![(0, 1)],
+	};
+	let json = serde_json::to_string(&config).unwrap();
+	let decoded = serde_json::from_str::<GenesisConfig<Test>>(&json).unwrap();
+	assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+}
+
+#[test]
+fn genesis_config_should_build_from_a_chain_spec_fragment() {
+	// the genesis keeps the representation of the `genesis_config` derive, so names and symbols
+	// are byte arrays and balances are numbers.
+	let fragment = r#"{
+		"assets": [[0, 1, true, 1]],
+		"metadata": [[0, [84, 111, 107, 101, 110], [84, 75, 78], 12]],
+		"reserveMetadataDeposits": false,
+		"accounts": [[0, 1, 100], [0, 2, 50]],
+		"approvals": [[0, 1, 3, 20]],
+		"frozenAssets": [],
+		"frozenAccounts": [[0, 2]]
+	}"#;
+	let config = serde_json::from_str::<GenesisConfig<Test>>(fragment).unwrap();
+	new_test_ext_with(config).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Assets::total_supply(0), 150);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert!(Account::<Test>::get(0, 2).unwrap().status.is_frozen());
+		assert_eq!(Metadata::<Test>::get(0).unwrap().name.to_vec(), b"Token".to_vec());
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		assert_ok!(Assets::do_try_state());
+	});
+
+	// unknown fields are a mistake in the chain spec, not something to ignore.
+	let typo = fragment.replace("frozenAssets", "frozenAsset");
+	assert!(serde_json::from_str::<GenesisConfig<Test>>(&typo).is_err());
+}
+
+#[test]
+fn querying_name_symbol_and_decimals_should_work() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::fungibles::metadata::Inspect;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![0u8; 10],
+			vec![1u8; 10],
+			12,
+			false
+		));
+		assert_eq!(Assets::name(0), vec![0u8; 10]);
+		assert_eq!(Assets::symbol(0), vec![1u8; 10]);
+		assert_eq!(Assets::decimals(0), 12);
+	});
+}
+
+#[test]
+fn querying_allowance_should_work() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::fungibles::approvals::{Inspect, Mutate};
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+		assert_ok!(Assets::approve(0, &1, &2, 50));
+		assert_eq!(<Assets as Inspect<u64>>::allowance(0, &1, &2), 50);
+		// Transfer asset 0, from owner 1 and delegate 2 to destination 3
+		assert_ok!(Assets::transfer_from(0, &1, &2, &3, 50));
+		assert_eq!(<Assets as Inspect<u64>>::allowance(0, &1, &2), 0);
+	});
+}
+
+#[test]
+fn approval_lifecycle_through_traits_works() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::fungibles::approvals::{Inspect, Mutate};
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+
+		// approvals are additive and take a single deposit.
+		assert_ok!(Assets::approve(0, &1, &2, 30));
+		assert_ok!(Assets::approve(0, &1, &2, 20));
+		assert_eq!(<Assets as Inspect<u64>>::allowance(0, &1, &2), 50);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+
+		// a partial spend keeps the deposit...
+		assert_ok!(Assets::transfer_from(0, &1, &2, &3, 20));
+		assert_eq!(<Assets as Inspect<u64>>::allowance(0, &1, &2), 30);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_noop!(Assets::transfer_from(0, &1, &2, &3, 31), Error::<Test>::Unapproved);
+		assert_noop!(Assets::transfer_from(0, &1, &3, &3, 1), Error::<Test>::Unapproved);
+
+		// ...and consuming the rest returns it.
+		assert_ok!(Assets::transfer_from(0, &1, &2, &3, 30));
+		assert_eq!(<Assets as Inspect<u64>>::allowance(0, &1, &2), 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert!(!Approvals::<Test>::contains_key((0, 1, 2)));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 3), 50);
+	});
+}
+
+#[test]
+fn hold_and_release_should_work() {
+	use frame_support::traits::tokens::{
+		fungibles::{Inspect, InspectHold, MutateHold},
+		Precision::Exact,
+	};
+	use TestHoldReason::*;
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::hold(0, &Escrow, &1, 30));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Held {
+			asset_id: 0,
+			who: 1,
+			reason: Escrow,
+			amount: 30,
+		}));
+		assert_ok!(Assets::hold(0, &Staking, &1, 20));
+		assert_ok!(Assets::hold(0, &Escrow, &1, 10));
+		assert_eq!(Assets::balance_on_hold(0, &Escrow, &1), 40);
+		assert_eq!(Assets::balance_on_hold(0, &Staking, &1), 20);
+		assert_eq!(<Assets as InspectHold<u64>>::total_balance_on_hold(0, &1), 60);
+		// held funds are still owned and still part of the supply.
+		assert_eq!(Assets::balance(0, 1), 40);
+		assert_eq!(<Assets as Inspect<u64>>::total_balance(0, &1), 100);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// held funds cannot be moved, and the account must stay alive to keep them.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_eq!(Assets::balance(0, 1), 10);
+		// cannot hold more than what can be spent.
+		assert_noop!(Assets::hold(0, &Escrow, &1, 1), TokenError::FundsUnavailable);
+
+		// releasing returns the funds to the balance.
+		assert_noop!(Assets::release(0, &Staking, &1, 21, Exact), TokenError::FundsUnavailable);
+		assert_eq!(Assets::release(0, &Staking, &1, 20, Exact), Ok(20));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Released {
+			asset_id: 0,
+			who: 1,
+			reason: Staking,
+			amount: 20,
+		}));
+		assert_eq!(Assets::balance_on_hold(0, &Staking, &1), 0);
+		assert_eq!(Assets::balance(0, 1), 30);
+		assert_eq!(Holds::<Test>::get(0, 1).len(), 1);
+
+		// once nothing is held, the account may die again.
+		assert_eq!(Assets::release(0, &Escrow, &1, 40, Exact), Ok(40));
+		assert!(!Holds::<Test>::contains_key(0, 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 70));
+		assert!(!Account::<Test>::contains_key(0, 1));
+		assert_eq!(Assets::total_supply(0), 100);
+	});
+}
+
+#[test]
+fn holds_should_be_limited() {
+	use frame_support::traits::tokens::fungibles::{InspectHold, MutateHold};
+	use TestHoldReason::*;
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::hold(0, &Escrow, &1, 10));
+		assert_ok!(Assets::hold(0, &Staking, &1, 10));
+		assert!(!Assets::hold_available(0, &Governance, &1));
+		assert_noop!(Assets::hold(0, &Governance, &1, 10), TokenError::CannotCreateHold);
+		// existing holds can still be increased.
+		assert!(Assets::hold_available(0, &Escrow, &1));
+		assert_ok!(Assets::hold(0, &Escrow, &1, 10));
+
+		// accounts which don't exist can't have holds.
+		assert!(!Assets::hold_available(0, &Escrow, &2));
+		assert_noop!(Assets::hold(0, &Escrow, &2, 0), TokenError::CannotCreateHold);
+	});
+}
+
+#[test]
+fn holds_should_keep_accounts_alive() {
+	use frame_support::traits::tokens::{
+		fungibles::MutateHold, Fortitude::Polite, Precision::Exact,
+	};
+	use TestHoldReason::*;
+
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 10));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::hold(0, &Escrow, &2, 50));
+
+		// the admin can only burn down to the minimum balance.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Assets::balance_on_hold(0, &Escrow, &2), 50);
+		// the account can't get its deposit back while holding funds.
+		assert_noop!(
+			Assets::refund(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test>::ContainsHolds
+		);
+		assert_noop!(
+			Assets::force_refund(RuntimeOrigin::signed(1), 0, 2, true),
+			Error::<Test>::ContainsHolds
+		);
+
+		// burning held funds reduces the supply.
+		assert_eq!(Assets::burn_held(0, &Escrow, &2, 20, Exact, Polite), Ok(20));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::BurnedHeld {
+			asset_id: 0,
+			who: 2,
+			reason: Escrow,
+			amount: 20,
+		}));
+		assert_eq!(Assets::balance_on_hold(0, &Escrow, &2), 30);
+		assert_eq!(Assets::total_supply(0), 40);
+
+		// destroying the asset also removes the holds.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert!(!Holds::<Test>::contains_key(0, 2));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn freezes_should_work() {
+	use frame_support::traits::tokens::{
+		fungibles::{Inspect, InspectFreeze, MutateFreeze},
+		Fortitude::Polite,
+		Preservation::Expendable,
+	};
+	use TestFreezeId::*;
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		let reducible = || <Assets as Inspect<u64>>::reducible_balance(0, &1, Expendable, Polite);
+
+		// the largest freeze applies, and the account is kept alive.
+		assert_ok!(Assets::set_freeze(0, &Vesting, &1, 30));
+		assert_ok!(Assets::set_freeze(0, &Voting, &1, 50));
+		assert_eq!(Assets::balance_frozen(0, &Vesting, &1), 30);
+		assert_eq!(Assets::balance_frozen(0, &Voting, &1), 50);
+		assert_eq!(reducible(), 40);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 41),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+
+		// extending never lowers a freeze.
+		assert_ok!(Assets::extend_freeze(0, &Vesting, &1, 20));
+		assert_eq!(Assets::balance_frozen(0, &Vesting, &1), 30);
+		assert_ok!(Assets::extend_freeze(0, &Vesting, &1, 45));
+		assert_eq!(Assets::balance_frozen(0, &Vesting, &1), 45);
+
+		// the legacy freezer is combined with the named freezes.
+		set_frozen_balance(0, 1, 55);
+		assert_eq!(reducible(), 0);
+		clear_frozen_balance(0, 1);
+		assert_ok!(<Assets as MutateFreeze<u64>>::thaw(0, &Voting, &1));
+		assert_eq!(Assets::balance_frozen(0, &Voting, &1), 0);
+		assert_eq!(reducible(), 5);
+		set_frozen_balance(0, 1, 20);
+		assert_eq!(reducible(), 5);
+		clear_frozen_balance(0, 1);
+
+		// setting a freeze to zero is the same as thawing it.
+		assert_ok!(Assets::set_freeze(0, &Vesting, &1, 0));
+		assert!(!Freezes::<Test>::contains_key(0, 1));
+		assert_eq!(reducible(), 60);
+
+		// freezes are limited, and only exist on accounts.
+		assert_ok!(Assets::set_freeze(0, &Vesting, &1, 10));
+		assert_ok!(Assets::set_freeze(0, &Voting, &1, 10));
+		assert!(!Assets::can_freeze(0, &Governance, &1));
+		assert_noop!(Assets::set_freeze(0, &Governance, &1, 10), Error::<Test>::TooManyFreezes);
+		assert!(Assets::can_freeze(0, &Voting, &1));
+		assert!(!Assets::can_freeze(0, &Vesting, &3));
+		assert_noop!(Assets::set_freeze(0, &Vesting, &3, 10), Error::<Test>::NoAccount);
+	});
+}
+
+#[test]
+fn freezes_should_keep_accounts_alive() {
+	use frame_support::traits::tokens::fungibles::MutateFreeze;
+	use TestFreezeId::*;
+
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 10));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::set_freeze(0, &Vesting, &2, 20));
+
+		// the account can't be emptied while frozen, even without asking to keep it alive.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 100),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 70));
+		assert_eq!(Assets::balance(0, 2), 30);
+
+		// burning respects the freeze as well.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_noop!(
+			Assets::refund(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test>::ContainsFreezes
+		);
+
+		// once thawed, the deposit can be refunded.
+		assert_ok!(<Assets as MutateFreeze<u64>>::thaw(0, &Vesting, &2));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, true));
+		assert!(!Account::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn slashing_held_funds_should_work() {
+	use frame_support::traits::tokens::fungibles::{Balanced, BalancedHold, MutateHold};
+	use TestHoldReason::*;
+
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::hold(0, &Escrow, &1, 60));
+		assert_ok!(Assets::hold(0, &Staking, &1, 20));
+
+		// only what is held for the reason can be slashed; the rest is reported back.
+		let (credit, remaining) = Assets::slash(0, &Escrow, &1, 80);
+		assert_eq!(credit.peek(), 60);
+		assert_eq!(remaining, 20);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SlashedHeld {
+			asset_id: 0,
+			who: 1,
+			reason: Escrow,
+			amount: 60,
+		}));
+		assert_eq!(Assets::balance_on_hold(0, &Escrow, &1), 0);
+		assert_eq!(Assets::balance_on_hold(0, &Staking, &1), 20);
+		assert_eq!(Assets::balance(0, 1), 20);
+		// the supply is only reduced once the credit is dropped.
+		assert_eq!(Assets::total_supply(0), 100);
+		drop(credit);
+		assert_eq!(Assets::total_supply(0), 40);
+
+		// the credit may instead be handed to a beneficiary, leaving the supply intact.
+		let (credit, remaining) = Assets::slash(0, &Staking, &1, 15);
+		assert_eq!(remaining, 0);
+		assert_ok!(Assets::resolve(&2, credit));
+		assert_eq!(Assets::balance(0, 2), 15);
+		assert_eq!(Assets::balance_on_hold(0, &Staking, &1), 5);
+		assert_eq!(Assets::total_supply(0), 40);
+
+		// nothing is slashed from a reason with nothing on hold.
+		let (credit, remaining) = Assets::slash(0, &Governance, &1, 10);
+		assert_eq!(credit.peek(), 0);
+		assert_eq!(remaining, 10);
+		assert_eq!(Assets::balance(0, 1), 20);
+	});
+}
+
+#[test]
+fn transfer_large_asset() {
+	build_and_execute(|| {
+		let amount = u64::pow(2, 63) + 2;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, amount));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, amount - 1));
+	})
+}
+
+#[test]
+fn querying_roles_should_work() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::fungibles::roles::Inspect;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::set_team(
+			RuntimeOrigin::signed(1),
+			0,
+			// Issuer
+			2,
+			// Admin
+			3,
+			// Freezer
+			4,
+		));
+		assert_eq!(<Assets as Inspect<u64>>::owner(0), Some(1));
+		assert_eq!(<Assets as Inspect<u64>>::issuer(0), Some(2));
+		assert_eq!(<Assets as Inspect<u64>>::admin(0), Some(3));
+		assert_eq!(<Assets as Inspect<u64>>::freezer(0), Some(4));
+		// the pallet's own helpers agree with the trait.
+		assert_eq!(Assets::owner(0), Some(1));
+		assert_eq!(Assets::issuer(0), Some(2));
+		assert_eq!(Assets::admin(0), Some(3));
+		assert_eq!(Assets::freezer(0), Some(4));
+		// unknown assets have no roles.
+		assert_eq!(<Assets as Inspect<u64>>::owner(1), None);
+		assert_eq!(Assets::admin(1), None);
+	});
+}
+
+#[test]
+fn querying_asset_existence_should_work() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::{fungibles::Inspect, DepositConsequence, Provenance};
+		// unknown assets don't exist.
+		assert!(!Assets::asset_exists(0));
+		assert!(!<Assets as Inspect<u64>>::asset_exists(0));
+		assert_eq!(Assets::asset_status(0), None);
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert!(Assets::asset_exists(0));
+		assert!(<Assets as Inspect<u64>>::asset_exists(0));
+		assert_eq!(Assets::asset_status(0), Some(AssetStatus::Live));
+
+		// frozen assets still exist.
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert!(Assets::asset_exists(0));
+		assert_eq!(Assets::asset_status(0), Some(AssetStatus::Frozen));
+
+		// assets being destroyed are reported as unknown, though their status is still there.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(!Assets::asset_exists(0));
+		assert!(!<Assets as Inspect<u64>>::asset_exists(0));
+		assert_eq!(Assets::asset_status(0), Some(AssetStatus::Destroying));
+		assert_eq!(
+			<Assets as Inspect<u64>>::can_deposit(0, &1, 10, Provenance::Minted),
+			DepositConsequence::UnknownAsset
+		);
+
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::asset_status(0), None);
+	});
+}
+
+#[test]
+fn normal_asset_create_and_destroy_callbacks_should_work() {
+	build_and_execute(|| {
+		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_none());
+		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_some());
+		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		// Callback still hasn't been invoked
+		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_some());
+	});
+}
+
+#[test]
+fn root_asset_create_should_work() {
+	build_and_execute(|| {
+		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_none());
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_some());
+		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+	});
+}
+
+#[test]
+fn create_origin_should_be_respected() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		// only the configured creators may create assets, and the deposit is taken from them.
+		set_asset_creators(vec![1]);
+		assert_noop!(Assets::create(RuntimeOrigin::signed(2), 0, 2, 1), DispatchError::BadOrigin);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 2, 1));
+		assert_eq!(Assets::owner(0), Some(1));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn role_origin_should_act_as_the_freezer() {
+	build_and_execute(|| {
+		let user_asset = USER_ASSET_IDS_START;
+		for id in [0, user_asset] {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 1));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 2, 100));
+		}
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 100));
+		let collective = RuntimeOrigin::signed(COLLECTIVE);
+
+		// without a role, the collective is just another account.
+		assert_noop!(Assets::freeze(collective.clone(), 0, 2), Error::<Test>::NoPermission);
+
+		set_collective_role(Role::Freezer);
+		assert_ok!(Assets::freeze(collective.clone(), 0, 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Frozen {
+			asset_id: 0,
+			who: 2,
+		}));
+		assert_ok!(Assets::block(collective.clone(), 0, 3));
+		assert_eq!(Account::<Test>::get(0, 3).unwrap().status, AccountStatus::Blocked);
+
+		// it acts in no other role, and not for the assets outside of its range.
+		assert_noop!(Assets::thaw(collective.clone(), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(collective.clone(), 0, 2, 10), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::freeze(collective.clone(), user_asset, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(Assets::freeze(collective.clone(), 1, 2), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::freeze_asset(collective, 0));
+		assert_eq!(Asset::<Test>::get(0).unwrap().status, AssetStatus::Frozen);
+
+		// the accounts holding the roles still act as usual.
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), user_asset, 2));
+	});
+}
+
+#[test]
+fn role_origin_should_act_as_the_admin_and_the_owner() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		let collective = RuntimeOrigin::signed(COLLECTIVE);
+
+		set_collective_role(Role::Admin);
+		assert_ok!(Assets::force_transfer(collective.clone(), 0, 2, 3, 10));
+		assert_ok!(Assets::burn(collective.clone(), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 80);
+		assert_eq!(Assets::balance(0, 3), 10);
+		assert_noop!(
+			Assets::set_max_holders(collective.clone(), 0, Some(1)),
+			Error::<Test>::NoPermission
+		);
+
+		// the deposits of an Owner acting through the collective are still those of the Owner.
+		set_collective_role(Role::Owner);
+		assert_ok!(Assets::set_metadata(collective.clone(), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 21);
+		assert_eq!(Balances::reserved_balance(&COLLECTIVE), 0);
+		assert_ok!(Assets::set_team(collective.clone(), 0, 4, 4, 4));
+		assert_ok!(Assets::transfer_ownership(collective.clone(), 0, 4));
+		assert_eq!(Assets::owner(0), Some(4));
+		assert_eq!(Balances::reserved_balance(&4), 21);
+		assert_ok!(Assets::start_destroy(collective, 0));
+		assert_eq!(Asset::<Test>::get(0).unwrap().status, AssetStatus::Destroying);
+	});
+}
+
+#[test]
+fn reserved_asset_ids_should_be_respected() {
+	build_and_execute(|| {
+		use frame_support::traits::fungibles::Create;
+		Balances::make_free_balance_be(&1, 100);
+		let (first, last) = (RESERVED_ASSET_IDS.start, RESERVED_ASSET_IDS.end - 1);
+
+		// reserved ids can't be created by signed origins, at both ends of the range.
+		for id in [first, last] {
+			assert_noop!(
+				Assets::create(RuntimeOrigin::signed(1), id, 1, 1),
+				Error::<Test>::IdReserved
+			);
+		}
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), first - 1, 1, 1));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), last + 1, 1, 1));
+
+		// but they can be forced, or created by other pallets.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), first, 1, true, 1));
+		assert_ok!(<Assets as Create<u64>>::create(last, 1, true, 1));
+		assert_eq!(Assets::owner(first), Some(1));
+		assert_eq!(Assets::owner(last), Some(1));
+	});
+}
+
+#[test]
+fn deposit_policy_should_be_respected() {
+	build_and_execute(|| {
+		use frame_support::traits::AccountTouch;
+		let user_asset = USER_ASSET_IDS_START;
+		Balances::make_free_balance_be(&1, 1000);
+		Balances::make_free_balance_be(&2, 1000);
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), user_asset, 1, 1));
+		assert_eq!(Asset::<Test>::get(user_asset).unwrap().deposit, 10);
+		assert_eq!(Balances::reserved_balance(&1), 11);
+
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		assert_eq!(<Assets as AccountTouch<u32, u64>>::deposit_required(user_asset), 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), user_asset));
+		assert_eq!(Balances::reserved_balance(&2), 110);
+
+		// 3 bytes of metadata, on top of the base deposit.
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			user_asset,
+			b"ab".to_vec(),
+			b"c".to_vec(),
+			0
+		));
+		assert_eq!(Metadata::<Test>::get(user_asset).unwrap().deposit, 40);
+		assert_eq!(Balances::reserved_balance(&1), 51);
+
+		// refunds return what was reserved, whatever the current policy.
+		set_user_deposit_factor(1);
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), user_asset, false));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), user_asset));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), user_asset));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), user_asset, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), user_asset, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), user_asset));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+	});
+}
+
+#[test]
+fn metadata_deposit_trait_should_cover_the_metadata_of_any_asset() {
+	build_and_execute(|| {
+		use frame_support::traits::fungibles::metadata::MetadataDeposit;
+		let user_asset = USER_ASSET_IDS_START;
+		let quote = <Assets as MetadataDeposit<u64>>::calc_metadata_deposit;
+
+		// 3 bytes of metadata take 4 for asset 0 and 40 for a user asset.
+		assert_eq!(Assets::calc_metadata_deposit(&0, b"ab", b"c"), 4);
+		assert_eq!(Assets::calc_metadata_deposit(&user_asset, b"ab", b"c"), 40);
+		assert_eq!(quote(b"ab", b"c"), 40);
+
+		set_user_deposit_factor(1);
+		assert_eq!(quote(b"ab", b"c"), 4);
+	});
+}
+
+#[test]
+fn creation_events_report_deposit() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 2, 1));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Created {
+			asset_id: 0,
+			creator: 1,
+			owner: 2,
+			deposit: 1,
+		}));
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ForceCreated {
+			asset_id: 1,
+			owner: 1,
+			deposit: 0,
+		}));
+	});
+}
+
+#[test]
+fn asset_create_and_destroy_is_reverted_if_callback_fails() {
+	build_and_execute(|| {
+		// Asset creation fails due to callback failure
+		AssetsCallbackHandle::set_return_error();
+		Balances::make_free_balance_be(&1, 100);
+		assert_noop!(
+			Assets::create(RuntimeOrigin::signed(1), 0, 1, 1),
+			Error::<Test>::CallbackFailed
+		);
+
+		// Callback succeeds, so asset creation succeeds
+		AssetsCallbackHandle::set_return_ok();
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+
+		// Asset destroy should fail due to callback failure
+		AssetsCallbackHandle::set_return_error();
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_noop!(
+			Assets::finish_destroy(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::CallbackFailed
+		);
+	});
+}
+
+#[test]
+fn callbacks_should_see_the_asset_and_owner() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		// the creator is reported as the owner, not the admin.
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 2, 1));
+		assert_eq!(AssetsCallbackHandle::last_created(), Some((0, 1)));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 3, true, 1));
+		assert_eq!(AssetsCallbackHandle::last_created(), Some((1, 3)));
+		assert_eq!(AssetsCallbackHandle::last_destroyed(), None);
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(AssetsCallbackHandle::last_destroyed(), Some(0));
+	});
+}
+
+#[test]
+fn failing_callbacks_should_revert_trait_calls() {
+	build_and_execute(|| {
+		use frame_support::traits::fungibles::{Create, Destroy};
+		AssetsCallbackHandle::set_return_error();
+		assert_noop!(
+			Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1),
+			Error::<Test>::CallbackFailed
+		);
+		assert_noop!(<Assets as Create<u64>>::create(0, 1, true, 1), Error::<Test>::CallbackFailed);
+		assert!(!Asset::<Test>::contains_key(0));
+
+		AssetsCallbackHandle::set_return_ok();
+		assert_ok!(<Assets as Create<u64>>::create(0, 1, true, 1));
+		assert_ok!(<Assets as Destroy<u64>>::start_destroy(0, None));
+		AssetsCallbackHandle::set_return_error();
+		assert_noop!(<Assets as Destroy<u64>>::finish_destroy(0), Error::<Test>::CallbackFailed);
+		assert_eq!(Assets::asset_status(0), Some(AssetStatus::Destroying));
+	});
+}
+
+#[test]
+fn multiple_transfer_alls_work_ok() {
+	build_and_execute(|| {
+		// Only run PoC when the system pallet is enabled, since the underlying bug is in the
+		// system pallet it won't work with BalancesAccountStore
+		// Start with a balance of 100
+		Balances::force_set_balance(RuntimeOrigin::root(), 1, 100).unwrap();
+		// Emulate a sufficient, in reality this could be reached by transferring a sufficient
+		// asset to the account
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		// Spend the same balance multiple times
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(1), 1337, false));
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(1), 1337, false));
+
+		assert_eq!(Balances::free_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1337), 100);
+	});
+}
+
+#[test]
+fn weights_sane() {
+	let info = crate::Call::<Test>::create { id: 10, admin: 4, min_balance: 3 }.get_dispatch_info();
+	assert_eq!(<() as crate::WeightInfo>::create(), info.weight);
+
+	let info = crate::Call::<Test>::finish_destroy { id: 10 }.get_dispatch_info();
+	assert_eq!(<() as crate::WeightInfo>::finish_destroy(), info.weight);
+
+	// the destroy calls are weighed for the batch asked for, up to the configured limit.
+	let info = crate::Call::<Test>::destroy_accounts { id: 10, max_items: 2 }.get_dispatch_info();
+	assert_eq!(<() as crate::WeightInfo>::destroy_accounts(2), info.weight);
+	let info =
+		crate::Call::<Test>::destroy_accounts { id: 10, max_items: u32::MAX }.get_dispatch_info();
+	let limit = <Test as Config>::RemoveAccountsLimit::get();
+	assert_eq!(<() as crate::WeightInfo>::destroy_accounts(limit), info.weight);
+
+	let info = crate::Call::<Test>::destroy_approvals { id: 10, max_items: 2 }.get_dispatch_info();
+	assert_eq!(<() as crate::WeightInfo>::destroy_approvals(2), info.weight);
+	let info =
+		crate::Call::<Test>::destroy_approvals { id: 10, max_items: u32::MAX }.get_dispatch_info();
+	let limit = <Test as Config>::RemoveApprovalsLimit::get();
+	assert_eq!(<() as crate::WeightInfo>::destroy_approvals(limit), info.weight);
+
+	let info = crate::Call::<Test>::touch { id: 10 }.get_dispatch_info();
+	assert_eq!(<() as crate::WeightInfo>::touch(), info.weight);
+
+	let info = crate::Call::<Test>::refund { id: 10, allow_burn: true }.get_dispatch_info();
+	assert_eq!(<() as crate::WeightInfo>::refund(), info.weight);
+}
+
+#[test]
+fn weights_should_be_monotonic() {
+	fn check<W: crate::WeightInfo>() {
+		let increasing = |f: &dyn Fn(u32) -> Weight, max: u32| {
+			let mut last = f(0);
+			for x in (0..=max).step_by(7).chain([max, max.saturating_add(1), u32::MAX]) {
+				let next = f(x);
+				assert!(last.all_lte(next), "weight decreased at {}: {:?} > {:?}", x, last, next);
+				last = next;
+			}
+		};
+		let accounts = <Test as Config>::RemoveAccountsLimit::get();
+		let approvals = <Test as Config>::RemoveApprovalsLimit::get();
+		let string_limit = <Test as Config>::StringLimit::get();
+
+		increasing(&|c| W::destroy_accounts(c), accounts);
+		increasing(&|a| W::destroy_approvals(a), approvals);
+		increasing(&|n| W::owned_assets(n), 1000);
+		for other in [0, string_limit] {
+			increasing(&|n| W::set_metadata(n, other), string_limit);
+			increasing(&|s| W::set_metadata(other, s), string_limit);
+			increasing(&|n| W::force_set_metadata(n, other), string_limit);
+			increasing(&|s| W::force_set_metadata(other, s), string_limit);
+		}
+
+		// The limit-scaled weights grow in both dimensions and saturate instead of overflowing.
+		assert!(W::destroy_accounts(0).any_lt(W::destroy_accounts(1)));
+		assert!(W::destroy_approvals(0).any_lt(W::destroy_approvals(1)));
+		assert!(W::destroy_accounts(1).proof_size() > W::destroy_accounts(0).proof_size());
+		assert!(W::destroy_approvals(1).proof_size() > W::destroy_approvals(0).proof_size());
+		assert!(W::destroy_accounts(accounts).all_lte(W::destroy_accounts(u32::MAX)));
+		assert!(W::destroy_approvals(approvals).all_lte(W::destroy_approvals(u32::MAX)));
+	}
+
+	check::<()>();
+	check::<crate::weights::SubstrateWeight<Test>>();
+}
+
+#[test]
+fn early_exits_should_only_charge_for_one_read() {
+	build_and_execute(|| {
+		let one_read = Some(
+			<Test as frame_system::Config>::DbWeight::get()
+				.reads(1)
+				.set_proof_size(u64::MAX),
+		);
+		let actual_weight = |result: DispatchResultWithPostInfo| match result {
+			Ok(info) => info.actual_weight,
+			Err(e) => e.post_info.actual_weight,
+		};
+
+		// only the execution time is refunded, the proof size is charged in full.
+		let call = crate::Call::<Test>::mint { id: 0, beneficiary: 1, amount: 100 };
+		let info = call.get_dispatch_info();
+		let post_info = Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100).unwrap_err().post_info;
+		assert_eq!(
+			post_info.calc_actual_weight(&info),
+			<Test as frame_system::Config>::DbWeight::get()
+				.reads(1)
+				.set_proof_size(info.weight.proof_size()),
+		);
+
+		// the asset is unknown.
+		assert_eq!(actual_weight(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100)), one_read);
+		assert_eq!(actual_weight(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50)), one_read);
+		assert_eq!(
+			actual_weight(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50)),
+			one_read
+		);
+
+		// the asset is not live.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			actual_weight(Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 2, 50)),
+			one_read
+		);
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), 0));
+
+		// other errors and successes are charged the full weight.
+		assert_eq!(actual_weight(Assets::mint(RuntimeOrigin::signed(2), 0, 1, 100)), None);
+		assert_eq!(actual_weight(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 500)), None);
+		assert_eq!(actual_weight(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50)), None);
+	});
+}
+
+#[test]
+fn asset_destroy_refund_existence_deposit() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		let admin = 1;
+		let admin_origin = RuntimeOrigin::signed(admin);
+
+		let account2 = 2; // account with own deposit
+		let account3 = 3; // account with admin's deposit
+		Balances::make_free_balance_be(&account2, 100);
+
+		assert_eq!(Balances::reserved_balance(&account2), 0);
+		assert_eq!(Balances::reserved_balance(&account3), 0);
+		assert_eq!(Balances::reserved_balance(&admin), 0);
+
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(account2), 0));
+		assert_ok!(Assets::touch_other(admin_origin.clone(), 0, account3));
+
+		assert_eq!(Balances::reserved_balance(&account2), 10);
+		assert_eq!(Balances::reserved_balance(&account3), 0);
+		assert_eq!(Balances::reserved_balance(&admin), 10);
+
+		assert_ok!(Assets::start_destroy(admin_origin.clone(), 0));
+		assert_ok!(Assets::destroy_accounts(admin_origin.clone(), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(admin_origin.clone(), 0, u32::MAX));
+		assert_ok!(Assets::finish_destroy(admin_origin.clone(), 0));
+
+		assert_eq!(Balances::reserved_balance(&account2), 0);
+		assert_eq!(Balances::reserved_balance(&account3), 0);
+		assert_eq!(Balances::reserved_balance(&admin), 0);
+	});
+}
+
+#[test]
+fn stored_map_should_take_and_release_account_deposits() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		// nobody pays for the account.
+		assert_noop!(Tags::tag(RuntimeOrigin::signed(2), 0, 7), Error::<Test>::NoDeposit);
+
+		set_extra_payer(1);
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(1), 0, 7));
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(2), 0, 8));
+		assert_eq!(Balances::reserved_balance(&1), 20);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(asset_account_counts(0), (2, 0));
+		let account = Account::<Test>::get(0, 1).unwrap();
+		assert_eq!((account.reason, account.extra), (ExistenceReason::DepositHeld(10), 7));
+		let account = Account::<Test>::get(0, 2).unwrap();
+		assert_eq!((account.reason, account.extra), (ExistenceReason::DepositFrom(1, 10), 8));
+
+		// the accounts can be used like any other.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_noop!(Tags::untag(RuntimeOrigin::signed(2), 0), Error::<Test>::WouldBurn);
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 100));
+
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(asset_account_counts(0), (0, 0));
+		assert!(!Account::<Test>::contains_key(0, 1));
+		assert!(!Account::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn stored_map_should_use_existing_accounts() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		set_extra_payer(1);
+
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(2), 0, 7));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(asset_account_counts(0), (1, 0));
+		let account = Account::<Test>::get(0, 2).unwrap();
+		assert_eq!((account.reason, account.extra), (ExistenceReason::Consumer, 7));
+
+		// removing the data removes the account, so it must be empty.
+		assert_noop!(Tags::untag(RuntimeOrigin::signed(2), 0), Error::<Test>::WouldBurn);
+		// emptying the account removes it along with its data.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(2), 0));
+		assert_eq!(asset_account_counts(0), (0, 0));
+	});
+}
+
+#[test]
+fn stored_map_removal_should_keep_counters_and_notify_once() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(2), 0, 7));
+		set_extra_payer(1);
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(3), 0, 8));
+		assert_eq!(asset_account_counts(0), (2, 1));
+		assert_eq!(System::sufficients(&2), 1);
+
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(3), 0));
+		assert_eq!(asset_account_counts(0), (1, 1));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(take_hooks(), vec![Hook::Died(0, 3)]);
+
+		// the account dies when emptied, and removing its data afterwards does nothing.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_eq!(asset_account_counts(0), (0, 0));
+		assert_eq!(System::sufficients(&2), 0);
+		assert_eq!(take_hooks(), vec![Hook::Died(0, 2)]);
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(2), 0));
+		assert_eq!(asset_account_counts(0), (0, 0));
+		assert!(take_hooks().is_empty());
+	});
+}
+
+#[test]
+fn listing_assets_should_work() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 5));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			b"Zero".to_vec(),
+			b"ZRO".to_vec(),
+			12
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 1));
+
+		let (metadata, summary) = Assets::asset_info(0).unwrap();
 		assert_eq!(
-			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(
-				100,
-				not_sufficient
-			),
-			Err(ConversionError::AssetNotSufficient)
+			(metadata.name, metadata.symbol, metadata.decimals),
+			(b"Zero".to_vec(), b"ZRO".to_vec(), 12)
 		);
-		// 10 / 1 == 10 -> the conversion should 10x the value
 		assert_eq!(
-			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(100, id),
-			Ok(100 * 10)
+			summary,
+			AssetDetailsSummary {
+				status: AssetStatus::Live,
+				supply: 100,
+				min_balance: 5,
+				is_sufficient: false,
+				accounts: 1,
+			}
 		);
+		// assets without metadata are listed with empty metadata.
+		let (metadata, summary) = Assets::asset_info(1).unwrap();
+		assert!(metadata.name.is_empty());
+		assert_eq!(summary.status, AssetStatus::Destroying);
+		assert_eq!(Assets::asset_info(2), None);
+
+		// page through the assets, including the one from genesis.
+		let first_page = Assets::assets_info(None, 2);
+		assert_eq!(first_page.len(), 2);
+		let cursor = first_page.last().map(|(id, ..)| *id);
+		let second_page = Assets::assets_info(cursor, 2);
+		assert_eq!(second_page.len(), 1);
+		assert!(Assets::assets_info(second_page.last().map(|(id, ..)| *id), 2).is_empty());
+		let mut ids: Vec<_> =
+			first_page.into_iter().chain(second_page).map(|(id, ..)| id).collect();
+		ids.sort();
+		assert_eq!(ids, vec![0, 1, 999]);
+		assert_eq!(Assets::assets_info(None, 10).len(), 3);
+		assert!(Assets::assets_info(None, 0).is_empty());
 	});
 }
 
 #[test]
-fn assets_from_genesis_should_exist() {
-	new_test_ext().execute_with(|| {
-		assert_eq!(asset_ids(), vec![999]);
-		assert!(Metadata::<Test>::contains_key(999));
-		assert_eq!(Assets::balance(999, 1), 100);
-		assert_eq!(Assets::total_supply(999), 100);
+fn listing_account_balances_should_work() {
+	build_and_execute(|| {
+		for id in 0..4 {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 1));
+		}
+		for id in 0..3 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 2, 100));
+		}
+		set_frozen_balance(0, 2, 30);
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 2, 2));
+
+		let mut balances = Assets::account_balances_info(2, None, 10);
+		balances.sort_by_key(|(id, _)| *id);
+		assert_eq!(
+			balances,
+			vec![
+				(0, AccountBalanceSummary { balance: 100, is_frozen: false, reducible: 69 }),
+				(1, AccountBalanceSummary { balance: 100, is_frozen: false, reducible: 100 }),
+				(2, AccountBalanceSummary { balance: 100, is_frozen: true, reducible: 0 }),
+			]
+		);
+
+		// the list is bounded, and can be paged through.
+		let first_page = Assets::account_balances_info(2, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let cursor = first_page.last().map(|(id, _)| *id);
+		let second_page = Assets::account_balances_info(2, cursor, 2);
+		assert_eq!(second_page.len(), 1);
+		let mut ids: Vec<_> = first_page.into_iter().chain(second_page).map(|(id, _)| id).collect();
+		ids.sort();
+		assert_eq!(ids, vec![0, 1, 2]);
+		assert!(Assets::account_balances_info(3, None, 10).is_empty());
 	});
 }
 
 #[test]
-fn querying_name_symbol_and_decimals_should_work() {
-	new_test_ext().execute_with(|| {
-		use frame_support::traits::tokens::fungibles::metadata::Inspect;
+fn listing_holders_should_be_stable_across_pages() {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::force_set_metadata(
-			RuntimeOrigin::root(),
-			0,
-			vec![0u8; 10],
-			vec![1u8; 10],
-			12,
-			false
-		));
-		assert_eq!(Assets::name(0), vec![0u8; 10]);
-		assert_eq!(Assets::symbol(0), vec![1u8; 10]);
-		assert_eq!(Assets::decimals(0), 12);
+		for who in 10..20 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, who));
+		}
+		let all = Assets::holders(0, None, 100);
+		assert_eq!(all.len(), 10);
+		assert!(Assets::holders(0, None, 0).is_empty());
+		assert!(Assets::holders(1, None, 100).is_empty());
+
+		let first_page = Assets::holders(0, None, 4);
+		assert_eq!(first_page[..], all[..4]);
+		let cursor = first_page.last().map(|(who, _)| *who).unwrap();
+
+		// balances change between the pages: the cursor is reaped and a holder of the next page
+		// receives more.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, cursor, u64::MAX));
+		let next = all[4].0;
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, next, 100));
+
+		let second_page = Assets::holders(0, Some(cursor), 4);
+		assert_eq!(second_page[0], (next, next + 100));
+		assert_eq!(second_page[1..], all[5..8]);
+		let cursor = second_page.last().map(|(who, _)| *who);
+		let third_page = Assets::holders(0, cursor, 4);
+		assert_eq!(third_page[..], all[8..]);
+		assert!(Assets::holders(0, third_page.last().map(|(who, _)| *who), 4).is_empty());
+
+		// every holder was listed exactly once.
+		let mut listed: Vec<_> = first_page
+			.into_iter()
+			.chain(second_page)
+			.chain(third_page)
+			.map(|(who, _)| who)
+			.collect();
+		listed.sort();
+		assert_eq!(listed, (10..20).collect::<Vec<_>>());
 	});
 }
 
 #[test]
-fn querying_allowance_should_work() {
-	new_test_ext().execute_with(|| {
-		use frame_support::traits::tokens::fungibles::approvals::{Inspect, Mutate};
+fn querying_approvals_should_work() {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
-		Balances::make_free_balance_be(&1, 2);
-		assert_ok!(Assets::approve(0, &1, &2, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 100));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 40));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 1, 2, 30));
+
 		assert_eq!(Assets::allowance(0, &1, &2), 50);
-		// Transfer asset 0, from owner 1 and delegate 2 to destination 3
-		assert_ok!(Assets::transfer_from(0, &1, &2, &3, 50));
-		assert_eq!(Assets::allowance(0, &1, &2), 0);
+		assert_eq!(Assets::allowance(0, &2, &1), 0);
+		let mut approvals: Vec<_> = Assets::approvals_of(0, &1)
+			.into_iter()
+			.map(|(delegate, approval)| (delegate, approval.amount))
+			.collect();
+		approvals.sort();
+		assert_eq!(approvals, vec![(2, 50), (3, 40)]);
+		let mut allowances = Assets::allowances(1, None);
+		allowances.sort();
+		assert_eq!(allowances, vec![(0, 2, 50), (0, 3, 40), (1, 2, 30)]);
+		assert_eq!(Assets::allowances(1, Some(1)), vec![(1, 2, 30)]);
+
+		// spending part of an approval lowers it, spending all of it removes it.
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 4, 20));
+		assert_eq!(Assets::allowance(0, &1, &2), 30);
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(3), 0, 1, 4, 40));
+		assert_eq!(Assets::allowance(0, &1, &3), 0);
+		assert_eq!(Assets::allowances(1, Some(0)), vec![(0, 2, 30)]);
+
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(1), 0, 2));
+		assert!(Assets::approvals_of(0, &1).is_empty());
+		assert_eq!(Assets::allowances(1, None), vec![(1, 2, 30)]);
+		assert!(Assets::allowances(2, None).is_empty());
 	});
 }
 
 #[test]
-fn transfer_large_asset() {
-	new_test_ext().execute_with(|| {
-		let amount = u64::pow(2, 63) + 2;
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, amount));
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, amount - 1));
-	})
+fn transfer_consequences_should_be_reported() {
+	build_and_execute(|| {
+		use frame_support::traits::tokens::{DepositConsequence, WithdrawConsequence};
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 10));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 10));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 100));
+
+		// account 2 has no provider, so it can only hold sufficient assets.
+		assert_eq!(Assets::deposit_consequence(0, &2, 50), DepositConsequence::CannotCreate);
+		assert_eq!(Assets::deposit_consequence(1, &2, 50), DepositConsequence::Success);
+		assert_eq!(Assets::deposit_consequence(1, &2, 5), DepositConsequence::BelowMinimum);
+		assert_eq!(Assets::deposit_consequence(2, &2, 50), DepositConsequence::UnknownAsset);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50),
+			TokenError::CannotCreate
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 50));
+
+		assert_eq!(Assets::withdraw_consequence(1, &1, 40, true), WithdrawConsequence::Success);
+		assert_eq!(Assets::withdraw_consequence(1, &1, 45, true), WithdrawConsequence::WouldDie);
+		assert_eq!(
+			Assets::withdraw_consequence(1, &1, 45, false),
+			WithdrawConsequence::ReducedToZero(5)
+		);
+		assert_eq!(Assets::withdraw_consequence(1, &1, 60, false), WithdrawConsequence::BalanceLow);
+
+		// frozen accounts and assets can neither send nor, when the asset is frozen, receive.
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 1, 1));
+		assert_eq!(Assets::withdraw_consequence(1, &1, 10, false), WithdrawConsequence::Frozen);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 10), Error::<Test>::Frozen);
+		assert_eq!(Assets::deposit_consequence(1, &1, 10), DepositConsequence::Success);
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Assets::withdraw_consequence(1, &2, 10, false), WithdrawConsequence::Frozen);
+		assert_eq!(Assets::deposit_consequence(1, &2, 10), DepositConsequence::Blocked);
+	});
 }
 
 #[test]
-fn querying_roles_should_work() {
-	new_test_ext().execute_with(|| {
-		use frame_support::traits::tokens::fungibles::roles::Inspect;
+fn asset_count_should_work() {
+	build_and_execute(|| {
+		// the genesis asset is counted.
+		assert_eq!(Assets::asset_count(), 1);
+		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::set_team(
-			RuntimeOrigin::signed(1),
-			0,
-			// Issuer
-			2,
-			// Admin
-			3,
-			// Freezer
-			4,
-		));
-		assert_eq!(Assets::owner(0), Some(1));
-		assert_eq!(Assets::issuer(0), Some(2));
-		assert_eq!(Assets::admin(0), Some(3));
-		assert_eq!(Assets::freezer(0), Some(4));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 1, 1, 1));
+		assert_noop!(Assets::create(RuntimeOrigin::signed(1), 1, 1, 1), Error::<Test>::InUse);
+		assert_eq!(Assets::asset_count(), 3);
+
+		// assets being destroyed are still counted.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Assets::asset_count(), 3);
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 1, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 1, u32::MAX));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Assets::asset_count(), 2);
+		assert_eq!(Assets::asset_count() as usize, Asset::<Test>::iter_keys().count());
+
+		// the same id can be created again.
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 1, 1, 1));
+		assert_eq!(Assets::asset_count(), 3);
 	});
 }
 
-#[test]
-fn normal_asset_create_and_destroy_callbacks_should_work() {
-	new_test_ext().execute_with(|| {
-		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_none());
-		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+/// Asserts that the metadata deposit recorded in the details of asset `id` is the one in its
+/// metadata, or zero without metadata, and returns it.
+fn recorded_metadata_deposit(id: u32) -> u64 {
+	let deposit = Asset::<Test>::get(id).unwrap().metadata_deposit;
+	assert_eq!(deposit, Metadata::<Test>::get(id).map_or(0, |m| m.deposit));
+	deposit
+}
 
+#[test]
+fn metadata_deposit_should_be_recorded_in_details() {
+	build_and_execute(|| {
+		use frame_support::traits::fungibles::metadata::Mutate;
 		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
 		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
-		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_some());
-		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+		assert_eq!(recorded_metadata_deposit(0), 0);
 
-		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
-		// Callback still hasn't been invoked
-		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 10], vec![0; 10], 12));
+		assert_eq!(recorded_metadata_deposit(0), 21);
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 5], vec![0; 5], 12));
+		assert_eq!(recorded_metadata_deposit(0), 11);
+		assert_ok!(<Assets as Mutate<u64>>::set(0, &1, vec![0; 6], vec![0; 6], 12));
+		assert_eq!(recorded_metadata_deposit(0), 13);
+
+		// forcing metadata leaves the deposit alone.
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![0; 1],
+			vec![0; 1],
+			12,
+			false
+		));
+		assert_eq!(recorded_metadata_deposit(0), 13);
 
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(recorded_metadata_deposit(0), 13);
+		assert_eq!(Balances::reserved_balance(&2), 14);
+
+		// a slashed deposit shrinks as it moves.
+		let _ = Balances::slash_reserved(&2, 10);
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(2), 0, 1));
+		assert_eq!(recorded_metadata_deposit(0), 3);
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 1);
+
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), 0));
+		assert_eq!(recorded_metadata_deposit(0), 0);
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 2], vec![0; 2], 12));
+		assert_eq!(recorded_metadata_deposit(0), 5);
+		assert_ok!(Assets::force_clear_metadata(RuntimeOrigin::root(), 0));
+		assert_eq!(recorded_metadata_deposit(0), 0);
+
+		// destroying the asset returns whatever is recorded.
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 2], vec![0; 2], 12));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0, u32::MAX));
 		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
-		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_some());
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Destroyed {
+			asset_id: 0,
+			owner: 1,
+			deposit_returned: 6,
+		}));
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
 	});
 }
 
 #[test]
-fn root_asset_create_should_work() {
-	new_test_ext().execute_with(|| {
-		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_none());
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert!(storage::get(AssetsCallbackHandle::CREATED.as_bytes()).is_some());
-		assert!(storage::get(AssetsCallbackHandle::DESTROYED.as_bytes()).is_none());
+fn refresh_asset_deposit_should_follow_the_deposit_constants() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0; 10], vec![0; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 1 + 21);
+
+		// Anyone may reserve the shortfall once the constants rise.
+		AssetDeposit::set(5);
+		MetadataDepositPerByte::set(2);
+		assert_ok!(Assets::refresh_asset_deposit(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&1), 5 + 41);
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 5);
+		assert_eq!(recorded_metadata_deposit(0), 41);
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::DepositUpdated {
+			asset_id: 0,
+			who: 1,
+			kind: DepositKind::Asset,
+			old: 1,
+			new: 5,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DepositUpdated {
+			asset_id: 0,
+			who: 1,
+			kind: DepositKind::Metadata,
+			old: 21,
+			new: 41,
+		}));
+
+		// Refreshing up-to-date deposits does nothing.
+		System::reset_events();
+		assert_ok!(Assets::refresh_asset_deposit(RuntimeOrigin::signed(2), 0));
+		assert!(System::events().is_empty());
+
+		// The excess is released once they fall.
+		AssetDeposit::set(2);
+		MetadataDepositPerByte::set(1);
+		assert_ok!(Assets::refresh_asset_deposit(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 2 + 21);
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 2);
+		assert_eq!(recorded_metadata_deposit(0), 21);
+
+		// The owner must afford the shortfall.
+		AssetDeposit::set(1000);
+		assert_noop!(
+			Assets::refresh_asset_deposit(RuntimeOrigin::signed(2), 0),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+		assert_noop!(
+			Assets::refresh_asset_deposit(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::Unknown
+		);
+		assert_noop!(
+			Assets::refresh_asset_deposit(RuntimeOrigin::none(), 0),
+			DispatchError::BadOrigin
+		);
 	});
 }
 
 #[test]
-fn asset_create_and_destroy_is_reverted_if_callback_fails() {
-	new_test_ext().execute_with(|| {
-		// Asset creation fails due to callback failure
-		AssetsCallbackHandle::set_return_error();
+fn poke_account_deposit_should_follow_the_deposit_constant() {
+	build_and_execute(|| {
 		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Assets::touch_other(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_eq!(Balances::reserved_balance(&2), 10);
+
+		// Anyone may release the excess once the constant falls, to whoever paid the deposit.
+		AssetAccountDeposit::set(4);
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 2));
+		assert_eq!(Balances::reserved_balance(&2), 4);
+		assert_eq!(Assets::existence_reason(0, 2), Some(ExistenceReason::DepositHeld(4)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DepositUpdated {
+			asset_id: 0,
+			who: 2,
+			kind: DepositKind::Account,
+			old: 10,
+			new: 4,
+		}));
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+		assert_eq!(Assets::existence_reason(0, 3), Some(ExistenceReason::DepositFrom(1, 4)));
+
+		// Poking an up-to-date deposit does nothing.
+		System::reset_events();
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 2));
+		assert!(System::events().is_empty());
+
+		// The shortfall is reserved once it rises, and refunded in full later on.
+		AssetAccountDeposit::set(20);
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 2));
+		assert_eq!(Balances::reserved_balance(&2), 20);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DepositUpdated {
+			asset_id: 0,
+			who: 2,
+			kind: DepositKind::Account,
+			old: 4,
+			new: 20,
+		}));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, false));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+
+		// The depositor must afford the shortfall.
+		AssetAccountDeposit::set(1000);
 		assert_noop!(
-			Assets::create(RuntimeOrigin::signed(1), 0, 1, 1),
-			Error::<Test>::CallbackFailed
+			Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 3),
+			BalancesError::<Test, _>::InsufficientBalance,
 		);
 
-		// Callback succeeds, so asset creation succeeds
-		AssetsCallbackHandle::set_return_ok();
-		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
-
-		// Asset destroy should fail due to callback failure
-		AssetsCallbackHandle::set_return_error();
-		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
-		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		// Only accounts existing for a deposit have one to poke.
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 4, 10));
 		assert_noop!(
-			Assets::finish_destroy(RuntimeOrigin::signed(1), 0),
-			Error::<Test>::CallbackFailed
+			Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 4),
+			Error::<Test>::NoDeposit
+		);
+		assert_noop!(
+			Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 6),
+			Error::<Test>::NoAccount
 		);
 	});
 }
 
 #[test]
-fn multiple_transfer_alls_work_ok() {
-	new_test_ext().execute_with(|| {
-		// Only run PoC when the system pallet is enabled, since the underlying bug is in the
-		// system pallet it won't work with BalancesAccountStore
-		// Start with a balance of 100
-		Balances::force_set_balance(RuntimeOrigin::root(), 1, 100).unwrap();
-		// Emulate a sufficient, in reality this could be reached by transferring a sufficient
-		// asset to the account
-		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
-		// Spend the same balance multiple times
-		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(1), 1337, false));
-		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(1), 1337, false));
-
-		assert_eq!(Balances::free_balance(&1), 0);
-		assert_eq!(Balances::free_balance(&1337), 100);
+fn footprint_deposits_should_price_what_is_stored() {
+	build_and_execute(|| {
+		// balance, status, reason with the deposit and maybe its depositor, and `Extra`.
+		assert_eq!(Assets::new_account_footprint(&1, &1), StorageFootprint { items: 1, bytes: 22 });
+		assert_eq!(Assets::new_account_footprint(&2, &1), StorageFootprint { items: 1, bytes: 30 });
+		// amount and deposit.
+		assert_eq!(Assets::approval_footprint(), StorageFootprint { items: 1, bytes: 16 });
+
+		let footprint = StorageFootprint { items: 2, bytes: 5 };
+		assert_eq!(TestFootprintDeposits::account_deposit(&0, footprint), 9);
+		assert_eq!(TestFootprintDeposits::approval_deposit(&0, footprint), 9);
+		// the flat deposits ignore the footprint.
+		assert_eq!(ConfiguredDeposits::<Test>::account_deposit(&0, footprint), 10);
+		assert_eq!(ConfiguredDeposits::<Test>::approval_deposit(&0, footprint), 1);
+		// asset and metadata deposits are the configured ones either way.
+		assert_eq!(TestFootprintDeposits::asset_deposit(&0), 1);
+		assert_eq!(TestFootprintDeposits::metadata_deposit(&0, 10), 11);
 	});
 }
 
 #[test]
-fn weights_sane() {
-	let info = crate::Call::<Test>::create { id: 10, admin: 4, min_balance: 3 }.get_dispatch_info();
-	assert_eq!(<() as crate::WeightInfo>::create(), info.weight);
+fn footprint_deposits_should_be_reserved_and_returned_like_flat_ones() {
+	build_and_execute(|| {
+		for who in 1..=4 {
+			Balances::make_free_balance_be(&who, 100);
+		}
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 
-	let info = crate::Call::<Test>::finish_destroy { id: 10 }.get_dispatch_info();
-	assert_eq!(<() as crate::WeightInfo>::finish_destroy(), info.weight);
+		// under the flat deposits.
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+
+		// under the footprint deposits, accounts paid for by someone else cost more.
+		charge_footprint(true);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Assets::existence_reason(0, 4), Some(ExistenceReason::DepositHeld(24)));
+		assert_ok!(Assets::touch_other(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Assets::existence_reason(0, 3), Some(ExistenceReason::DepositFrom(1, 32)));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 5, 50));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 5,
+			amount: 50,
+			deposit: 18,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 32 + 18);
+
+		// accounts created before are repriced when poked.
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 2));
+		assert_eq!(Balances::reserved_balance(&2), 24);
+
+		// whatever the policy now, exactly what was recorded is returned.
+		charge_footprint(false);
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(4), 0, false));
+		assert_eq!(Balances::reserved_balance(&4), 0);
+		assert_eq!(Balances::free_balance(&4), 100);
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(1), 0, 5));
+		assert_eq!(Balances::reserved_balance(&1), 32);
+
+		// and poking goes back to the flat deposits.
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_ok!(Assets::poke_account_deposit(RuntimeOrigin::signed(5), 0, 2));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		assert_ok!(Assets::refund_other(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 100);
+	});
 }
 
 #[test]
-fn asset_destroy_refund_existence_deposit() {
-	new_test_ext().execute_with(|| {
+fn stored_map_should_reprice_the_accounts_it_writes() {
+	build_and_execute(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
-		let admin = 1;
-		let admin_origin = RuntimeOrigin::signed(admin);
+		set_extra_payer(1);
 
-		let account2 = 2; // account with own deposit
-		let account3 = 3; // account with admin's deposit
-		Balances::make_free_balance_be(&account2, 100);
+		charge_footprint(true);
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(1), 0, 7));
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(2), 0, 8));
+		assert_eq!(Balances::reserved_balance(&1), 24 + 32);
 
-		assert_eq!(Balances::reserved_balance(&account2), 0);
-		assert_eq!(Balances::reserved_balance(&account3), 0);
-		assert_eq!(Balances::reserved_balance(&admin), 0);
+		// writing the data again prices the account under the current policy.
+		charge_footprint(false);
+		assert_ok!(Tags::tag(RuntimeOrigin::signed(2), 0, 9));
+		assert_eq!(Assets::existence_reason(0, 2), Some(ExistenceReason::DepositFrom(1, 10)));
+		assert_eq!(Balances::reserved_balance(&1), 24 + 10);
 
-		assert_ok!(Assets::touch(RuntimeOrigin::signed(account2), 0));
-		assert_ok!(Assets::touch_other(admin_origin.clone(), 0, account3));
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Tags::untag(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
 
-		assert_eq!(Balances::reserved_balance(&account2), 10);
-		assert_eq!(Balances::reserved_balance(&account3), 0);
-		assert_eq!(Balances::reserved_balance(&admin), 10);
+#[test]
+fn refresh_asset_deposit_should_leave_deposit_free_assets_alone() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			vec![0; 10],
+			vec![0; 10],
+			12,
+			false
+		));
+		AssetDeposit::set(5);
+		MetadataDepositPerByte::set(2);
 
-		assert_ok!(Assets::start_destroy(admin_origin.clone(), 0));
-		assert_ok!(Assets::destroy_accounts(admin_origin.clone(), 0));
-		assert_ok!(Assets::destroy_approvals(admin_origin.clone(), 0));
-		assert_ok!(Assets::finish_destroy(admin_origin.clone(), 0));
+		assert_ok!(Assets::refresh_asset_deposit(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 0);
+		assert_eq!(recorded_metadata_deposit(0), 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
 
-		assert_eq!(Balances::reserved_balance(&account2), 0);
-		assert_eq!(Balances::reserved_balance(&account3), 0);
-		assert_eq!(Balances::reserved_balance(&admin), 0);
+#[test]
+fn try_state_should_detect_corrupted_storage() {
+	let corruptions: [(fn(), &'static str); 13] = [
+		(|| AssetCount::<Test>::put(5), "`AssetCount` does not match the number of assets"),
+		(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().accounts += 1),
+			"asset `accounts` does not match its accounts",
+		),
+		(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().sufficients += 1),
+			"asset `sufficients` does not match its sufficient accounts",
+		),
+		(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().supply += 1),
+			"asset balances do not add up to its supply",
+		),
+		(
+			|| Account::<Test>::mutate(0, 1, |a| a.as_mut().unwrap().balance += 1),
+			"asset balances exceed its supply",
+		),
+		(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().approvals += 1),
+			"asset `approvals` does not match its approvals",
+		),
+		(
+			|| ApprovalCount::<Test>::insert(0, 3, 1),
+			"`ApprovalCount` does not match the approvals of each owner",
+		),
+		(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().metadata_deposit += 1),
+			"asset `metadata_deposit` does not match its metadata",
+		),
+		(
+			|| Account::<Test>::insert(7, 1, Account::<Test>::get(0, 1).unwrap()),
+			"there are accounts of unknown assets",
+		),
+		(
+			|| DelegateApprovals::<Test>::remove(2, (0, 1)),
+			"an approval is missing from `DelegateApprovals`",
+		),
+		(
+			|| DelegateApprovals::<Test>::insert(3, (0, 1), ()),
+			"`DelegateApprovals` has entries without an approval",
+		),
+		(
+			|| DelegateApprovalCount::<Test>::insert(2, 2),
+			"`DelegateApprovalCount` does not match the approvals of each delegate",
+		),
+		(
+			|| HoldingCount::<Test>::insert(1, 2),
+			"`HoldingCount` does not match the accounts of each holder",
+		),
+	];
+
+	for (corrupt, error) in corruptions {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+			Balances::make_free_balance_be(&1, 100);
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+			assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0], vec![0], 12));
+			assert_ok!(Assets::do_try_state());
+
+			corrupt();
+			assert_eq!(Assets::do_try_state(), Err(error.into()));
+		});
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn benchmark_freezer_should_be_respected() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		BenchmarkFreezer::<Test, (), 2>::set_frozen(&0, &1, 1, 50);
+		assert_eq!(Assets::frozen_balance(0, &1), Some(50));
+
+		// the frozen balance and the minimum balance stay put.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 49));
+
+		// its items are removed once the account dies.
+		<BenchmarkFreezer<Test, (), 2> as FrozenBalance<u32, u64, u64>>::died(0, &1);
+		assert_eq!(Assets::frozen_balance(0, &1), None);
 	});
 }
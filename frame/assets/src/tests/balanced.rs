@@ -0,0 +1,208 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `fungibles::Balanced` implementation and its imbalances.
+
+use super::*;
+use frame_support::traits::{
+	tokens::{
+		fungibles::{Balanced, Credit, Debt},
+		Fortitude::Polite,
+		Precision::{BestEffort, Exact},
+		Preservation::{Expendable, Preserve},
+	},
+	SameOrOther,
+};
+
+/// The supply must always equal the sum of all balances once every imbalance is dropped.
+fn assert_supply_matches_balances(id: u32) {
+	let total: u64 = Account::<Test>::iter_prefix(id).map(|(_, a)| a.balance).sum();
+	assert_eq!(Assets::total_supply(id), total);
+}
+
+#[test]
+fn dropped_imbalances_should_adjust_supply() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+
+		// a deposit only counts towards the supply once its debt is dropped.
+		let debt = Assets::deposit(0, &1, 100, Exact).unwrap();
+		assert_eq!(debt.peek(), 100);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::total_supply(0), 0);
+		drop(debt);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// likewise a withdrawal only leaves the supply once its credit is dropped.
+		let credit = Assets::withdraw(0, &1, 30, Exact, Preserve, Polite).unwrap();
+		assert_eq!(credit.peek(), 30);
+		assert_eq!(Assets::total_supply(0), 100);
+		drop(credit);
+		assert_eq!(Assets::total_supply(0), 70);
+		assert_supply_matches_balances(0);
+
+		// issuing and rescinding change the supply straight away, and dropping undoes it.
+		drop(Assets::issue(0, 50));
+		assert_eq!(Assets::total_supply(0), 70);
+		drop(Assets::rescind(0, 20));
+		assert_eq!(Assets::total_supply(0), 70);
+		assert_supply_matches_balances(0);
+	});
+}
+
+#[test]
+fn merged_and_offset_imbalances_should_keep_supply_consistent() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+
+		let Ok(credit) = Assets::issue(0, 50).merge(Assets::issue(0, 20)) else {
+			panic!("imbalances of the same asset merge")
+		};
+		assert_eq!(credit.peek(), 70);
+		assert_eq!(Assets::total_supply(0), 70);
+
+		// a debt from a deposit cancels out part of the credit, the rest is burnt on drop.
+		let debt = Assets::deposit(0, &1, 30, Exact).unwrap();
+		let Ok(SameOrOther::Same(rest)) = credit.offset(debt) else {
+			panic!("credit is larger than the debt")
+		};
+		assert_eq!(rest.peek(), 40);
+		drop(rest);
+		assert_eq!(Assets::total_supply(0), 30);
+		assert_supply_matches_balances(0);
+
+		// a credit smaller than the debt leaves a debt behind.
+		let debt = Assets::rescind(0, 25);
+		assert_eq!(Assets::total_supply(0), 5);
+		let credit = Assets::withdraw(0, &1, 15, Exact, Preserve, Polite).unwrap();
+		let Ok(SameOrOther::Other(rest)) = credit.offset(debt) else {
+			panic!("debt is larger than the credit")
+		};
+		assert_eq!(rest.peek(), 10);
+		drop(rest);
+		assert_eq!(Assets::total_supply(0), 15);
+		assert_supply_matches_balances(0);
+
+		// imbalances of different assets can't be combined.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 10));
+		let other = Assets::issue(1, 10);
+		let credit = Assets::issue(0, 10);
+		let Err((credit, other)) = credit.merge(other) else {
+			panic!("imbalances of different assets don't merge")
+		};
+		assert_eq!((credit.asset(), other.asset()), (0, 1));
+		drop((credit, other));
+		assert_supply_matches_balances(0);
+		assert_supply_matches_balances(1);
+	});
+}
+
+#[test]
+fn withdrawing_below_minimum_balance_should_sweep_dust() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 15));
+
+		assert_noop!(
+			Assets::withdraw(0, &1, 10, Exact, Preserve, Polite).map(|c| c.peek()),
+			Error::<Test>::BalanceLow
+		);
+		// the leftover below the minimum balance is swept into the credit.
+		let credit = Assets::withdraw(0, &1, 10, Exact, Expendable, Polite).unwrap();
+		assert_eq!(credit.peek(), 15);
+		assert!(!Account::<Test>::contains_key(0, 1));
+		drop(credit);
+		assert_eq!(Assets::total_supply(0), 0);
+
+		// settling a debt hands back the dust.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 15));
+		let debt = Assets::rescind(0, 10);
+		let Ok(dust) = Assets::settle(&1, debt, Expendable) else {
+			panic!("the account can cover the debt")
+		};
+		assert_eq!(dust.peek(), 5);
+		assert_eq!(Assets::total_supply(0), 5);
+		drop(dust);
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_supply_matches_balances(0);
+	});
+}
+
+#[test]
+fn failed_resolve_and_settle_should_return_the_imbalance() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 20));
+
+		// a credit below the minimum balance can't create an account.
+		let credit: Credit<u64, Assets> = Assets::issue(0, 5);
+		let credit = Assets::resolve(&2, credit).unwrap_err();
+		assert_eq!(credit.peek(), 5);
+		// but it can top up an existing one.
+		assert_ok!(Assets::resolve(&1, credit));
+		assert_eq!(Assets::balance(0, 1), 25);
+
+		// a debt larger than what can be spent is handed back untouched.
+		let debt: Debt<u64, Assets> = Assets::rescind(0, 20);
+		let Err(debt) = Assets::settle(&1, debt, Preserve) else {
+			panic!("the account can't cover the debt")
+		};
+		assert_eq!(debt.peek(), 20);
+		assert_eq!(Assets::balance(0, 1), 25);
+		drop(debt);
+		assert_supply_matches_balances(0);
+	});
+}
+
+#[test]
+fn best_effort_deposit_should_stop_at_overflow() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, u64::MAX - 50));
+
+		assert_noop!(
+			Assets::deposit(0, &2, 100, Exact).map(|d| d.peek()),
+			ArithmeticError::Overflow
+		);
+		let debt = Assets::deposit(0, &2, 100, BestEffort).unwrap();
+		assert_eq!(debt.peek(), 50);
+		drop(debt);
+		assert_eq!(Assets::total_supply(0), u64::MAX);
+		assert_supply_matches_balances(0);
+
+		// nothing can be deposited at all once the supply is exhausted.
+		assert_eq!(Assets::deposit(0, &1, 10, BestEffort).map(|d| d.peek()), Ok(0));
+	});
+}
+
+#[test]
+fn withdrawing_from_an_asset_which_is_not_live_should_fail() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+
+		assert_noop!(
+			Assets::withdraw(0, &1, 10, Exact, Preserve, Polite).map(|c| c.peek()),
+			Error::<Test>::AssetNotLive
+		);
+		assert_noop!(
+			Assets::deposit(0, &1, 10, Exact).map(|d| d.peek()),
+			Error::<Test>::AssetNotLive
+		);
+	});
+}
@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `CurrencyOf` adapter, through a pallet which only knows about `Currency`.
+
+use super::*;
+use frame_support::traits::{
+	BalanceStatus, ExistenceRequirement::AllowDeath, Imbalance, SignedImbalance, WithdrawReasons,
+};
+
+type AssetCurrency = <Test as pallet_deposits::Config>::Currency;
+
+fn new_currency_asset() {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1984, 1, true, 10));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1984, 1, 100));
+}
+
+#[test]
+fn currency_should_reflect_the_asset() {
+	build_and_execute(|| {
+		new_currency_asset();
+		assert_eq!(AssetCurrency::minimum_balance(), 10);
+		assert_eq!(AssetCurrency::total_issuance(), 100);
+		assert_eq!(AssetCurrency::free_balance(&1), 100);
+
+		assert_ok!(Deposits::pay(RuntimeOrigin::signed(1), 2, 30));
+		assert_eq!(Assets::balance(1984, 1), 70);
+		assert_eq!(Assets::balance(1984, 2), 30);
+		// the payer is kept alive.
+		assert_noop!(Deposits::pay(RuntimeOrigin::signed(1), 2, 65), TokenError::NotExpendable);
+		// and the recipient must reach the minimum balance.
+		assert_noop!(Deposits::pay(RuntimeOrigin::signed(1), 3, 5), TokenError::BelowMinimum);
+
+		assert!(AssetCurrency::can_slash(&2, 30));
+		assert!(!AssetCurrency::can_slash(&2, 31));
+		assert_ok!(AssetCurrency::ensure_can_withdraw(&2, 30, WithdrawReasons::all(), 0));
+		assert!(AssetCurrency::ensure_can_withdraw(&2, 31, WithdrawReasons::all(), 0).is_err());
+		assert_ok!(AssetCurrency::transfer(&2, &1, 30, AllowDeath));
+		assert!(!Account::<Test>::contains_key(1984, 2));
+	});
+}
+
+#[test]
+fn reserves_should_be_holds() {
+	build_and_execute(|| {
+		new_currency_asset();
+
+		assert_ok!(Deposits::place_deposit(RuntimeOrigin::signed(1), 40));
+		assert_eq!(AssetCurrency::reserved_balance(&1), 40);
+		assert_eq!(Assets::balance_on_hold(1984, &TestHoldReason::Escrow, &1), 40);
+		assert_eq!(AssetCurrency::free_balance(&1), 60);
+		assert_eq!(AssetCurrency::total_balance(&1), 100);
+		assert!(AssetCurrency::can_reserve(&1, 50));
+		assert!(!AssetCurrency::can_reserve(&1, 51));
+
+		assert_ok!(Deposits::release_deposit(RuntimeOrigin::signed(1), 15));
+		assert_eq!(AssetCurrency::reserved_balance(&1), 25);
+		// whatever could not be unreserved is reported back.
+		assert_eq!(AssetCurrency::unreserve(&1, 30), 5);
+		assert_eq!(AssetCurrency::reserved_balance(&1), 0);
+		assert_eq!(AssetCurrency::free_balance(&1), 100);
+		assert_eq!(AssetCurrency::total_issuance(), 100);
+	});
+}
+
+#[test]
+fn imbalances_should_adjust_the_supply_once() {
+	build_and_execute(|| {
+		new_currency_asset();
+
+		// deposits below the minimum balance can't create accounts.
+		assert_eq!(AssetCurrency::deposit_creating(&3, 5).peek(), 0);
+		assert!(AssetCurrency::deposit_into_existing(&3, 20).is_err());
+
+		let debt = AssetCurrency::deposit_creating(&3, 20);
+		assert_eq!(debt.peek(), 20);
+		assert_eq!(AssetCurrency::total_issuance(), 100);
+		drop(debt);
+		assert_eq!(AssetCurrency::total_issuance(), 120);
+
+		let Ok(credit) = AssetCurrency::withdraw(&3, 20, WithdrawReasons::all(), AllowDeath) else {
+			panic!("account 3 has enough funds")
+		};
+		assert_eq!(credit.peek(), 20);
+		drop(credit);
+		assert_eq!(AssetCurrency::total_issuance(), 100);
+
+		drop(AssetCurrency::burn(10));
+		drop(AssetCurrency::issue(10));
+		assert_eq!(AssetCurrency::total_issuance(), 100);
+
+		let SignedImbalance::Positive(debt) = AssetCurrency::make_free_balance_be(&1, 150) else {
+			panic!("the balance went up")
+		};
+		assert_eq!(debt.peek(), 50);
+		drop(debt);
+		let SignedImbalance::Negative(credit) = AssetCurrency::make_free_balance_be(&1, 40) else {
+			panic!("the balance went down")
+		};
+		assert_eq!(credit.peek(), 110);
+		drop(credit);
+		assert_eq!(AssetCurrency::free_balance(&1), 40);
+		assert_eq!(AssetCurrency::total_issuance(), 40);
+	});
+}
+
+#[test]
+fn slashing_should_take_free_then_reserved_funds() {
+	build_and_execute(|| {
+		new_currency_asset();
+		assert_ok!(Deposits::place_deposit(RuntimeOrigin::signed(1), 40));
+
+		// the account is kept alive while it has reserved funds.
+		let (credit, remaining) = AssetCurrency::slash(&1, 80);
+		assert_eq!((credit.peek(), remaining), (80, 0));
+		assert_eq!(AssetCurrency::free_balance(&1), 10);
+		assert_eq!(AssetCurrency::reserved_balance(&1), 10);
+		drop(credit);
+		assert_eq!(AssetCurrency::total_issuance(), 20);
+
+		let (credit, remaining) = AssetCurrency::slash_reserved(&1, 20);
+		assert_eq!((credit.peek(), remaining), (10, 10));
+		drop(credit);
+		assert_eq!(AssetCurrency::total_issuance(), 10);
+	});
+}
+
+#[test]
+fn repatriating_reserves_should_work() {
+	build_and_execute(|| {
+		new_currency_asset();
+		assert_ok!(Deposits::pay(RuntimeOrigin::signed(1), 2, 20));
+		assert_ok!(Deposits::place_deposit(RuntimeOrigin::signed(1), 40));
+
+		assert_eq!(AssetCurrency::repatriate_reserved(&1, &2, 15, BalanceStatus::Free), Ok(0));
+		assert_eq!(AssetCurrency::free_balance(&2), 35);
+		assert_eq!(AssetCurrency::repatriate_reserved(&1, &2, 10, BalanceStatus::Reserved), Ok(0));
+		assert_eq!(AssetCurrency::reserved_balance(&2), 10);
+		// only what is reserved can be moved, the rest is reported back.
+		assert_eq!(AssetCurrency::repatriate_reserved(&1, &2, 20, BalanceStatus::Free), Ok(5));
+		assert_eq!(AssetCurrency::reserved_balance(&1), 0);
+		assert_eq!(AssetCurrency::free_balance(&2), 50);
+		assert_eq!(AssetCurrency::total_issuance(), 100);
+	});
+}
@@ -0,0 +1,190 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the flagged transfer, mint and burn functions offered to other pallets.
+
+use super::*;
+use sp_runtime::traits::Bounded;
+
+/// A pallet built on top of this one, using it only through its public functions, generically
+/// over the runtime.
+struct Escrow<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Escrow<T> {
+	/// Moves everything `who` can spare of the asset `id` into `escrow`, keeping `who` alive.
+	fn lock(
+		id: T::AssetId,
+		who: &T::AccountId,
+		escrow: &T::AccountId,
+	) -> Result<T::Balance, DispatchError> {
+		let flags = TransferFlags {
+			keep_alive: true,
+			best_effort: true,
+			burn_dust: false,
+			bypass_filter: false,
+		};
+		Pallet::<T>::transfer_with_flags(id, who, escrow, T::Balance::max_value(), None, flags)
+	}
+
+	/// Hands exactly `amount` of the asset `id` from `escrow` to `who`, leaving any dust of
+	/// `escrow` to `T::OnDust` and whatever the transfer filter says.
+	fn release(
+		id: T::AssetId,
+		escrow: &T::AccountId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let flags = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: true,
+			bypass_filter: true,
+		};
+		Pallet::<T>::transfer_with_flags(id, escrow, who, amount, None, flags)
+	}
+
+	/// Burns all the asset `id` held by `escrow`, as its admin `admin`.
+	fn slash(
+		id: T::AssetId,
+		escrow: &T::AccountId,
+		admin: T::AccountId,
+	) -> Result<T::Balance, DispatchError> {
+		let flags = DebitFlags { keep_alive: false, best_effort: true };
+		Pallet::<T>::burn_with_flags(id, escrow, T::Balance::max_value(), Some(admin), flags)
+	}
+}
+
+/// The events of this pallet since the last call.
+fn take_asset_events() -> Vec<crate::Event<Test>> {
+	let events = System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Assets(event) => Some(event),
+			_ => None,
+		})
+		.collect();
+	System::reset_events();
+	events
+}
+
+#[test]
+fn transfer_with_flags_should_return_the_amount_moved() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint_to(0, &2, 100, Some(1)));
+		assert_eq!(
+			take_asset_events(),
+			vec![crate::Event::Issued { asset_id: 0, owner: 2, amount: 100 }]
+		);
+
+		// `2` is kept alive with the minimum balance.
+		assert_eq!(Escrow::<Test>::lock(0, &2, &3), Ok(90));
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(
+			take_asset_events(),
+			vec![crate::Event::Transferred { asset_id: 0, from: 2, to: 3, amount: 90 }]
+		);
+
+		// the dust of the escrow is burned rather than credited, and a non-compliant recipient
+		// can be paid since the filter is bypassed.
+		set_non_compliant(2);
+		assert_eq!(Escrow::<Test>::release(0, &3, &2, 85), Ok(85));
+		assert_eq!(Assets::balance(0, 2), 95);
+		assert!(!Account::<Test>::contains_key(0, 3));
+		assert_eq!(Assets::total_supply(0), 95);
+		assert_eq!(
+			take_asset_events(),
+			vec![
+				crate::Event::Transferred { asset_id: 0, from: 3, to: 2, amount: 85 },
+				crate::Event::DustBurned { asset_id: 0, who: 3, amount: 5 },
+			]
+		);
+
+		// without bypassing the filter, the transfer is vetoed.
+		assert_noop!(Escrow::<Test>::lock(0, &2, &3), NOT_COMPLIANT);
+		clear_non_compliant();
+
+		// transferring nothing does nothing.
+		let flags = TransferFlags {
+			keep_alive: true,
+			best_effort: false,
+			burn_dust: false,
+			bypass_filter: false,
+		};
+		assert_eq!(Assets::transfer_with_flags(0, &2, &3, 0, None, flags), Ok(0));
+		assert!(take_asset_events().is_empty());
+	});
+}
+
+#[test]
+fn failing_calls_should_leave_no_trace() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint_to(0, &2, 100, None));
+		take_asset_events();
+
+		// the admin is checked once the accounts have been worked out.
+		let flags = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			bypass_filter: false,
+		};
+		assert_noop!(
+			Assets::transfer_with_flags(0, &2, &3, 50, Some(2), flags),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::transfer_with_flags(
+				0,
+				&2,
+				&3,
+				95,
+				None,
+				TransferFlags { keep_alive: true, ..flags }
+			),
+			Error::<Test>::BalanceLow
+		);
+		assert_noop!(Assets::mint_to(0, &3, 50, Some(2)), Error::<Test>::NoPermission);
+		assert_noop!(Escrow::<Test>::slash(0, &2, 2), Error::<Test>::NoPermission);
+		assert!(take_asset_events().is_empty());
+	});
+}
+
+#[test]
+fn burn_with_flags_should_return_the_amount_burned() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint_to(0, &2, 100, None));
+		take_asset_events();
+
+		let flags = DebitFlags { keep_alive: true, best_effort: true };
+		assert_eq!(Assets::burn_with_flags(0, &2, 200, None, flags), Ok(90));
+		assert_eq!(
+			take_asset_events(),
+			vec![crate::Event::Burned { asset_id: 0, owner: 2, balance: 90 }]
+		);
+
+		// burning the whole balance reaps the account.
+		assert_eq!(Escrow::<Test>::slash(0, &2, 1), Ok(10));
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_eq!(
+			take_asset_events(),
+			vec![crate::Event::Burned { asset_id: 0, owner: 2, balance: 10 }]
+		);
+	});
+}
@@ -0,0 +1,149 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs the `fungibles` conformance tests against sufficient and non-sufficient assets.
+
+use super::*;
+use frame_support::traits::fungibles::{conformance_tests, Mutate};
+use paste::paste;
+
+/// The asset the conformance tests are run with.
+const ASSET: u32 = 0;
+
+/// Create `ASSET` with the given `min_balance`. Accounts of a non-sufficient asset can only exist
+/// with a native balance, so every account the conformance tests use is given one.
+fn create_asset(is_sufficient: bool, min_balance: u64) {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET, 1, is_sufficient, min_balance));
+	if !is_sufficient {
+		for who in [0, 1, 5, 10, 20, 100, DustTreasury::get()] {
+			Balances::make_free_balance_be(&who, 100);
+		}
+	}
+}
+
+macro_rules! run_tests {
+	($path:path, $min_balance:expr, $kind:ident = $is_sufficient:expr, $($name:ident),*) => {
+		$(
+			paste! {
+				#[test]
+				fn [< $name _ $kind _min_balance_ $min_balance _dust_trap_on >]() {
+					build_and_execute(|| {
+						create_asset($is_sufficient, $min_balance);
+						credit_dust_to_treasury();
+						let trap_account = DustTreasury::get();
+						<Assets as Mutate<u64>>::set_balance(ASSET, &trap_account, $min_balance);
+						$path::$name::<Assets, u64>(ASSET, Some(trap_account));
+					});
+				}
+
+				#[test]
+				fn [< $name _ $kind _min_balance_ $min_balance _dust_trap_off >]() {
+					build_and_execute(|| {
+						create_asset($is_sufficient, $min_balance);
+						$path::$name::<Assets, u64>(ASSET, None);
+					});
+				}
+			}
+		)*
+	};
+	($path:path, $min_balance:expr, $kind:ident = $is_sufficient:expr) => {
+		run_tests!(
+			$path,
+			$min_balance,
+			$kind = $is_sufficient,
+			mint_into_success,
+			mint_into_overflow,
+			mint_into_below_minimum,
+			burn_from_exact_success,
+			burn_from_best_effort_success,
+			burn_from_exact_insufficient_funds,
+			restore_success,
+			restore_overflow,
+			restore_below_minimum,
+			shelve_success,
+			shelve_insufficient_funds,
+			transfer_success,
+			transfer_expendable_all,
+			transfer_expendable_dust,
+			transfer_protect_preserve,
+			set_balance_mint_success,
+			set_balance_burn_success,
+			can_deposit_success,
+			can_deposit_below_minimum,
+			can_deposit_overflow,
+			can_withdraw_success,
+			can_withdraw_reduced_to_zero,
+			can_withdraw_balance_low,
+			reducible_balance_expendable,
+			reducible_balance_protect_preserve
+		);
+	};
+}
+
+macro_rules! run_freeze_tests {
+	($path:path, $min_balance:expr, $kind:ident = $is_sufficient:expr, $($name:ident),*) => {
+		$(
+			paste! {
+				#[test]
+				fn [< $name _ $kind _min_balance_ $min_balance >]() {
+					build_and_execute(|| {
+						create_asset($is_sufficient, $min_balance);
+						$path::$name::<Assets, u64>(ASSET, &TestFreezeId::Vesting);
+					});
+				}
+			}
+		)*
+	};
+	($path:path, $min_balance:expr, $kind:ident = $is_sufficient:expr) => {
+		run_freeze_tests!(
+			$path,
+			$min_balance,
+			$kind = $is_sufficient,
+			set_freeze_success,
+			set_freeze_limits_transfer,
+			set_freeze_overwrite,
+			extend_freeze_success,
+			thaw_success
+		);
+
+		paste! {
+			#[test]
+			fn [< set_freeze_overlapping_ $kind _min_balance_ $min_balance >]() {
+				build_and_execute(|| {
+					create_asset($is_sufficient, $min_balance);
+					$path::set_freeze_overlapping::<Assets, u64>(
+						ASSET,
+						&TestFreezeId::Vesting,
+						&TestFreezeId::Voting,
+					);
+				});
+			}
+		}
+	};
+}
+
+run_tests!(conformance_tests::inspect_mutate, 1, sufficient = true);
+run_tests!(conformance_tests::inspect_mutate, 2, sufficient = true);
+run_tests!(conformance_tests::inspect_mutate, 5, sufficient = true);
+run_tests!(conformance_tests::inspect_mutate, 1000, sufficient = true);
+run_tests!(conformance_tests::inspect_mutate, 1, insufficient = false);
+run_tests!(conformance_tests::inspect_mutate, 1000, insufficient = false);
+
+run_freeze_tests!(conformance_tests::freeze, 1, sufficient = true);
+run_freeze_tests!(conformance_tests::freeze, 1000, sufficient = true);
+run_freeze_tests!(conformance_tests::freeze, 1, insufficient = false);
+run_freeze_tests!(conformance_tests::freeze, 1000, insufficient = false);
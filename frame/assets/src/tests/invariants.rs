@@ -0,0 +1,240 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Property-based tests checking the storage invariants after every step of random sequences of
+//! calls. A failing sequence is shrunk to the shortest one still breaking an invariant.
+
+use super::*;
+use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+
+/// The asset the steps are applied to, first owned by account 1 which always stays its admin.
+const ASSET: u32 = 0;
+/// The min balance of `ASSET`, small enough for the random amounts to leave dust behind.
+const MIN_BALANCE: u64 = 10;
+/// The steps are taken by the accounts `1..=ACCOUNTS`.
+const ACCOUNTS: u64 = 4;
+
+#[derive(Clone, Debug)]
+enum Step {
+	Mint { who: u64, amount: u64 },
+	Burn { who: u64, amount: u64 },
+	Transfer { from: u64, to: u64, amount: u64, keep_alive: bool },
+	ApproveTransfer { owner: u64, delegate: u64, amount: u64 },
+	TransferApproved { delegate: u64, owner: u64, to: u64, amount: u64 },
+	Touch { who: u64 },
+	Refund { who: u64, allow_burn: bool },
+	Freeze { who: u64 },
+	Thaw { who: u64 },
+	FreezeAsset,
+	ThawAsset,
+	Create { who: u64 },
+	TransferOwnership { from: u64, to: u64 },
+	ForceAssetStatus { owner: u64, is_frozen: bool },
+	StartDestroy,
+	DestroyAccounts { max_items: u32 },
+	DestroyApprovals { max_items: u32 },
+	FinishDestroy,
+}
+
+fn arbitrary_account(g: &mut Gen) -> u64 {
+	1 + u64::arbitrary(g) % ACCOUNTS
+}
+
+fn arbitrary_amount(g: &mut Gen) -> u64 {
+	u64::arbitrary(g) % (20 * MIN_BALANCE)
+}
+
+/// A batch size for the destroy calls, from zero to beyond the configured limits.
+fn arbitrary_batch(g: &mut Gen) -> u32 {
+	u32::arbitrary(g) % 12
+}
+
+impl Arbitrary for Step {
+	fn arbitrary(g: &mut Gen) -> Self {
+		// Balance changes are the most frequent, and destruction the rarest.
+		match u8::arbitrary(g) % 35 {
+			0..=5 => Step::Mint { who: arbitrary_account(g), amount: arbitrary_amount(g) },
+			6..=8 => Step::Burn { who: arbitrary_account(g), amount: arbitrary_amount(g) },
+			9..=14 => Step::Transfer {
+				from: arbitrary_account(g),
+				to: arbitrary_account(g),
+				amount: arbitrary_amount(g),
+				keep_alive: bool::arbitrary(g),
+			},
+			15..=17 => Step::ApproveTransfer {
+				owner: arbitrary_account(g),
+				delegate: arbitrary_account(g),
+				amount: arbitrary_amount(g),
+			},
+			18..=20 => Step::TransferApproved {
+				delegate: arbitrary_account(g),
+				owner: arbitrary_account(g),
+				to: arbitrary_account(g),
+				amount: arbitrary_amount(g),
+			},
+			21..=22 => Step::Touch { who: arbitrary_account(g) },
+			23..=24 => Step::Refund { who: arbitrary_account(g), allow_burn: bool::arbitrary(g) },
+			25 => Step::Freeze { who: arbitrary_account(g) },
+			26 => Step::Thaw { who: arbitrary_account(g) },
+			27 => Step::FreezeAsset,
+			28 => Step::ThawAsset,
+			29 => Step::Create { who: arbitrary_account(g) },
+			30 => Step::TransferOwnership { from: arbitrary_account(g), to: arbitrary_account(g) },
+			31 => Step::ForceAssetStatus {
+				owner: arbitrary_account(g),
+				is_frozen: bool::arbitrary(g),
+			},
+			32 => Step::StartDestroy,
+			33 => Step::DestroyAccounts { max_items: arbitrary_batch(g) },
+			_ => match bool::arbitrary(g) {
+				true => Step::DestroyApprovals { max_items: arbitrary_batch(g) },
+				false => Step::FinishDestroy,
+			},
+		}
+	}
+}
+
+impl Step {
+	/// Dispatch the step. It may well fail, in which case it must not have changed anything.
+	fn apply(self) {
+		let signed = RuntimeOrigin::signed;
+		match self {
+			Step::Mint { who, amount } => {
+				let _ = Assets::mint(signed(1), ASSET, who, amount);
+			},
+			Step::Burn { who, amount } => {
+				let _ = Assets::burn(signed(1), ASSET, who, amount);
+			},
+			Step::Transfer { from, to, amount, keep_alive: true } => {
+				let _ = Assets::transfer_keep_alive(signed(from), ASSET, to, amount);
+			},
+			Step::Transfer { from, to, amount, keep_alive: false } => {
+				let _ = Assets::transfer(signed(from), ASSET, to, amount);
+			},
+			Step::ApproveTransfer { owner, delegate, amount } => {
+				let _ = Assets::approve_transfer(signed(owner), ASSET, delegate, amount);
+			},
+			Step::TransferApproved { delegate, owner, to, amount } => {
+				let _ = Assets::transfer_approved(signed(delegate), ASSET, owner, to, amount);
+			},
+			Step::Touch { who } => {
+				let _ = Assets::touch(signed(who), ASSET);
+			},
+			Step::Refund { who, allow_burn } => {
+				let _ = Assets::refund(signed(who), ASSET, allow_burn);
+			},
+			Step::Freeze { who } => {
+				let _ = Assets::freeze(signed(1), ASSET, who);
+			},
+			Step::Thaw { who } => {
+				let _ = Assets::thaw(signed(1), ASSET, who);
+			},
+			Step::FreezeAsset => {
+				let _ = Assets::freeze_asset(signed(1), ASSET);
+			},
+			Step::ThawAsset => {
+				let _ = Assets::thaw_asset(signed(1), ASSET);
+			},
+			Step::Create { who } => {
+				let _ = Assets::create(signed(who), ASSET, 1, MIN_BALANCE);
+			},
+			Step::TransferOwnership { from, to } => {
+				let _ = Assets::transfer_ownership(signed(from), ASSET, to);
+			},
+			Step::ForceAssetStatus { owner, is_frozen } => {
+				let _ = Assets::force_asset_status(
+					RuntimeOrigin::root(),
+					ASSET,
+					owner,
+					1,
+					1,
+					1,
+					MIN_BALANCE,
+					false,
+					is_frozen,
+				);
+			},
+			Step::StartDestroy => {
+				let _ = Assets::start_destroy(signed(1), ASSET);
+			},
+			Step::DestroyAccounts { max_items } => {
+				let _ = Assets::destroy_accounts(signed(1), ASSET, max_items);
+			},
+			Step::DestroyApprovals { max_items } => {
+				let _ = Assets::destroy_approvals(signed(1), ASSET, max_items);
+			},
+			Step::FinishDestroy => {
+				let _ = Assets::finish_destroy(signed(1), ASSET);
+			},
+		}
+	}
+}
+
+/// Every account has exactly the deposits recorded for its asset accounts and approvals reserved.
+fn check_deposits() -> Result<(), &'static str> {
+	for who in 1..=ACCOUNTS {
+		let mut recorded = 0;
+		for (holder, account) in Account::<Test>::iter_prefix(ASSET) {
+			match account.reason {
+				ExistenceReason::DepositHeld(deposit) if holder == who => recorded += deposit,
+				ExistenceReason::DepositFrom(depositor, deposit) if depositor == who =>
+					recorded += deposit,
+				_ => {},
+			}
+		}
+		for (_, approval) in Approvals::<Test>::iter_prefix((ASSET, who)) {
+			recorded += approval.deposit;
+		}
+		if Balances::reserved_balance(&who) != recorded {
+			return Err("the reserved balance does not match the recorded deposits")
+		}
+	}
+	Ok(())
+}
+
+fn invariants_hold(steps: Vec<Step>) -> TestResult {
+	new_test_ext().execute_with(|| {
+		// `force_asset_status` hands the asset over without moving its deposit.
+		AssetDeposit::set(0);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET, 1, false, MIN_BALANCE));
+		for who in 1..=ACCOUNTS {
+			Balances::make_free_balance_be(&who, 1_000);
+		}
+
+		for (i, step) in steps.into_iter().enumerate() {
+			let description = format!("step {} ({:?})", i, step);
+			step.apply();
+			if let Err(e) = Assets::do_try_state() {
+				return TestResult::error(format!(
+					"{} broke the storage invariants: {:?}",
+					description, e
+				))
+			}
+			if let Err(e) = check_deposits() {
+				return TestResult::error(format!("{}: {}", description, e))
+			}
+		}
+		TestResult::passed()
+	})
+}
+
+#[test]
+fn random_steps_should_keep_the_invariants() {
+	QuickCheck::new()
+		.tests(200)
+		.quickcheck(invariants_hold as fn(Vec<Step>) -> TestResult)
+}
@@ -0,0 +1,175 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the lazy migration of the `Account` entries, with `TestAccountMigration`.
+
+use super::*;
+use crate::migration::lazy::StartAccountMigration;
+use codec::DecodeAll;
+use frame_support::{
+	storage::unhashed,
+	traits::{Hooks, OnRuntimeUpgrade},
+};
+
+/// Creates the asset 0 with sufficient accounts `1..=20` and the account `21` placing a
+/// deposit, all of them with a balance of 100 and stored in the old encoding, and starts the
+/// migration.
+fn start_migration() {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+	for who in 1..=20 {
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, 100));
+	}
+	Balances::make_free_balance_be(&21, 100);
+	assert_ok!(Assets::touch(RuntimeOrigin::signed(21), 0));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 21, 100));
+	for who in 1..=21 {
+		put_old_account(0, who);
+	}
+	assert_eq!(migrated(), 0);
+	StartAccountMigration::<Test>::on_runtime_upgrade();
+}
+
+/// The number of `Account` entries in the current encoding.
+fn migrated() -> usize {
+	Account::<Test>::iter_keys()
+		.filter(|(id, who)| {
+			let raw = unhashed::get_raw(&Account::<Test>::hashed_key_for(id, who)).unwrap();
+			AssetAccountOf::<Test, ()>::decode_all(&mut &raw[..]).is_ok()
+		})
+		.count()
+}
+
+#[test]
+fn migration_should_spread_over_blocks() {
+	build_and_execute(|| {
+		start_migration();
+
+		// Each block has room for 4 accounts.
+		let budget = <() as WeightInfo>::continue_migration(4);
+		for block in 1..=5 {
+			System::set_block_number(block);
+			assert_eq!(Assets::on_idle(block, budget), budget);
+			assert_eq!(migrated(), 4 * block as usize);
+			assert!(AccountMigrationCursor::<Test>::exists());
+
+			// Accounts read the same whether they are migrated or not.
+			for who in 1..=21 {
+				assert_eq!(Assets::balance(0, who), 100);
+			}
+			Assets::do_try_state().expect("storage invariants should hold");
+		}
+
+		// The last block visits the last account and finds no more.
+		System::set_block_number(6);
+		assert_eq!(Assets::on_idle(6, budget), <() as WeightInfo>::continue_migration(1));
+		assert_eq!(migrated(), 21);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountMigrationCompleted));
+		assert!(!AccountMigrationCursor::<Test>::exists());
+		assert_eq!(Assets::existence_reason(0, 21), Some(ExistenceReason::DepositHeld(10)));
+
+		// Nothing is left to do.
+		assert_eq!(Assets::on_idle(7, budget), Weight::zero());
+	});
+}
+
+#[test]
+fn migration_should_not_exceed_the_idle_weight() {
+	build_and_execute(|| {
+		start_migration();
+
+		// Not even room for one account.
+		let budget = <() as WeightInfo>::continue_migration(1);
+		assert_eq!(
+			Assets::on_idle(1, budget.saturating_sub(Weight::from_parts(1, 0))),
+			Weight::zero()
+		);
+		assert_eq!(
+			Assets::on_idle(1, budget.saturating_sub(Weight::from_parts(0, 1))),
+			Weight::zero()
+		);
+		assert_eq!(migrated(), 0);
+
+		// Room for one account, short of a second in one dimension only.
+		let budget =
+			<() as WeightInfo>::continue_migration(2).saturating_sub(Weight::from_parts(0, 1));
+		assert_eq!(Assets::on_idle(1, budget), <() as WeightInfo>::continue_migration(1));
+		assert_eq!(migrated(), 1);
+	});
+}
+
+#[test]
+fn continue_migration_should_work() {
+	build_and_execute(|| {
+		assert_noop!(
+			Assets::continue_migration(RuntimeOrigin::signed(1), 10),
+			Error::<Test>::NoMigrationOngoing
+		);
+		start_migration();
+
+		// Anyone may migrate accounts, and is only charged for those visited.
+		let info = Assets::continue_migration(RuntimeOrigin::signed(5), 8).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::continue_migration(8)));
+		assert_eq!(migrated(), 8);
+
+		let info = Assets::continue_migration(RuntimeOrigin::signed(5), 100).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::continue_migration(13)));
+		assert_eq!(migrated(), 21);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountMigrationCompleted));
+
+		assert_noop!(
+			Assets::continue_migration(RuntimeOrigin::signed(5), 10),
+			Error::<Test>::NoMigrationOngoing
+		);
+	});
+}
+
+#[test]
+fn unmigrated_accounts_should_be_usable() {
+	build_and_execute(|| {
+		start_migration();
+
+		// Changing an account rewrites it in the current encoding first.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 3));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(3), 0, 4, 10), Error::<Test>::Frozen);
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 4, 100));
+		assert_eq!(migrated(), 3);
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::balance(0, 2), 140);
+		assert_eq!(Assets::balance(0, 4), 0);
+		assert_eq!(Assets::total_supply(0), 2000);
+
+		// The deposit of an account is returned whatever its encoding.
+		assert_eq!(Balances::free_balance(&21), 90);
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(21), 0, true));
+		assert_eq!(Balances::free_balance(&21), 100);
+		assert_eq!(Assets::total_supply(0), 1900);
+
+		// Accounts are destroyed along with the asset whatever their encoding.
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		while Asset::<Test>::get(0).unwrap().accounts > 0 {
+			assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0, u32::MAX));
+		}
+		assert_eq!(Account::<Test>::iter_keys().count(), 0);
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+
+		// With nothing left, the migration ends at once.
+		assert_ok!(Assets::continue_migration(RuntimeOrigin::signed(5), 1));
+		assert!(!AccountMigrationCursor::<Test>::exists());
+	});
+}
@@ -0,0 +1,166 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for an instance of the pallet identifying its assets by a structured `Location`.
+
+use super::*;
+use frame_support::{instances::Instance2, traits::fungibles::Inspect};
+
+type LocationAsset = Asset<Test, Instance2>;
+type LocationAccount = Account<Test, Instance2>;
+
+fn location(index: u32) -> Location {
+	Location { parents: 1, index }
+}
+
+fn parameter(index: u32) -> LocationParameter {
+	location(index).into()
+}
+
+#[test]
+fn location_ids_should_work() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(LocationAssets::create(RuntimeOrigin::signed(1), parameter(0), 1, 1));
+		System::assert_last_event(RuntimeEvent::LocationAssets(crate::Event::Created {
+			asset_id: location(0),
+			creator: 1,
+			owner: 1,
+			deposit: 1,
+		}));
+		assert!(LocationAsset::contains_key(location(0)));
+		// the same index under another location is another asset.
+		assert!(!LocationAsset::contains_key(Location { parents: 0, index: 0 }));
+
+		assert_ok!(LocationAssets::mint(RuntimeOrigin::signed(1), parameter(0), 1, 100));
+		assert_ok!(LocationAssets::transfer(RuntimeOrigin::signed(1), parameter(0), 2, 40));
+		assert_eq!(LocationAssets::balance(location(0), 1), 60);
+		assert_eq!(<LocationAssets as Inspect<u64>>::balance(location(0), &2), 40);
+		assert_eq!(<LocationAssets as Inspect<u64>>::total_issuance(location(0)), 100);
+		assert!(LocationAccount::contains_key(location(0), 2));
+
+		assert_ok!(LocationAssets::set_metadata(
+			RuntimeOrigin::signed(1),
+			parameter(0),
+			b"Relay".to_vec(),
+			b"DOT".to_vec(),
+			10
+		));
+		assert_eq!(Metadata::<Test, Instance2>::get(location(0)).unwrap().decimals, 10);
+
+		// the default instance is unaffected.
+		assert_eq!(Assets::total_supply(0), 0);
+	});
+}
+
+#[test]
+fn location_ids_should_be_destroyable() {
+	build_and_execute(|| {
+		assert_ok!(LocationAssets::force_create(RuntimeOrigin::root(), parameter(7), 1, true, 1));
+		assert_ok!(LocationAssets::mint(RuntimeOrigin::signed(1), parameter(7), 2, 10));
+
+		assert_ok!(LocationAssets::start_destroy(RuntimeOrigin::signed(1), parameter(7)));
+		assert_ok!(LocationAssets::destroy_accounts(
+			RuntimeOrigin::signed(1),
+			parameter(7),
+			u32::MAX
+		));
+		assert_ok!(LocationAssets::destroy_approvals(
+			RuntimeOrigin::signed(1),
+			parameter(7),
+			u32::MAX
+		));
+		assert_ok!(LocationAssets::finish_destroy(RuntimeOrigin::signed(1), parameter(7)));
+		System::assert_last_event(RuntimeEvent::LocationAssets(crate::Event::Destroyed {
+			asset_id: location(7),
+			owner: 1,
+			deposit_returned: 0,
+		}));
+		assert!(!LocationAsset::contains_key(location(7)));
+		assert!(!LocationAccount::contains_key(location(7), 2));
+	});
+}
+
+#[test]
+fn asset_id_hasher_migration_should_work() {
+	use crate::migration::asset_id_hasher;
+	use frame_support::{traits::OnRuntimeUpgrade, Blake2_128Concat, Twox64Concat};
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(LocationAssets::create(RuntimeOrigin::signed(1), parameter(0), 1, 1));
+		assert_ok!(LocationAssets::mint(RuntimeOrigin::signed(1), parameter(0), 1, 100));
+		assert_ok!(LocationAssets::approve_transfer(RuntimeOrigin::signed(1), parameter(0), 2, 10));
+		assert_ok!(LocationAssets::set_metadata(
+			RuntimeOrigin::signed(1),
+			parameter(0),
+			b"Relay".to_vec(),
+			b"DOT".to_vec(),
+			10
+		));
+
+		// go back to the layout of a chain hashing asset ids with `Blake2_128Concat`.
+		assert_eq!(asset_id_hasher::rehash::<Test, Instance2, Twox64Concat, Blake2_128Concat>(), 5);
+		assert!(!LocationAsset::contains_key(location(0)));
+		assert_eq!(LocationAssets::balance(location(0), 1), 0);
+
+		asset_id_hasher::Migrate::<Test, Instance2, Blake2_128Concat>::on_runtime_upgrade();
+		assert!(LocationAsset::contains_key(location(0)));
+		assert_eq!(LocationAssets::balance(location(0), 1), 100);
+		assert_eq!(LocationAssets::allowance(location(0), &1, &2), 10);
+		assert_eq!(Metadata::<Test, Instance2>::get(location(0)).unwrap().decimals, 10);
+		assert_ok!(LocationAssets::transfer(RuntimeOrigin::signed(1), parameter(0), 2, 40));
+
+		// nothing is left to rehash, and the default instance is untouched.
+		assert_eq!(asset_id_hasher::rehash::<Test, Instance2, Blake2_128Concat, Twox64Concat>(), 0);
+		assert_eq!(Assets::balance(999, 1), 100);
+	});
+}
+
+#[test]
+fn location_assets_should_be_built_from_genesis() {
+	use sp_runtime::BuildStorage;
+
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test, Instance2> {
+		assets: vec![(location(0), 1, true, 1)],
+		metadata: vec![(location(0), b"Relay".to_vec(), b"DOT".to_vec(), 10)],
+		accounts: vec![(location(0), 1, 100)],
+		approvals: vec![(location(0), 1, 2, 10)],
+		frozen_accounts: vec![(location(0), 1)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(LocationAssets::asset_count(), 1);
+		assert_eq!(LocationAssets::balance(location(0), 1), 100);
+		assert_eq!(Metadata::<Test, Instance2>::get(location(0)).unwrap().decimals, 10);
+		assert_eq!(LocationAssets::allowance(location(0), &1, &2), 10);
+		assert_noop!(
+			LocationAssets::transfer(RuntimeOrigin::signed(1), parameter(0), 2, 10),
+			Error::<Test, Instance2>::Frozen
+		);
+
+		// the default instance is untouched.
+		assert_eq!(Assets::asset_count(), 0);
+		assert_ok!(Assets::do_try_state());
+		assert_ok!(LocationAssets::do_try_state());
+	});
+}
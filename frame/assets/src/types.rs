@@ -20,9 +20,14 @@
 use super::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{fungible, tokens::ConversionToAssetBalance},
+	traits::{fungible, tokens::ConversionToAssetBalance, TypedGet},
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{Convert, SaturatedConversion},
+	FixedPointNumber, FixedU128,
 };
-use sp_runtime::{traits::Convert, FixedPointNumber, FixedU128};
 
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
@@ -32,13 +37,17 @@ pub(super) type AssetAccountOf<T, I> = AssetAccount<
 	<T as Config<I>>::Extra,
 	<T as SystemConfig>::AccountId,
 >;
-pub(super) type ExistenceReasonOf<T, I> =
+pub(super) type AssetDetailsOf<T, I> =
+	AssetDetails<<T as Config<I>>::Balance, <T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub type ExistenceReasonOf<T, I> =
 	ExistenceReason<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 
 /// AssetStatus holds the current state of the asset. It could either be Live and available for use,
 /// or in a Destroying state.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub(super) enum AssetStatus {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum AssetStatus {
 	/// The asset is active and able to be used.
 	Live,
 	/// Whether the asset is frozen for non-admin transfers.
@@ -49,6 +58,15 @@ pub(super) enum AssetStatus {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display, AccountId: Serialize, DepositBalance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr, AccountId: Deserialize<'de>, DepositBalance: std::str::FromStr"
+	))
+)]
 pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
 	pub(super) owner: AccountId,
@@ -59,10 +77,13 @@ pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	/// Can freeze tokens.
 	pub(super) freezer: AccountId,
 	/// The total supply across all accounts.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) supply: Balance,
 	/// The balance deposited for this asset. This pays for the data stored here.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) deposit: DepositBalance,
 	/// The ED for virtual accounts.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) min_balance: Balance,
 	/// If `true`, then any account with this asset is given a provider reference. Otherwise, it
 	/// requires a consumer reference.
@@ -75,15 +96,42 @@ pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	pub(super) approvals: u32,
 	/// The status of the asset
 	pub(super) status: AssetStatus,
+	/// The balance deposited for the metadata of this asset, as recorded in `Metadata`.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub(super) metadata_deposit: DepositBalance,
+}
+
+/// A role in the management of an asset, as held by the accounts of its [`AssetDetails`].
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Role {
+	/// Can change the other roles, the metadata and the asset's settings.
+	Owner,
+	/// Can mint tokens.
+	Issuer,
+	/// Can thaw tokens, force transfers and burn tokens from any account.
+	Admin,
+	/// Can freeze tokens.
+	Freezer,
 }
 
 /// Data concerning an approval.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display, DepositBalance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr, DepositBalance: std::str::FromStr"
+	))
+)]
 pub struct Approval<Balance, DepositBalance> {
 	/// The amount of funds approved for the balance transfer from the owner to some delegated
 	/// target.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) amount: Balance,
 	/// The amount reserved on the owner's account to hold this item in storage.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) deposit: DepositBalance,
 }
 
@@ -93,8 +141,30 @@ fn ensure_bool_decodes_to_consumer_or_sufficient() {
 	assert_eq!(true.encode(), ExistenceReason::<(), ()>::Sufficient.encode());
 }
 
+/// The kind of deposit reserved by the pallet, as reported by `Event::DepositUpdated`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DepositKind {
+	/// The deposit held by the owner of an asset class.
+	Asset,
+	/// The deposit held by the owner of an asset class for its metadata.
+	Metadata,
+	/// The deposit held for an asset-account to exist.
+	Account,
+	/// The deposit held by the owner of an approval.
+	Approval,
+}
+
 /// The reason for an account's existence within an asset class.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display, AccountId: Serialize",
+		deserialize = "Balance: std::str::FromStr, AccountId: Deserialize<'de>"
+	))
+)]
 pub enum ExistenceReason<Balance, AccountId> {
 	/// A consumer reference was used to create this account.
 	#[codec(index = 0)]
@@ -104,14 +174,14 @@ pub enum ExistenceReason<Balance, AccountId> {
 	Sufficient,
 	/// The account holder has placed a deposit to exist within an asset class.
 	#[codec(index = 2)]
-	DepositHeld(Balance),
+	DepositHeld(#[cfg_attr(feature = "std", serde(with = "serde_balance"))] Balance),
 	/// A deposit was placed for this account to exist, but it has been refunded.
 	#[codec(index = 3)]
 	DepositRefunded,
 	/// Some other `AccountId` has placed a deposit to make this account exist.
 	/// An account with such a reason might not be referenced in `system`.
 	#[codec(index = 4)]
-	DepositFrom(AccountId, Balance),
+	DepositFrom(AccountId, #[cfg_attr(feature = "std", serde(with = "serde_balance"))] Balance),
 }
 
 impl<Balance, AccountId> ExistenceReason<Balance, AccountId>
@@ -143,6 +213,16 @@ where
 			None
 		}
 	}
+
+	/// Take the deposit held for the account `who`, if any, together with the account which paid
+	/// it and which should get it back.
+	pub(crate) fn take_deposit_and_payer(
+		&mut self,
+		who: &AccountId,
+	) -> Option<(AccountId, Balance)> {
+		self.take_deposit_from()
+			.or_else(|| self.take_deposit().map(|deposit| (who.clone(), deposit)))
+	}
 }
 
 #[test]
@@ -153,6 +233,8 @@ fn ensure_bool_decodes_to_liquid_or_frozen() {
 
 /// The status of an asset account.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum AccountStatus {
 	/// Asset account can receive and transfer the assets.
 	Liquid,
@@ -173,8 +255,18 @@ impl AccountStatus {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display, DepositBalance: std::fmt::Display, Extra: Serialize, AccountId: Serialize",
+		deserialize = "Balance: std::str::FromStr, DepositBalance: std::str::FromStr, Extra: Deserialize<'de>, AccountId: Deserialize<'de>"
+	))
+)]
 pub struct AssetAccount<Balance, DepositBalance, Extra, AccountId> {
 	/// The balance.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) balance: Balance,
 	/// The status of the account.
 	pub(super) status: AccountStatus,
@@ -185,14 +277,26 @@ pub struct AssetAccount<Balance, DepositBalance, Extra, AccountId> {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "DepositBalance: std::fmt::Display, BoundedString: AsRef<[u8]>",
+		deserialize = "DepositBalance: std::str::FromStr, BoundedString: TryFrom<Vec<u8>>"
+	))
+)]
 pub struct AssetMetadata<DepositBalance, BoundedString> {
 	/// The balance deposited for this metadata.
 	///
 	/// This pays for the data stored in this struct.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
 	pub(super) deposit: DepositBalance,
 	/// The user friendly name of this asset. Limited in length by `StringLimit`.
+	#[cfg_attr(feature = "std", serde(with = "serde_bytes"))]
 	pub(super) name: BoundedString,
 	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
+	#[cfg_attr(feature = "std", serde(with = "serde_bytes"))]
 	pub(super) symbol: BoundedString,
 	/// The number of decimals this asset uses to represent one unit.
 	pub(super) decimals: u8,
@@ -200,6 +304,125 @@ pub struct AssetMetadata<DepositBalance, BoundedString> {
 	pub(super) is_frozen: bool,
 }
 
+/// Balances are (de)serialized as decimal strings, since JSON numbers cannot hold every `u128`.
+#[cfg(feature = "std")]
+mod serde_balance {
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer, T: std::fmt::Display>(
+		t: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&t.to_string())
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		s.parse::<T>().map_err(|_| serde::de::Error::custom("Parse from string failed"))
+	}
+}
+
+/// Bounded byte strings, such as names and symbols, are (de)serialized as `0x`-prefixed hex.
+#[cfg(feature = "std")]
+mod serde_bytes {
+	use serde::{Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer, T: AsRef<[u8]>>(
+		t: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		sp_core::bytes::serialize(t.as_ref(), serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>, T: TryFrom<Vec<u8>>>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let bytes = sp_core::bytes::deserialize(deserializer)?;
+		T::try_from(bytes).map_err(|_| serde::de::Error::custom("Byte string too long"))
+	}
+}
+
+/// The parts of an asset's details which are of interest to wallets, as reported by
+/// [`Pallet::asset_info`] and [`Pallet::assets_info`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AssetDetailsSummary<Balance> {
+	/// The status of the asset.
+	pub status: AssetStatus,
+	/// The total supply across all accounts.
+	pub supply: Balance,
+	/// The ED for virtual accounts.
+	pub min_balance: Balance,
+	/// If `true`, then any account with this asset is given a provider reference.
+	pub is_sufficient: bool,
+	/// The total number of accounts.
+	pub accounts: u32,
+}
+
+/// The balance of an asset account, as reported by [`Pallet::account_balances_info`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AccountBalanceSummary<Balance> {
+	/// The balance of the account.
+	pub balance: Balance,
+	/// Whether the account, or the whole asset, is frozen.
+	pub is_frozen: bool,
+	/// The amount which can currently be withdrawn, if the account may die.
+	pub reducible: Balance,
+}
+
+impl<Balance, AccountId, DepositBalance> From<AssetDetails<Balance, AccountId, DepositBalance>>
+	for AssetDetailsSummary<Balance>
+{
+	fn from(details: AssetDetails<Balance, AccountId, DepositBalance>) -> Self {
+		AssetDetailsSummary {
+			status: details.status,
+			supply: details.supply,
+			min_balance: details.min_balance,
+			is_sufficient: details.is_sufficient,
+			accounts: details.accounts,
+		}
+	}
+}
+
+/// An identifier and balance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// An identifier for this item.
+	pub id: Id,
+	/// Some amount for this item.
+	pub amount: Balance,
+}
+
+/// The maximum length of the raw storage key kept by a [`MigrationCursor`].
+pub const MAX_CURSOR_KEY_LEN: u32 = 1024;
+
+/// How far a lazy migration of the `Account` entries has got, see [`crate::migration::lazy`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum MigrationCursor {
+	/// No entry has been visited yet.
+	Start,
+	/// The entries up to, and including, the one stored under this raw key have been visited.
+	After(BoundedVec<u8, ConstU32<MAX_CURSOR_KEY_LEN>>),
+}
+
+/// How far the repair of the counters of an asset has got, see [`crate::Call::repair_refs`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RefRepair {
+	/// The `Account` entries of the asset visited so far.
+	pub cursor: MigrationCursor,
+	/// The number of accounts counted so far.
+	pub accounts: u32,
+	/// The number of those which exist because the asset is sufficient.
+	pub sufficients: u32,
+}
+
+impl Default for RefRepair {
+	fn default() -> Self {
+		RefRepair { cursor: MigrationCursor::Start, accounts: 0, sufficients: 0 }
+	}
+}
+
 /// Trait for allowing a minimum balance on the account to be specified, beyond the
 /// `minimum_balance` of the asset. This is additive - the `minimum_balance` of the asset must be
 /// met *and then* anything here in addition.
@@ -216,6 +439,9 @@ pub trait FrozenBalance<AssetId, AccountId, Balance> {
 	///
 	/// In special cases (privileged intervention) the account balance may also go below the sum.
 	///
+	/// The returned value should not exceed the account's balance. If it does, the pallet treats
+	/// the whole balance as frozen: nothing can be withdrawn and a `Frozen` error is returned.
+	///
 	/// If `None` is returned, then nothing special is enforced.
 	fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance>;
 
@@ -232,29 +458,91 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-pub(super) struct TransferFlags {
+/// Combines several freezers into one.
+///
+/// The frozen balance is the largest one reported by any member, or `None` if none of them freeze
+/// anything. Each member enforces its own minimum on the same funds, so taking the maximum rather
+/// than the sum lets independent freezers share a balance without freezing it twice over. `died`
+/// is passed on to every member.
+#[impl_trait_for_tuples::impl_for_tuples(1, 30)]
+impl<AssetId: Clone, AccountId, Balance: Ord> FrozenBalance<AssetId, AccountId, Balance> for Tuple {
+	fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance> {
+		let mut frozen = None;
+		for_tuples!( #( frozen = frozen.max(Tuple::frozen_balance(asset.clone(), who)); )* );
+		frozen
+	}
+
+	fn died(asset: AssetId, who: &AccountId) {
+		for_tuples!( #( Tuple::died(asset.clone(), who); )* );
+	}
+}
+
+/// A freezer backed by storage of its own, to benchmark the instance `I` of the pallet with.
+///
+/// Every call reads (and `died` also removes) `N` storage items per account, the largest of which
+/// is the frozen balance. A runtime whose `Freezer` reads storage should use this as its `Freezer`
+/// when benchmarking, with `N` at least the number of items its own freezer reads. The items are
+/// prefixed with the name of the pallet instance, so that instances do not share frozen balances.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkFreezer<T, I, const N: u32>(PhantomData<(T, I)>);
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Config<I>, I: 'static, const N: u32> BenchmarkFreezer<T, I, N> {
+	fn key(asset: &T::AssetId, who: &T::AccountId, i: u32) -> Vec<u8> {
+		(b":assets:benchmark_freezer:", <Pallet<T, I> as PalletInfoAccess>::name(), asset, who, i)
+			.encode()
+	}
+
+	/// Freeze `amount` of `who`'s balance of `asset` in the storage item `i`.
+	pub fn set_frozen(asset: &T::AssetId, who: &T::AccountId, i: u32, amount: T::Balance) {
+		frame_support::storage::unhashed::put(&Self::key(asset, who, i), &amount);
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Config<I>, I: 'static, const N: u32> FrozenBalance<T::AssetId, T::AccountId, T::Balance>
+	for BenchmarkFreezer<T, I, N>
+{
+	fn frozen_balance(asset: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		(0..N)
+			.filter_map(|i| frame_support::storage::unhashed::get(&Self::key(&asset, who, i)))
+			.max()
+	}
+
+	fn died(asset: T::AssetId, who: &T::AccountId) {
+		for i in 0..N {
+			frame_support::storage::unhashed::kill(&Self::key(&asset, who, i));
+		}
+	}
+}
+
+/// How a transfer made with [`Pallet::transfer_with_flags`] may deviate from the amount asked for.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
 	/// this cannot be achieved legally.
-	pub(super) keep_alive: bool,
+	pub keep_alive: bool,
 	/// Less than the amount specified needs be debited by the operation for it to be considered
 	/// successful. If `false`, then the amount debited will always be at least the amount
 	/// specified.
-	pub(super) best_effort: bool,
-	/// Any additional funds debited (due to minimum balance requirements) should be burned rather
-	/// than credited to the destination account.
-	pub(super) burn_dust: bool,
+	pub best_effort: bool,
+	/// Any additional funds debited (due to minimum balance requirements) should be handed to
+	/// `T::OnDust` rather than credited to the destination account.
+	pub burn_dust: bool,
+	/// The transfer is forced by a privileged origin and is not subject to `T::TransferFilter`.
+	pub bypass_filter: bool,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-pub(super) struct DebitFlags {
+/// How a burn made with [`Pallet::burn_with_flags`] may deviate from the amount asked for.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct DebitFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
 	/// this cannot be achieved legally.
-	pub(super) keep_alive: bool,
+	pub keep_alive: bool,
 	/// Less than the amount specified needs be debited by the operation for it to be considered
 	/// successful. If `false`, then the amount debited will always be at least the amount
 	/// specified.
-	pub(super) best_effort: bool,
+	pub best_effort: bool,
 }
 
 impl From<TransferFlags> for DebitFlags {
@@ -317,3 +605,172 @@ where
 			.saturating_mul_int(balance))
 	}
 }
+
+/// Credits dust to the account `A::get()`, for use as `OnDust`.
+///
+/// Dust which can't be credited, e.g. because it doesn't reach the minimum balance needed to
+/// create the account, is burned instead.
+pub struct ResolveDustTo<A, F>(PhantomData<(A, F)>);
+impl<A: TypedGet, F: fungibles::Balanced<A::Type>> HandleDust<A::Type, F> for ResolveDustTo<A, F> {
+	fn handle_dust(dust: fungibles::Credit<A::Type, F>) -> Option<A::Type> {
+		let to = A::get();
+		// dust handed back is dropped, which burns it.
+		F::resolve(&to, dust).ok().map(|()| to)
+	}
+}
+
+/// The storage taken up by an account or an approval, as priced by a [`DepositPolicy`].
+#[derive(Clone, Copy, Eq, PartialEq, Default, RuntimeDebug)]
+pub struct StorageFootprint {
+	/// The number of storage items.
+	pub items: u32,
+	/// The encoded size of the items, in bytes.
+	pub bytes: u32,
+}
+
+impl StorageFootprint {
+	/// The footprint of `value` stored as a single item.
+	pub fn of<E: Encode>(value: &E) -> Self {
+		Self { items: 1, bytes: value.encoded_size().saturated_into() }
+	}
+}
+
+/// Reserves the flat deposits configured by `AssetDeposit`, `AssetAccountDeposit`,
+/// `MetadataDepositBase`, `MetadataDepositPerByte` and `ApprovalDeposit` for every asset class,
+/// whatever the footprint of accounts and approvals.
+pub struct ConfiguredDeposits<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> DepositPolicy<T::AssetId, DepositBalanceOf<T, I>>
+	for ConfiguredDeposits<T, I>
+{
+	fn asset_deposit(_: &T::AssetId) -> DepositBalanceOf<T, I> {
+		T::AssetDeposit::get()
+	}
+
+	fn account_deposit(_: &T::AssetId, _: StorageFootprint) -> DepositBalanceOf<T, I> {
+		T::AssetAccountDeposit::get()
+	}
+
+	fn metadata_deposit(_: &T::AssetId, bytes: u32) -> DepositBalanceOf<T, I> {
+		Self::max_metadata_deposit(bytes)
+	}
+
+	fn max_metadata_deposit(bytes: u32) -> DepositBalanceOf<T, I> {
+		T::MetadataDepositPerByte::get()
+			.saturating_mul(bytes.into())
+			.saturating_add(T::MetadataDepositBase::get())
+	}
+
+	fn approval_deposit(_: &T::AssetId, _: StorageFootprint) -> DepositBalanceOf<T, I> {
+		T::ApprovalDeposit::get()
+	}
+}
+
+/// Reserves the asset and metadata deposits like [`ConfiguredDeposits`], but prices accounts and
+/// approvals by their footprint: `PerItem` for every storage item plus `PerByte` for every byte.
+///
+/// Accounts are priced when they are created and whenever their `Extra` data is written through
+/// `StoredMap`; `poke_account_deposit` reprices them otherwise.
+pub struct FootprintDeposits<T, PerItem, PerByte, I = ()>(PhantomData<(T, PerItem, PerByte, I)>);
+impl<T, PerItem, PerByte, I> FootprintDeposits<T, PerItem, PerByte, I>
+where
+	T: Config<I>,
+	PerItem: Get<DepositBalanceOf<T, I>>,
+	PerByte: Get<DepositBalanceOf<T, I>>,
+	I: 'static,
+{
+	fn price(footprint: StorageFootprint) -> DepositBalanceOf<T, I> {
+		PerItem::get()
+			.saturating_mul(footprint.items.into())
+			.saturating_add(PerByte::get().saturating_mul(footprint.bytes.into()))
+	}
+}
+impl<T, PerItem, PerByte, I> DepositPolicy<T::AssetId, DepositBalanceOf<T, I>>
+	for FootprintDeposits<T, PerItem, PerByte, I>
+where
+	T: Config<I>,
+	PerItem: Get<DepositBalanceOf<T, I>>,
+	PerByte: Get<DepositBalanceOf<T, I>>,
+	I: 'static,
+{
+	fn asset_deposit(id: &T::AssetId) -> DepositBalanceOf<T, I> {
+		ConfiguredDeposits::<T, I>::asset_deposit(id)
+	}
+
+	fn account_deposit(_: &T::AssetId, footprint: StorageFootprint) -> DepositBalanceOf<T, I> {
+		Self::price(footprint)
+	}
+
+	fn metadata_deposit(id: &T::AssetId, bytes: u32) -> DepositBalanceOf<T, I> {
+		ConfiguredDeposits::<T, I>::metadata_deposit(id, bytes)
+	}
+
+	fn max_metadata_deposit(bytes: u32) -> DepositBalanceOf<T, I> {
+		ConfiguredDeposits::<T, I>::max_metadata_deposit(bytes)
+	}
+
+	fn approval_deposit(_: &T::AssetId, footprint: StorageFootprint) -> DepositBalanceOf<T, I> {
+		Self::price(footprint)
+	}
+}
+
+/// Accepts names which are UTF-8 text without control or bidirectional formatting characters,
+/// and symbols made of ASCII letters, digits, `-`, `.` and `_` only.
+///
+/// Bidirectional formatting characters are refused since they can make a name display as
+/// another one.
+pub struct ReadableMetadata;
+impl ReadableMetadata {
+	/// Whether `c` is a control character or one changing the direction of the text around it.
+	fn is_formatting(c: char) -> bool {
+		c.is_control() ||
+			matches!(
+				c,
+				'\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+			)
+	}
+}
+impl MetadataPolicy for ReadableMetadata {
+	fn is_valid(name: &[u8], symbol: &[u8]) -> bool {
+		let readable_name = sp_std::str::from_utf8(name)
+			.map_or(false, |name| !name.chars().any(Self::is_formatting));
+		let plain_symbol = symbol
+			.iter()
+			.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_'));
+		readable_name && plain_symbol
+	}
+}
+
+/// Counters of the activity of an asset since it was created, which saturate at their maximum.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetStatistics<Balance> {
+	/// The number of transfers of a non-zero amount.
+	pub transfers: u64,
+	/// The total amount credited by these transfers.
+	pub volume: Balance,
+	/// The number of mints of a non-zero amount.
+	pub mints: u64,
+	/// The number of burns of a non-zero amount.
+	pub burns: u64,
+}
+
+/// A problem with an entry of the `GenesisConfig`.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub(crate) struct GenesisProblem {
+	/// The list of the config holding the entry.
+	pub(crate) list: &'static str,
+	/// The index of the entry in its list.
+	pub(crate) index: usize,
+	/// What is wrong with the entry.
+	pub(crate) problem: &'static str,
+}
+
+/// Displays the problems of a `GenesisConfig`, one per line.
+pub(super) struct GenesisProblems<'a>(pub(super) &'a [GenesisProblem]);
+impl sp_std::fmt::Display for GenesisProblems<'_> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		for problem in self.0 {
+			write!(f, "\n{}[{}]: {}", problem.list, problem.index, problem.problem)?;
+		}
+		Ok(())
+	}
+}
@@ -78,54 +78,73 @@ pub trait WeightInfo {
 	fn transfer_approved() -> Weight;
 	fn cancel_approval() -> Weight;
 	fn force_cancel_approval() -> Weight;
+	fn force_cancel_approvals_of(a: u32, ) -> Weight;
 	fn set_min_balance() -> Weight;
 	fn touch() -> Weight;
 	fn touch_other() -> Weight;
 	fn refund() -> Weight;
 	fn refund_other() -> Weight;
 	fn block() -> Weight;
+	fn set_max_holders() -> Weight;
+	fn owned_assets(n: u32, ) -> Weight;
+	fn continue_migration(n: u32, ) -> Weight;
+	fn refresh_asset_deposit() -> Weight;
+	fn set_allow_mint_when_frozen() -> Weight;
+	fn set_allow_admin_burn_when_frozen() -> Weight;
+	fn destroy_metadata() -> Weight;
+	fn poke_account_deposit() -> Weight;
+	fn reject_approval() -> Weight;
+	fn repair_refs(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_assets using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:1 w:1)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:1)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `293`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 31_340_000 picoseconds.
-		Weight::from_parts(31_977_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(31_977_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:1 w:1)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:1)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `153`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_342_000 picoseconds.
-		Weight::from_parts(13_782_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(13_782_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn start_destroy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_437_000 picoseconds.
-		Weight::from_parts(14_833_000, 3675)
+		Weight::from_parts(14_833_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1001 w:1000)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1000 w:1000)
@@ -134,9 +153,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn destroy_accounts(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0 + c * (208 ±0)`
-		//  Estimated: `3675 + c * (2609 ±0)`
+		//  Estimated: `3691 + c * (2609 ±0)`
 		// Minimum execution time: 18_728_000 picoseconds.
-		Weight::from_parts(18_982_000, 3675)
+		Weight::from_parts(18_982_000, 3691)
 			// Standard Error: 11_708
 			.saturating_add(Weight::from_parts(14_363_570, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
@@ -146,16 +165,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(c.into()))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1001 w:1000)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy_approvals(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `522 + a * (86 ±0)`
-		//  Estimated: `3675 + a * (2623 ±0)`
+		//  Estimated: `3691 + a * (2623 ±0)`
 		// Minimum execution time: 18_611_000 picoseconds.
-		Weight::from_parts(18_970_000, 3675)
+		Weight::from_parts(18_970_000, 3691)
 			// Standard Error: 13_224
 			.saturating_add(Weight::from_parts(16_397_299, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
@@ -165,163 +184,201 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 2623).saturating_mul(a.into()))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:0)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:1 w:1)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:1)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn finish_destroy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_504_000 picoseconds.
-		Weight::from_parts(14_906_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(14_906_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Assets AllowMintWhenFrozen (r:1 w:0)
+	/// Proof: Assets AllowMintWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 26_653_000 picoseconds.
-		Weight::from_parts(27_260_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(27_260_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Assets AllowAdminBurnWhenFrozen (r:1 w:0)
+	/// Proof: Assets AllowAdminBurnWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(34_474_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_609_000 picoseconds.
 		Weight::from_parts(48_476_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 41_625_000 picoseconds.
 		Weight::from_parts(43_030_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_661_000 picoseconds.
 		Weight::from_parts(48_469_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 17_727_000 picoseconds.
-		Weight::from_parts(18_384_000, 3675)
+		Weight::from_parts(18_384_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	fn thaw() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 17_657_000 picoseconds.
-		Weight::from_parts(18_282_000, 3675)
+		Weight::from_parts(18_282_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn freeze_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_743_000 picoseconds.
-		Weight::from_parts(14_193_000, 3675)
+		Weight::from_parts(14_193_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn thaw_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_653_000 picoseconds.
-		Weight::from_parts(14_263_000, 3675)
+		Weight::from_parts(14_263_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:0)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:2 w:2)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:2)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 15_328_000 picoseconds.
-		Weight::from_parts(16_042_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(16_042_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn set_team() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_097_000 picoseconds.
-		Weight::from_parts(14_641_000, 3675)
+		Weight::from_parts(14_641_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 50]`.
@@ -329,27 +386,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_metadata(_n: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 29_535_000 picoseconds.
-		Weight::from_parts(31_456_892, 3675)
+		Weight::from_parts(31_456_892, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	fn clear_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `515`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 30_680_000 picoseconds.
-		Weight::from_parts(31_930_000, 3675)
+		Weight::from_parts(31_930_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 50]`.
@@ -357,215 +414,377 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_set_metadata(_n: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `190`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_660_000 picoseconds.
-		Weight::from_parts(15_718_387, 3675)
+		Weight::from_parts(15_718_387, 3691)
 			// Standard Error: 622
 			.saturating_add(Weight::from_parts(2_640, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	fn force_clear_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `515`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 30_853_000 picoseconds.
-		Weight::from_parts(31_483_000, 3675)
+		Weight::from_parts(31_483_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:2 w:2)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:2)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_asset_status() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_632_000 picoseconds.
-		Weight::from_parts(14_077_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(14_077_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	fn approve_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 33_780_000 picoseconds.
-		Weight::from_parts(34_533_000, 3675)
+		Weight::from_parts(34_533_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `668`
 		//  Estimated: `6208`
 		// Minimum execution time: 67_712_000 picoseconds.
 		Weight::from_parts(69_946_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	fn cancel_approval() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 36_668_000 picoseconds.
-		Weight::from_parts(37_637_000, 3675)
+		Weight::from_parts(37_637_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	fn force_cancel_approval() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 36_685_000 picoseconds.
-		Weight::from_parts(37_950_000, 3675)
+		Weight::from_parts(37_950_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Approvals (r:101 w:100)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets ApprovalCount (r:1 w:1)
+	/// Proof: Assets ApprovalCount (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets DelegateApprovals (r:0 w:100)
+	/// Proof: Assets DelegateApprovals (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets DelegateApprovalCount (r:100 w:100)
+	/// Proof: Assets DelegateApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 100]`.
+	fn force_cancel_approvals_of(a: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `force_cancel_approvals_of` benchmark to replace them.
+		Weight::from_parts(21_874_000, 3691)
+			.saturating_add(Weight::from_parts(19_402_118, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2623).saturating_mul(a.into()))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn set_min_balance() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_466_000 picoseconds.
-		Weight::from_parts(14_924_000, 3675)
+		Weight::from_parts(14_924_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn touch() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 34_874_000 picoseconds.
-		Weight::from_parts(36_330_000, 3675)
+		Weight::from_parts(36_330_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn touch_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 33_278_000 picoseconds.
-		Weight::from_parts(34_104_000, 3675)
+		Weight::from_parts(34_104_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn refund() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `579`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 32_898_000 picoseconds.
-		Weight::from_parts(33_489_000, 3675)
+		Weight::from_parts(33_489_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn refund_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `510`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 31_243_000 picoseconds.
-		Weight::from_parts(31_909_000, 3675)
+		Weight::from_parts(31_909_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	fn block() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 17_692_000 picoseconds.
-		Weight::from_parts(18_253_000, 3675)
+		Weight::from_parts(18_253_000, 3691)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets MaxHolders (r:0 w:1)
+	/// Proof: Assets MaxHolders (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn set_max_holders() -> Weight {
+		// Placeholder figures, not measured: run the `set_max_holders` benchmark to replace them.
+		Weight::from_parts(14_388_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1001 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn owned_assets(n: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `owned_assets` benchmark to replace them.
+		Weight::from_parts(6_240_000, 990)
+			.saturating_add(Weight::from_parts(7_318_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2701).saturating_mul(n.into()))
+	}
+	/// Storage: Assets AccountMigrationCursor (r:1 w:1)
+	/// Proof: Assets AccountMigrationCursor (max_values: Some(1), max_size: Some(1027), added: 1522, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:1000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn continue_migration(n: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `continue_migration` benchmark to replace them.
+		Weight::from_parts(9_417_000, 2512)
+			.saturating_add(Weight::from_parts(5_106_339, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn refresh_asset_deposit() -> Weight {
+		// Placeholder figures, not measured: run the `refresh_asset_deposit` benchmark to replace them.
+		Weight::from_parts(39_240_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets AllowMintWhenFrozen (r:0 w:1)
+	/// Proof: Assets AllowMintWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_allow_mint_when_frozen() -> Weight {
+		// Placeholder figures, not measured: run the `set_allow_mint_when_frozen` benchmark to replace them.
+		Weight::from_parts(14_388_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets AllowAdminBurnWhenFrozen (r:0 w:1)
+	/// Proof: Assets AllowAdminBurnWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_allow_admin_burn_when_frozen() -> Weight {
+		// Placeholder figures, not measured: run the `set_allow_admin_burn_when_frozen` benchmark to replace them.
+		Weight::from_parts(14_388_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn destroy_metadata() -> Weight {
+		// Placeholder figures, not measured: run the `destroy_metadata` benchmark to replace them.
+		Weight::from_parts(31_930_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn poke_account_deposit() -> Weight {
+		// Placeholder figures, not measured: run the `poke_account_deposit` benchmark to replace them.
+		Weight::from_parts(34_108_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Approvals (r:1 w:1)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
+	fn reject_approval() -> Weight {
+		// Placeholder figures, not measured: run the `reject_approval` benchmark to replace them.
+		Weight::from_parts(37_637_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets RefRepairs (r:1 w:1)
+	/// Proof: Assets RefRepairs (max_values: None, max_size: Some(1051), added: 3526, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1000 w:1000)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn repair_refs(n: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `repair_refs` benchmark to replace them.
+		Weight::from_parts(16_630_000, 4516)
+			.saturating_add(Weight::from_parts(9_853_114, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:1 w:1)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:1)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `293`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 31_340_000 picoseconds.
-		Weight::from_parts(31_977_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(31_977_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:1 w:1)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:1)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `153`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_342_000 picoseconds.
-		Weight::from_parts(13_782_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(13_782_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn start_destroy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_437_000 picoseconds.
-		Weight::from_parts(14_833_000, 3675)
+		Weight::from_parts(14_833_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1001 w:1000)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1000 w:1000)
@@ -574,9 +793,9 @@ impl WeightInfo for () {
 	fn destroy_accounts(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0 + c * (208 ±0)`
-		//  Estimated: `3675 + c * (2609 ±0)`
+		//  Estimated: `3691 + c * (2609 ±0)`
 		// Minimum execution time: 18_728_000 picoseconds.
-		Weight::from_parts(18_982_000, 3675)
+		Weight::from_parts(18_982_000, 3691)
 			// Standard Error: 11_708
 			.saturating_add(Weight::from_parts(14_363_570, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
@@ -586,16 +805,16 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(c.into()))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1001 w:1000)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy_approvals(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `522 + a * (86 ±0)`
-		//  Estimated: `3675 + a * (2623 ±0)`
+		//  Estimated: `3691 + a * (2623 ±0)`
 		// Minimum execution time: 18_611_000 picoseconds.
-		Weight::from_parts(18_970_000, 3675)
+		Weight::from_parts(18_970_000, 3691)
 			// Standard Error: 13_224
 			.saturating_add(Weight::from_parts(16_397_299, 0).saturating_mul(a.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
@@ -605,163 +824,201 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 2623).saturating_mul(a.into()))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:0)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:1 w:1)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:1)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn finish_destroy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_504_000 picoseconds.
-		Weight::from_parts(14_906_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(14_906_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Assets AllowMintWhenFrozen (r:1 w:0)
+	/// Proof: Assets AllowMintWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 26_653_000 picoseconds.
-		Weight::from_parts(27_260_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(27_260_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Assets AllowAdminBurnWhenFrozen (r:1 w:0)
+	/// Proof: Assets AllowAdminBurnWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(34_474_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_609_000 picoseconds.
 		Weight::from_parts(48_476_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 41_625_000 picoseconds.
 		Weight::from_parts(43_030_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_661_000 picoseconds.
 		Weight::from_parts(48_469_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 17_727_000 picoseconds.
-		Weight::from_parts(18_384_000, 3675)
+		Weight::from_parts(18_384_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	fn thaw() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 17_657_000 picoseconds.
-		Weight::from_parts(18_282_000, 3675)
+		Weight::from_parts(18_282_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn freeze_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_743_000 picoseconds.
-		Weight::from_parts(14_193_000, 3675)
+		Weight::from_parts(14_193_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn thaw_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_653_000 picoseconds.
-		Weight::from_parts(14_263_000, 3675)
+		Weight::from_parts(14_263_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:0)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:2 w:2)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:2)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 15_328_000 picoseconds.
-		Weight::from_parts(16_042_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(16_042_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn set_team() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_097_000 picoseconds.
-		Weight::from_parts(14_641_000, 3675)
+		Weight::from_parts(14_641_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 50]`.
@@ -769,27 +1026,27 @@ impl WeightInfo for () {
 	fn set_metadata(_n: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 29_535_000 picoseconds.
-		Weight::from_parts(31_456_892, 3675)
+		Weight::from_parts(31_456_892, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	fn clear_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `515`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 30_680_000 picoseconds.
-		Weight::from_parts(31_930_000, 3675)
+		Weight::from_parts(31_930_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 50]`.
@@ -797,172 +1054,326 @@ impl WeightInfo for () {
 	fn force_set_metadata(_n: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `190`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_660_000 picoseconds.
-		Weight::from_parts(15_718_387, 3675)
+		Weight::from_parts(15_718_387, 3691)
 			// Standard Error: 622
 			.saturating_add(Weight::from_parts(2_640, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Metadata (r:1 w:1)
 	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	fn force_clear_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `515`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 30_853_000 picoseconds.
-		Weight::from_parts(31_483_000, 3675)
+		Weight::from_parts(31_483_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssetCount (r:2 w:2)
+	/// Proof: Assets OwnedAssetCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets OwnedAssets (r:0 w:2)
+	/// Proof: Assets OwnedAssets (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_asset_status() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 13_632_000 picoseconds.
-		Weight::from_parts(14_077_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(14_077_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	fn approve_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 33_780_000 picoseconds.
-		Weight::from_parts(34_533_000, 3675)
+		Weight::from_parts(34_533_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(1066), added: 3541, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets AssetStats (r:1 w:1)
+	/// Proof: Assets AssetStats (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `668`
 		//  Estimated: `6208`
 		// Minimum execution time: 67_712_000 picoseconds.
 		Weight::from_parts(69_946_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	fn cancel_approval() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 36_668_000 picoseconds.
-		Weight::from_parts(37_637_000, 3675)
+		Weight::from_parts(37_637_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Approvals (r:1 w:1)
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	fn force_cancel_approval() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 36_685_000 picoseconds.
-		Weight::from_parts(37_950_000, 3675)
+		Weight::from_parts(37_950_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Approvals (r:101 w:100)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets ApprovalCount (r:1 w:1)
+	/// Proof: Assets ApprovalCount (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets DelegateApprovals (r:0 w:100)
+	/// Proof: Assets DelegateApprovals (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets DelegateApprovalCount (r:100 w:100)
+	/// Proof: Assets DelegateApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 100]`.
+	fn force_cancel_approvals_of(a: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `force_cancel_approvals_of` benchmark to replace them.
+		Weight::from_parts(21_874_000, 3691)
+			.saturating_add(Weight::from_parts(19_402_118, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2623).saturating_mul(a.into()))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn set_min_balance() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 14_466_000 picoseconds.
-		Weight::from_parts(14_924_000, 3675)
+		Weight::from_parts(14_924_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn touch() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 34_874_000 picoseconds.
-		Weight::from_parts(36_330_000, 3675)
+		Weight::from_parts(36_330_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn touch_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 33_278_000 picoseconds.
-		Weight::from_parts(34_104_000, 3675)
+		Weight::from_parts(34_104_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn refund() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `579`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 32_898_000 picoseconds.
-		Weight::from_parts(33_489_000, 3675)
+		Weight::from_parts(33_489_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	fn refund_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `510`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 31_243_000 picoseconds.
-		Weight::from_parts(31_909_000, 3675)
+		Weight::from_parts(31_909_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
-	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	fn block() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3691`
 		// Minimum execution time: 17_692_000 picoseconds.
-		Weight::from_parts(18_253_000, 3675)
+		Weight::from_parts(18_253_000, 3691)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets MaxHolders (r:0 w:1)
+	/// Proof: Assets MaxHolders (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn set_max_holders() -> Weight {
+		// Placeholder figures, not measured: run the `set_max_holders` benchmark to replace them.
+		Weight::from_parts(14_388_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1001 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn owned_assets(n: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `owned_assets` benchmark to replace them.
+		Weight::from_parts(6_240_000, 990)
+			.saturating_add(Weight::from_parts(7_318_402, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2701).saturating_mul(n.into()))
+	}
+	/// Storage: Assets AccountMigrationCursor (r:1 w:1)
+	/// Proof: Assets AccountMigrationCursor (max_values: Some(1), max_size: Some(1027), added: 1522, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:1000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn continue_migration(n: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `continue_migration` benchmark to replace them.
+		Weight::from_parts(9_417_000, 2512)
+			.saturating_add(Weight::from_parts(5_106_339, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn refresh_asset_deposit() -> Weight {
+		// Placeholder figures, not measured: run the `refresh_asset_deposit` benchmark to replace them.
+		Weight::from_parts(39_240_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets AllowMintWhenFrozen (r:0 w:1)
+	/// Proof: Assets AllowMintWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_allow_mint_when_frozen() -> Weight {
+		// Placeholder figures, not measured: run the `set_allow_mint_when_frozen` benchmark to replace them.
+		Weight::from_parts(14_388_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets AllowAdminBurnWhenFrozen (r:0 w:1)
+	/// Proof: Assets AllowAdminBurnWhenFrozen (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_allow_admin_burn_when_frozen() -> Weight {
+		// Placeholder figures, not measured: run the `set_allow_admin_burn_when_frozen` benchmark to replace them.
+		Weight::from_parts(14_388_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn destroy_metadata() -> Weight {
+		// Placeholder figures, not measured: run the `destroy_metadata` benchmark to replace them.
+		Weight::from_parts(31_930_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn poke_account_deposit() -> Weight {
+		// Placeholder figures, not measured: run the `poke_account_deposit` benchmark to replace them.
+		Weight::from_parts(34_108_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets Approvals (r:1 w:1)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
+	fn reject_approval() -> Weight {
+		// Placeholder figures, not measured: run the `reject_approval` benchmark to replace them.
+		Weight::from_parts(37_637_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(226), added: 2701, mode: MaxEncodedLen)
+	/// Storage: Assets RefRepairs (r:1 w:1)
+	/// Proof: Assets RefRepairs (max_values: None, max_size: Some(1051), added: 3526, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1000 w:1000)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn repair_refs(n: u32, ) -> Weight {
+		// Placeholder figures, not measured: run the `repair_refs` benchmark to replace them.
+		Weight::from_parts(16_630_000, 4516)
+			.saturating_add(Weight::from_parts(9_853_114, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
 }
@@ -23,7 +23,7 @@ use crate as pallet_nft_fractionalization;
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
-	BoundedVec, PalletId,
+	Blake2_128Concat, BoundedVec, PalletId,
 };
 use frame_system::EnsureSigned;
 use pallet_nfts::PalletFeatures;
@@ -94,21 +94,40 @@ impl pallet_balances::Config for Test {
 impl pallet_assets::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = frame_support::traits::Nothing;
 	type AssetDeposit = ConstU64<1>;
 	type AssetAccountDeposit = ConstU64<10>;
 	type MetadataDepositBase = ConstU64<1>;
 	type MetadataDepositPerByte = ConstU64<1>;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Test>;
 	type ApprovalDeposit = ConstU64<1>;
+	type MaxApprovalsPerOwner = ConstU32<10>;
+	type MaxApprovalsPerDelegate = ConstU32<10>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<0>;
 	type StringLimit = ConstU32<50>;
+	type MetadataPolicy = ();
 	type Freezer = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = frame_support::traits::ConstBool<false>;
+	type AccountMigration = ();
 	type WeightInfo = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
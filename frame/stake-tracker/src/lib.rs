@@ -0,0 +1,299 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Stake Tracker Pallet
+//!
+//! A pallet keeping the sorted lists of voters and targets of the staking system (represented by
+//! [`Config::Staking`], being [`StakingInterface`]) in line with it, by listening to its
+//! [`OnStakingUpdate`] events.
+//!
+//! ## Overview
+//!
+//! [`Config::VoterList`] holds the nominators and validators which have some active stake, each
+//! scored by that stake, converted to a [`VoteWeight`] with the `CurrencyToVote` of the staking
+//! system.
+//!
+//! Whenever the stake of a nominator or validator changes, they are rescored, taken out of the
+//! list if they have no active stake left, or inserted back into it if they were out of it. The
+//! stake of bonded accounts which neither nominate nor validate is not tracked: they are not
+//! voters.
+//!
+//! Accounts which start nominating or validating (validators vote for themselves) are inserted
+//! into the list, scored by their active stake, and those which stop doing both, e.g. as they
+//! chill, are taken out of it. So are unstaked accounts. Slashed voters are rescored by the
+//! active stake the slash left them.
+//!
+//! [`Config::TargetList`] holds the validators, each scored by their approval stake: their own
+//! active stake, and that of the nominators backing them. Validators are inserted into it as they
+//! start validating, and taken out of it as they stop, and their scores follow the stake updates,
+//! nominations and slashes of their backers.
+//!
+//! The approval stake of every account nominated or validating is kept in [`ApprovalStake`],
+//! whether they are in the target list or not, so that validators join it with the stake of the
+//! nominators which backed them beforehand.
+//!
+//! The pallet has no calls. It is meant to be the `EventListeners` of a staking system which does
+//! not maintain its voter and target lists itself.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_election_provider_support::{SortedListProvider, VoteWeight};
+use frame_support::traits::{Currency, Defensive};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_staking::{
+	currency_to_vote::CurrencyToVote, EraIndex, OnStakingUpdate, Stake, StakerStatus,
+	StakingInterface,
+};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+pub use pallet::*;
+
+pub const LOG_TARGET: &str = "runtime::stake-tracker";
+
+// syntactic sugar for logging.
+#[macro_export]
+macro_rules! log {
+	($level:tt, $patter:expr $(, $values:expr)* $(,)?) => {
+		log::$level!(
+			target: crate::LOG_TARGET,
+			concat!("[{:?}] 🗳️ ", $patter), <frame_system::Pallet<T>>::block_number() $(, $values)*
+		)
+	};
+}
+
+/// The balance type of the currency at stake.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The currency at stake, whose total issuance stakes are converted into votes against.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The staking system to follow.
+		type Staking: StakingInterface<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
+
+		/// The list of nominators and validators to keep in line with the staking system, scored
+		/// by their active stake.
+		type VoterList: SortedListProvider<Self::AccountId, Score = VoteWeight>;
+
+		/// The list of validators to keep in line with the staking system, scored by their
+		/// approval stake: their own active stake, and that of the nominators backing them.
+		type TargetList: SortedListProvider<Self::AccountId, Score = BalanceOf<Self>>;
+	}
+
+	/// The approval stake of each target: their own active stake if they validate, and that of
+	/// each nominator nominating them. Zero approval stakes are not stored.
+	#[pallet::storage]
+	#[pallet::getter(fn approval_stake)]
+	pub type ApprovalStake<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// The active stake of `who`, zero if they are not staking.
+	pub fn active_stake_of(who: &T::AccountId) -> BalanceOf<T> {
+		T::Staking::stake(who).map(|stake| stake.active).unwrap_or_default()
+	}
+
+	/// The score in [`Config::VoterList`] of a voter with `active` stake.
+	pub fn to_vote(active: BalanceOf<T>) -> VoteWeight {
+		<T::Staking as StakingInterface>::CurrencyToVote::to_vote(
+			active,
+			T::Currency::total_issuance(),
+		)
+	}
+
+	/// Whether `who` nominates or validates, and so belongs in [`Config::VoterList`] as long as
+	/// they have some active stake.
+	fn is_voter(who: &T::AccountId) -> bool {
+		matches!(T::Staking::status(who), Ok(StakerStatus::Nominator(_) | StakerStatus::Validator))
+	}
+
+	/// Score `who` in [`Config::VoterList`] by their `active` stake, inserting them if they were
+	/// out of the list, or taking them out of it if they have no active stake left.
+	fn update_voter(who: &T::AccountId, active: BalanceOf<T>) {
+		if active.is_zero() {
+			if T::VoterList::contains(who) {
+				let _ =
+					T::VoterList::on_remove(who).defensive_proof("the voter is in the list; qed");
+			}
+		} else if T::VoterList::contains(who) {
+			let _ = T::VoterList::on_update(who, Self::to_vote(active))
+				.defensive_proof("the voter is in the list; qed");
+		} else {
+			let _ = T::VoterList::on_insert(who.clone(), Self::to_vote(active))
+				.defensive_proof("the voter is not in the list yet; qed");
+		}
+	}
+
+	/// Take `who`, who no longer nominates nor validates, out of [`Config::VoterList`].
+	fn remove_voter(who: &T::AccountId) {
+		if T::VoterList::contains(who) {
+			let _ = T::VoterList::on_remove(who).defensive_proof("the voter is in the list; qed");
+		} else if !Self::active_stake_of(who).is_zero() {
+			// only voters without active stake are expected to be out of the list.
+			log!(warn, "voter {:?} with active stake was not in the voter list", who);
+		}
+	}
+
+	/// Add `more` to, and take `less` off, the [`ApprovalStake`] of `target`, and rescore them
+	/// by it in [`Config::TargetList`] if they are in it.
+	fn adjust_target(target: &T::AccountId, more: BalanceOf<T>, less: BalanceOf<T>) {
+		let approval = ApprovalStake::<T>::mutate_exists(target, |approval| {
+			let new = approval.unwrap_or_default().saturating_add(more).saturating_sub(less);
+			*approval = Some(new).filter(|new| !new.is_zero());
+			new
+		});
+		if T::TargetList::contains(target) {
+			let _ = T::TargetList::on_update(target, approval)
+				.defensive_proof("the target is in the list; qed");
+		}
+	}
+
+	/// Adjust by `more` and `less` the approval stake of the targets backed by the stake of
+	/// `who`: themselves if they validate, their nominations if they nominate.
+	fn adjust_approvals(who: &T::AccountId, more: BalanceOf<T>, less: BalanceOf<T>) {
+		match T::Staking::status(who) {
+			Ok(StakerStatus::Validator) => Self::adjust_target(who, more, less),
+			Ok(StakerStatus::Nominator(targets)) =>
+				for target in targets {
+					Self::adjust_target(&target, more, less)
+				},
+			_ => (),
+		}
+	}
+
+	/// Take `who`, who no longer validates, out of [`Config::TargetList`].
+	fn remove_target(who: &T::AccountId) {
+		if T::TargetList::contains(who) {
+			let _ = T::TargetList::on_remove(who).defensive_proof("the target is in the list; qed");
+		}
+	}
+}
+
+impl<T: Config> OnStakingUpdate<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_stake_update(who: &T::AccountId, prev_stake: Option<Stake<BalanceOf<T>>>) {
+		// bonded accounts only join the list once they nominate or validate.
+		if Self::is_voter(who) {
+			let active = Self::active_stake_of(who);
+			let prev_active = prev_stake.map(|stake| stake.active).unwrap_or_default();
+			Self::adjust_approvals(
+				who,
+				active.saturating_sub(prev_active),
+				prev_active.saturating_sub(active),
+			);
+			Self::update_voter(who, active);
+		}
+	}
+
+	fn on_nominator_add(who: &T::AccountId) {
+		let active = Self::active_stake_of(who);
+		Self::adjust_approvals(who, active, Zero::zero());
+		// a nominator still in the list, e.g. as they were chilled and nominate again, is only
+		// rescored.
+		Self::update_voter(who, active);
+	}
+
+	fn on_nominator_update(who: &T::AccountId, prev_nominations: Vec<T::AccountId>) {
+		let active = Self::active_stake_of(who);
+		for target in prev_nominations {
+			Self::adjust_target(&target, Zero::zero(), active);
+		}
+		Self::adjust_approvals(who, active, Zero::zero());
+	}
+
+	fn on_validator_add(who: &T::AccountId) {
+		let active = Self::active_stake_of(who);
+		Self::adjust_target(who, active, Zero::zero());
+		// along with their own stake, that of the nominators which already backed them.
+		if !T::TargetList::contains(who) {
+			let _ = T::TargetList::on_insert(who.clone(), Self::approval_stake(who))
+				.defensive_proof("the target is not in the list yet; qed");
+		}
+		// a nominator turning validator was removed from the list by `on_nominator_remove`, and is
+		// inserted again.
+		Self::update_voter(who, active);
+	}
+
+	fn on_nominator_remove(who: &T::AccountId, nominations: Vec<T::AccountId>) {
+		let active = Self::active_stake_of(who);
+		for target in nominations {
+			Self::adjust_target(&target, Zero::zero(), active);
+		}
+		// staking drops the old role before adding the new one, so a nominator turning validator is
+		// removed here as well, and inserted again by `on_validator_add`.
+		if !Self::is_voter(who) {
+			Self::remove_voter(who);
+		}
+	}
+
+	fn on_validator_remove(who: &T::AccountId) {
+		Self::remove_target(who);
+		// they no longer back themselves, but their nominators, if any, still do.
+		Self::adjust_target(who, Zero::zero(), Self::active_stake_of(who));
+		// staking drops the old role before adding the new one, so a validator turning nominator is
+		// removed here as well, and inserted again by `on_nominator_add`.
+		if !Self::is_voter(who) {
+			Self::remove_voter(who);
+		}
+	}
+
+	fn on_unstake(who: &T::AccountId) {
+		// the account is normally chilled, and so out of both lists, by now. If not, their stake
+		// stops backing their nominations, or themselves. The nominators still nominating them
+		// keep backing them.
+		if Self::is_voter(who) {
+			Self::adjust_approvals(who, Zero::zero(), Self::active_stake_of(who));
+		}
+		if T::VoterList::contains(who) {
+			let _ = T::VoterList::on_remove(who).defensive_proof("the voter is in the list; qed");
+		}
+		Self::remove_target(who);
+	}
+
+	fn on_slash(
+		stash: &T::AccountId,
+		slashed_active: BalanceOf<T>,
+		_slashed_unlocking: &BTreeMap<EraIndex, BalanceOf<T>>,
+	) {
+		if !Self::is_voter(stash) {
+			return
+		}
+		// the active stake is still the one from before the slash.
+		let slashed = Self::active_stake_of(stash).saturating_sub(slashed_active);
+		Self::adjust_approvals(stash, Zero::zero(), slashed);
+		if !T::VoterList::contains(stash) && !slashed_active.is_zero() {
+			log!(warn, "slashed voter {:?} was not in the voter list", stash);
+		}
+		// rescore by what the slash left.
+		Self::update_voter(stash, slashed_active);
+	}
+}
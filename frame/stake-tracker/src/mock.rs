@@ -0,0 +1,464 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mock runtime and staking system for pallet-stake-tracker tests.
+
+use crate::{self as pallet_stake_tracker, *};
+use frame_election_provider_support::ScoreProvider;
+use frame_support::{assert_ok, parameter_types, traits::ConstU64};
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+use sp_staking::EraIndex;
+use std::collections::{BTreeMap, BTreeSet};
+
+pub type AccountId = u64;
+pub type Balance = u64;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		VoterBagsList: pallet_bags_list::<Instance1>,
+		TargetBagsList: pallet_bags_list::<Instance2>,
+		StakeTracker: pallet_stake_tracker,
+	}
+);
+
+impl frame_system::Config for Test {
+	type SS58Prefix = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = sp_core::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ();
+	type DbWeight = ();
+	type BlockLength = ();
+	type BlockWeights = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+const VOTER_THRESHOLDS: [VoteWeight; 9] = [10, 20, 30, 40, 50, 60, 1_000, 2_000, 10_000];
+const TARGET_THRESHOLDS: [Balance; 9] = [100, 200, 300, 400, 500, 600, 1_000, 2_000, 10_000];
+
+parameter_types! {
+	pub static VoterBagThresholds: &'static [VoteWeight] = &VOTER_THRESHOLDS;
+	pub static TargetBagThresholds: &'static [Balance] = &TARGET_THRESHOLDS;
+}
+
+type VoterBagsListInstance = pallet_bags_list::Instance1;
+impl pallet_bags_list::Config<VoterBagsListInstance> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type ScoreProvider = StakingMock;
+	type BagThresholds = VoterBagThresholds;
+	type Score = VoteWeight;
+}
+
+type TargetBagsListInstance = pallet_bags_list::Instance2;
+impl pallet_bags_list::Config<TargetBagsListInstance> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type ScoreProvider = ApprovalStakeMock;
+	type BagThresholds = TargetBagThresholds;
+	type Score = Balance;
+}
+
+impl pallet_stake_tracker::Config for Test {
+	type Currency = Balances;
+	type Staking = StakingMock;
+	type VoterList = VoterBagsList;
+	type TargetList = TargetBagsList;
+}
+
+parameter_types! {
+	pub static Ledgers: BTreeMap<AccountId, Stake<Balance>> = Default::default();
+	pub static Nominators: BTreeMap<AccountId, Vec<AccountId>> = Default::default();
+	pub static Validators: BTreeSet<AccountId> = Default::default();
+}
+
+/// A staking system kept in memory, which reports every change to `StakeTracker` after making it,
+/// like a staking pallet would.
+pub struct StakingMock;
+impl StakingMock {
+	/// Have `who` validate, chilling their nominations if they were nominating.
+	pub(crate) fn validate(who: &AccountId) -> DispatchResult {
+		Self::stake(who)?;
+		if Validators::get().contains(who) {
+			return Ok(())
+		}
+		// like pallet-staking, drop the old role before adding the new one.
+		if let Some(nominations) = Nominators::mutate(|n| n.remove(who)) {
+			StakeTracker::on_nominator_remove(who, nominations);
+		}
+		Validators::mutate(|v| v.insert(*who));
+		StakeTracker::on_validator_add(who);
+		Ok(())
+	}
+
+	/// Slash `amount` off the active stake of `who`.
+	pub(crate) fn slash(who: &AccountId, amount: Balance) {
+		let mut stake = Self::stake(who).expect("only stakers are slashed");
+		let slashed = amount.min(stake.active);
+		stake.active -= slashed;
+		stake.total -= slashed;
+		// like pallet-staking, report the slash before the slashed ledger is written back.
+		StakeTracker::on_slash(who, stake.active, &Default::default());
+		Ledgers::mutate(|l| l.insert(*who, stake));
+	}
+
+	/// Remove `validator` from the nominations of each of `nominators`.
+	pub(crate) fn kick(validator: &AccountId, nominators: &[AccountId]) {
+		for nominator in nominators {
+			let Some(prev) = Nominators::get().get(nominator).cloned() else { continue };
+			if prev.contains(validator) {
+				let targets = prev.iter().filter(|t| *t != validator).cloned().collect();
+				Nominators::mutate(|n| n.insert(*nominator, targets));
+				StakeTracker::on_nominator_update(nominator, prev);
+			}
+		}
+	}
+
+	/// Chill `who`, then remove every trace of them.
+	fn kill(who: &AccountId) -> DispatchResult {
+		Self::stake(who)?;
+		Self::chill(who)?;
+		Ledgers::mutate(|l| l.remove(who));
+		StakeTracker::on_unstake(who);
+		Ok(())
+	}
+
+	fn set_stake(who: &AccountId, stake: Stake<Balance>) {
+		let prev = Ledgers::mutate(|l| l.insert(*who, stake));
+		StakeTracker::on_stake_update(who, prev);
+	}
+}
+
+impl ScoreProvider<AccountId> for StakingMock {
+	type Score = VoteWeight;
+
+	fn score(who: &AccountId) -> Self::Score {
+		StakeTracker::to_vote(StakeTracker::active_stake_of(who))
+	}
+}
+
+/// Scores targets by their approval stake, recomputed from the state of `StakingMock`.
+pub struct ApprovalStakeMock;
+impl ScoreProvider<AccountId> for ApprovalStakeMock {
+	type Score = Balance;
+
+	fn score(who: &AccountId) -> Self::Score {
+		approval_stake_of(who)
+	}
+}
+
+impl StakingInterface for StakingMock {
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type CurrencyToVote = ();
+
+	fn minimum_nominator_bond() -> Self::Balance {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn minimum_validator_bond() -> Self::Balance {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn stash_by_ctrl(_: &Self::AccountId) -> Result<Self::AccountId, DispatchError> {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn bonding_duration() -> EraIndex {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn current_era() -> EraIndex {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn stake(who: &Self::AccountId) -> Result<Stake<Self::Balance>, DispatchError> {
+		Ledgers::get().get(who).copied().ok_or(DispatchError::Other("NotStash"))
+	}
+
+	fn bond(who: &Self::AccountId, value: Self::Balance, _: &Self::AccountId) -> DispatchResult {
+		if Ledgers::get().contains_key(who) {
+			return Err(DispatchError::Other("AlreadyBonded"))
+		}
+		Self::set_stake(who, Stake { total: value, active: value });
+		Ok(())
+	}
+
+	fn nominate(who: &Self::AccountId, validators: Vec<Self::AccountId>) -> DispatchResult {
+		Self::stake(who)?;
+		if let Some(prev) = Nominators::get().get(who).cloned() {
+			Nominators::mutate(|n| n.insert(*who, validators));
+			StakeTracker::on_nominator_update(who, prev);
+		} else {
+			// like pallet-staking, drop the old role before adding the new one.
+			if Validators::mutate(|v| v.remove(who)) {
+				StakeTracker::on_validator_remove(who);
+			}
+			Nominators::mutate(|n| n.insert(*who, validators));
+			StakeTracker::on_nominator_add(who);
+		}
+		Ok(())
+	}
+
+	fn chill(who: &Self::AccountId) -> DispatchResult {
+		if let Some(nominations) = Nominators::mutate(|n| n.remove(who)) {
+			StakeTracker::on_nominator_remove(who, nominations);
+		}
+		if Validators::mutate(|v| v.remove(who)) {
+			StakeTracker::on_validator_remove(who);
+		}
+		Ok(())
+	}
+
+	fn bond_extra(who: &Self::AccountId, extra: Self::Balance) -> DispatchResult {
+		let stake = Self::stake(who)?;
+		Self::set_stake(who, Stake { total: stake.total + extra, active: stake.active + extra });
+		Ok(())
+	}
+
+	fn unbond(who: &Self::AccountId, value: Self::Balance) -> DispatchResult {
+		let stake = Self::stake(who)?;
+		let value = value.min(stake.active);
+		Self::set_stake(who, Stake { total: stake.total, active: stake.active - value });
+		Ok(())
+	}
+
+	fn withdraw_unbonded(who: Self::AccountId, _: u32) -> Result<bool, DispatchError> {
+		let stake = Self::stake(&who)?;
+		if stake.active.is_zero() {
+			Self::kill(&who)?;
+			Ok(true)
+		} else {
+			Self::set_stake(&who, Stake { total: stake.active, active: stake.active });
+			Ok(false)
+		}
+	}
+
+	fn desired_validator_count() -> u32 {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn election_ongoing() -> bool {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn force_unstake(who: Self::AccountId) -> DispatchResult {
+		Self::kill(&who)
+	}
+
+	fn is_exposed_in_era(_: &Self::AccountId, _: &EraIndex) -> bool {
+		unimplemented!("method currently not used in testing")
+	}
+
+	fn status(who: &Self::AccountId) -> Result<StakerStatus<Self::AccountId>, DispatchError> {
+		if Validators::get().contains(who) {
+			Ok(StakerStatus::Validator)
+		} else if let Some(nominations) = Nominators::get().get(who) {
+			Ok(StakerStatus::Nominator(nominations.clone()))
+		} else {
+			Self::stake(who).map(|_| StakerStatus::Idle)
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_era_stakers(
+		_: &EraIndex,
+		_: &Self::AccountId,
+		_: Vec<(Self::AccountId, Self::Balance)>,
+	) {
+		unimplemented!("method currently not used in testing")
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_current_era(_: EraIndex) {
+		unimplemented!("method currently not used in testing")
+	}
+}
+
+/// The accounts in `VoterBagsList`, in order.
+pub(crate) fn voters() -> Vec<AccountId> {
+	VoterBagsList::iter().collect()
+}
+
+/// The accounts in the bag of `VoterBagsList` whose upper threshold is `upper`, in order.
+pub(crate) fn voter_bag(upper: VoteWeight) -> Vec<AccountId> {
+	VoterBagsList::list_bags_get(upper)
+		.map(|bag| bag.std_iter().map(|node| *node.std_id()).collect())
+		.unwrap_or_default()
+}
+
+/// Check that `VoterBagsList` holds exactly the nominators and validators with active stake, each
+/// scored by that stake.
+pub(crate) fn assert_voter_list_in_sync() {
+	let mut expected: Vec<_> = Ledgers::get()
+		.into_iter()
+		.filter(|(who, stake)| {
+			stake.active > 0 &&
+				(Validators::get().contains(who) || Nominators::get().contains_key(who))
+		})
+		.map(|(who, stake)| (who, StakeTracker::to_vote(stake.active)))
+		.collect();
+	let mut listed: Vec<_> = voters()
+		.into_iter()
+		.map(|who| (who, VoterBagsList::get_score(&who).unwrap()))
+		.collect();
+	expected.sort();
+	listed.sort();
+	assert_eq!(listed, expected);
+}
+
+/// The active stake of `target` if they validate, plus that of each nominator backing them.
+pub(crate) fn approval_stake_of(target: &AccountId) -> Balance {
+	let own =
+		if Validators::get().contains(target) { StakeTracker::active_stake_of(target) } else { 0 };
+	let backing: Balance = Nominators::get()
+		.into_iter()
+		.map(|(who, targets)| {
+			let times = targets.iter().filter(|t| *t == target).count() as Balance;
+			times * StakeTracker::active_stake_of(&who)
+		})
+		.sum();
+	own + backing
+}
+
+/// The accounts in `TargetBagsList`, in order.
+pub(crate) fn targets() -> Vec<AccountId> {
+	TargetBagsList::iter().collect()
+}
+
+/// Check that `TargetBagsList` holds exactly the validators, each scored by their approval stake.
+pub(crate) fn assert_target_list_in_sync() {
+	let mut expected: Vec<_> = Validators::get()
+		.into_iter()
+		.map(|who| (who, approval_stake_of(&who)))
+		.collect();
+	let mut listed: Vec<_> = targets()
+		.into_iter()
+		.map(|who| (who, TargetBagsList::get_score(&who).unwrap()))
+		.collect();
+	expected.sort();
+	listed.sort();
+	assert_eq!(listed, expected);
+}
+
+/// The approval stakes recomputed from the state of `StakingMock`, zero ones left out.
+pub(crate) fn approval_stakes() -> BTreeMap<AccountId, Balance> {
+	Validators::get()
+		.into_iter()
+		.chain(Nominators::get().into_values().flatten())
+		.map(|target| (target, approval_stake_of(&target)))
+		.filter(|(_, approval)| *approval > 0)
+		.collect()
+}
+
+/// Check that `ApprovalStake` holds exactly the approval stakes recomputed from scratch.
+pub(crate) fn assert_approval_stakes_in_sync() {
+	let tracked: BTreeMap<_, _> = ApprovalStake::<Test>::iter().collect();
+	assert_eq!(tracked, approval_stakes());
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	validators: Vec<(AccountId, Balance)>,
+	nominators: Vec<(AccountId, Balance, Vec<AccountId>)>,
+}
+
+impl ExtBuilder {
+	/// Bond `stake` from `who` at genesis, and have them validate.
+	pub(crate) fn validator(mut self, who: AccountId, stake: Balance) -> Self {
+		self.validators.push((who, stake));
+		self
+	}
+
+	/// Bond `stake` from `who` at genesis, and have them nominate `targets`.
+	pub(crate) fn nominator(
+		mut self,
+		who: AccountId,
+		stake: Balance,
+		targets: Vec<AccountId>,
+	) -> Self {
+		self.nominators.push((who, stake, targets));
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		sp_tracing::try_init_simple();
+		let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		let mut ext = sp_io::TestExternalities::from(storage);
+		ext.execute_with(|| {
+			// Clear thread local vars for https://github.com/paritytech/substrate/issues/10479.
+			Ledgers::take();
+			Nominators::take();
+			Validators::take();
+
+			for (who, stake) in self.validators {
+				assert_ok!(StakingMock::bond(&who, stake, &who));
+				assert_ok!(StakingMock::validate(&who));
+			}
+			for (who, stake, targets) in self.nominators {
+				assert_ok!(StakingMock::bond(&who, stake, &who));
+				assert_ok!(StakingMock::nominate(&who, targets));
+			}
+		});
+
+		ext
+	}
+
+	pub fn build_and_execute(self, test: impl FnOnce()) {
+		self.build().execute_with(|| {
+			test();
+			assert_voter_list_in_sync();
+			assert_target_list_in_sync();
+			assert_approval_stakes_in_sync();
+		})
+	}
+}
@@ -0,0 +1,518 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for pallet-stake-tracker.
+
+use super::*;
+use crate::mock::*;
+use frame_support::assert_ok;
+use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+
+#[test]
+fn stake_updates_should_rebag_voters() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 15, vec![10])
+		.build_and_execute(|| {
+			assert_eq!(voter_bag(20), vec![1]);
+			assert_eq!(voter_bag(1_000), vec![10]);
+
+			// bonding extra moves the nominator up, across several thresholds.
+			assert_ok!(StakingMock::bond_extra(&1, 30));
+			assert_eq!(VoterBagsList::get_score(&1).unwrap(), 45);
+			assert!(voter_bag(20).is_empty());
+			assert_eq!(voter_bag(50), vec![1]);
+
+			// unbonding moves them down.
+			assert_ok!(StakingMock::unbond(&1, 40));
+			assert_eq!(VoterBagsList::get_score(&1).unwrap(), 5);
+			assert_eq!(voter_bag(10), vec![1]);
+
+			// withdrawing what was unbonded leaves the active stake, and so the score, as it was.
+			assert_eq!(StakingMock::withdraw_unbonded(1, 0), Ok(false));
+			assert_eq!(VoterBagsList::get_score(&1).unwrap(), 5);
+
+			// validators are rescored alike.
+			assert_ok!(StakingMock::bond_extra(&10, 1_000));
+			assert_eq!(voter_bag(2_000), vec![10]);
+			assert_eq!(voters(), vec![10, 1]);
+		});
+}
+
+#[test]
+fn voters_without_active_stake_should_be_out_of_the_list() {
+	ExtBuilder::default().nominator(1, 15, vec![]).build_and_execute(|| {
+		assert_ok!(StakingMock::unbond(&1, 15));
+		assert!(!VoterBagsList::contains(&1));
+
+		// they are back once they have some active stake again.
+		assert_ok!(StakingMock::bond_extra(&1, 25));
+		assert_eq!(voter_bag(30), vec![1]);
+	});
+}
+
+#[test]
+fn stake_updates_of_idle_stakers_should_be_ignored() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(StakingMock::bond(&1, 15, &1));
+		assert_ok!(StakingMock::bond_extra(&1, 10));
+		assert_ok!(StakingMock::unbond(&1, 5));
+		assert!(voters().is_empty());
+	});
+}
+
+#[test]
+fn new_nominators_should_be_inserted() {
+	ExtBuilder::default().validator(10, 100).build_and_execute(|| {
+		assert_ok!(StakingMock::bond(&1, 15, &1));
+		assert!(!VoterBagsList::contains(&1));
+
+		assert_ok!(StakingMock::nominate(&1, vec![10]));
+		assert_eq!(VoterBagsList::get_score(&1).unwrap(), 15);
+		assert_eq!(voter_bag(20), vec![1]);
+
+		// they land in the bag of their stake, whichever it is.
+		assert_ok!(StakingMock::bond(&2, 35, &2));
+		assert_ok!(StakingMock::nominate(&2, vec![10]));
+		assert_eq!(voter_bag(40), vec![2]);
+		assert_eq!(voters(), vec![10, 2, 1]);
+	});
+}
+
+#[test]
+fn chilled_nominators_should_be_back_once_they_nominate() {
+	ExtBuilder::default().nominator(1, 15, vec![]).build_and_execute(|| {
+		assert_ok!(StakingMock::chill(&1));
+		// stake changes while chilled are not tracked.
+		assert_ok!(StakingMock::bond_extra(&1, 20));
+
+		assert_ok!(StakingMock::nominate(&1, vec![]));
+		assert_eq!(VoterBagsList::get_score(&1).unwrap(), 35);
+		assert_eq!(voters(), vec![1]);
+		assert_eq!(voter_bag(40), vec![1]);
+	});
+}
+
+#[test]
+fn nominators_without_active_stake_should_not_be_inserted() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(StakingMock::bond(&1, 15, &1));
+		assert_ok!(StakingMock::unbond(&1, 15));
+
+		assert_ok!(StakingMock::nominate(&1, vec![]));
+		assert!(voters().is_empty());
+	});
+}
+
+#[test]
+fn new_validators_should_be_inserted() {
+	ExtBuilder::default().nominator(1, 15, vec![]).build_and_execute(|| {
+		assert_ok!(StakingMock::bond(&10, 55, &10));
+		assert!(!VoterBagsList::contains(&10));
+
+		assert_ok!(StakingMock::validate(&10));
+		assert_eq!(
+			VoterBagsList::get_score(&10).unwrap(),
+			StakeTracker::to_vote(StakingMock::stake(&10).unwrap().active),
+		);
+		assert_eq!(voter_bag(60), vec![10]);
+		assert_eq!(voters(), vec![10, 1]);
+	});
+}
+
+#[test]
+fn nominators_turning_validators_should_be_listed_once() {
+	ExtBuilder::default().nominator(1, 15, vec![]).build_and_execute(|| {
+		assert_ok!(StakingMock::validate(&1));
+		assert_eq!(voters(), vec![1]);
+		assert_eq!(voter_bag(20), vec![1]);
+
+		// and back.
+		assert_ok!(StakingMock::nominate(&1, vec![]));
+		assert_eq!(voters(), vec![1]);
+		assert_eq!(
+			VoterBagsList::get_score(&1).unwrap(),
+			StakeTracker::to_vote(StakingMock::stake(&1).unwrap().active),
+		);
+	});
+}
+
+#[test]
+fn chilled_stakers_should_be_removed() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.validator(11, 15)
+		.nominator(1, 15, vec![10, 11])
+		.nominator(2, 35, vec![10])
+		.build_and_execute(|| {
+			assert_eq!(voters().len(), 4);
+			assert_eq!(voter_bag(20), vec![11, 1]);
+
+			assert_ok!(StakingMock::chill(&1));
+			assert_eq!(voters().len(), 3);
+			assert_eq!(voter_bag(20), vec![11]);
+
+			assert_ok!(StakingMock::chill(&11));
+			assert_eq!(voters(), vec![10, 2]);
+			assert!(voter_bag(20).is_empty());
+
+			// chilling again changes nothing.
+			assert_ok!(StakingMock::chill(&11));
+			assert_eq!(voters(), vec![10, 2]);
+		});
+}
+
+#[test]
+fn kicked_nominators_should_keep_their_place() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.validator(11, 15)
+		.nominator(1, 15, vec![10, 11])
+		.nominator(2, 35, vec![11])
+		.build_and_execute(|| {
+			StakingMock::kick(&11, &[1, 2]);
+			assert_eq!(StakingMock::nominations(&1), Some(vec![10]));
+			assert_eq!(StakingMock::nominations(&2), Some(vec![]));
+
+			// they still nominate, with the same stake.
+			assert_eq!(voters().len(), 4);
+			assert_eq!(voter_bag(20), vec![11, 1]);
+			assert_eq!(voter_bag(40), vec![2]);
+		});
+}
+
+#[test]
+fn removed_stakers_without_active_stake_should_be_fine() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 15, vec![10])
+		.build_and_execute(|| {
+			assert_ok!(StakingMock::unbond(&1, 15));
+			assert_ok!(StakingMock::unbond(&10, 100));
+			assert!(voters().is_empty());
+
+			assert_ok!(StakingMock::chill(&1));
+			assert_ok!(StakingMock::chill(&10));
+			assert!(voters().is_empty());
+		});
+}
+
+#[test]
+fn unstaked_nominators_should_leave_no_trace() {
+	ExtBuilder::default().validator(10, 100).build_and_execute(|| {
+		assert_ok!(StakingMock::bond(&1, 15, &1));
+		assert_ok!(StakingMock::nominate(&1, vec![10]));
+		assert_eq!(voters(), vec![10, 1]);
+
+		assert_ok!(StakingMock::unbond(&1, 15));
+		assert_eq!(StakingMock::withdraw_unbonded(1, 0), Ok(true));
+		assert!(StakingMock::stake(&1).is_err());
+		assert_eq!(voters(), vec![10]);
+
+		assert_ok!(StakingMock::force_unstake(10));
+		assert!(voters().is_empty());
+	});
+}
+
+#[test]
+fn nominators_unstaked_without_chilling_should_no_longer_back_their_targets() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 15, vec![10])
+		.build_and_execute(|| {
+			assert_eq!(StakeTracker::approval_stake(&10), 115);
+
+			// staking reports the unstake while 1 still nominates, then forgets about them.
+			StakeTracker::on_unstake(&1);
+			Nominators::mutate(|n| n.remove(&1));
+			Ledgers::mutate(|l| l.remove(&1));
+
+			assert_eq!(voters(), vec![10]);
+			assert_eq!(StakeTracker::approval_stake(&10), 100);
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 100);
+		});
+}
+
+#[test]
+fn unstaking_twice_or_untracked_accounts_should_be_a_noop() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 15, vec![10])
+		.build_and_execute(|| {
+			assert_ok!(StakingMock::force_unstake(1));
+			assert_eq!(voters(), vec![10]);
+
+			StakeTracker::on_unstake(&1);
+			// never bonded.
+			StakeTracker::on_unstake(&2);
+			assert_eq!(voters(), vec![10]);
+			assert_eq!(VoterBagsList::get_score(&10).unwrap(), 100);
+		});
+}
+
+#[test]
+fn slashed_voters_should_be_rebagged() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 35, vec![10])
+		.build_and_execute(|| {
+			assert_eq!(voter_bag(40), vec![1]);
+
+			StakingMock::slash(&1, 10);
+			assert_eq!(VoterBagsList::get_score(&1).unwrap(), 25);
+			assert!(voter_bag(40).is_empty());
+			assert_eq!(voter_bag(30), vec![1]);
+
+			StakingMock::slash(&10, 50);
+			assert_eq!(VoterBagsList::get_score(&10).unwrap(), 50);
+			assert_eq!(voter_bag(50), vec![10]);
+		});
+}
+
+#[test]
+fn fully_slashed_voters_should_be_removed() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 35, vec![10])
+		.build_and_execute(|| {
+			StakingMock::slash(&1, 35);
+			assert_eq!(voters(), vec![10]);
+
+			// slashing what is left of them changes nothing.
+			StakingMock::slash(&1, 10);
+			assert_eq!(voters(), vec![10]);
+		});
+}
+
+#[test]
+fn slashes_of_idle_stakers_should_be_ignored() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(StakingMock::bond(&1, 15, &1));
+		StakingMock::slash(&1, 5);
+		assert!(voters().is_empty());
+	});
+}
+
+#[test]
+fn targets_should_be_ordered_by_approval_stake() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.validator(11, 150)
+		.validator(12, 250)
+		.build_and_execute(|| {
+			assert_eq!(targets(), vec![12, 11, 10]);
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 100);
+
+			// backing a target adds to their approval stake.
+			assert_ok!(StakingMock::bond(&1, 250, &1));
+			assert_ok!(StakingMock::nominate(&1, vec![10]));
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 350);
+			assert_eq!(targets(), vec![10, 12, 11]);
+
+			assert_ok!(StakingMock::bond(&2, 200, &2));
+			assert_ok!(StakingMock::nominate(&2, vec![11, 12]));
+			assert_eq!(targets(), vec![12, 10, 11]);
+
+			// moving nominations moves it along.
+			assert_ok!(StakingMock::nominate(&2, vec![10]));
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 550);
+			assert_eq!(TargetBagsList::get_score(&11).unwrap(), 150);
+			assert_eq!(targets(), vec![10, 12, 11]);
+
+			// so do the stake updates of the backers, and their leaving.
+			assert_ok!(StakingMock::unbond(&1, 200));
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 350);
+			assert_ok!(StakingMock::chill(&2));
+			assert_eq!(targets(), vec![12, 11, 10]);
+		});
+}
+
+#[test]
+fn targets_should_follow_validators() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 15, vec![10])
+		.build_and_execute(|| {
+			assert_eq!(targets(), vec![10]);
+
+			// the self-stake of validators counts as approval stake.
+			assert_ok!(StakingMock::bond_extra(&10, 100));
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 215);
+
+			// nominators turning validators become targets, and no longer back their nominations.
+			assert_ok!(StakingMock::validate(&1));
+			assert_eq!(targets(), vec![10, 1]);
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 200);
+			assert_eq!(TargetBagsList::get_score(&1).unwrap(), 15);
+
+			assert_ok!(StakingMock::chill(&1));
+			assert_eq!(targets(), vec![10]);
+			assert_ok!(StakingMock::force_unstake(10));
+			assert!(targets().is_empty());
+		});
+}
+
+#[test]
+fn slashes_should_lower_approval_stakes() {
+	ExtBuilder::default()
+		.validator(10, 100)
+		.nominator(1, 35, vec![10])
+		.build_and_execute(|| {
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 135);
+
+			StakingMock::slash(&1, 10);
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 125);
+
+			StakingMock::slash(&10, 100);
+			assert_eq!(TargetBagsList::get_score(&10).unwrap(), 25);
+			assert_eq!(targets(), vec![10]);
+		});
+}
+
+#[test]
+fn approval_stake_should_count_backers_from_before_validating() {
+	ExtBuilder::default().nominator(1, 15, vec![20]).build_and_execute(|| {
+		// nominating an account which does not validate yet backs them all the same.
+		assert_eq!(StakeTracker::approval_stake(20), 15);
+		assert!(targets().is_empty());
+
+		assert_ok!(StakingMock::bond(&20, 50, &20));
+		assert_ok!(StakingMock::validate(&20));
+		assert_eq!(StakeTracker::approval_stake(20), 65);
+		assert_eq!(TargetBagsList::get_score(&20).unwrap(), 65);
+
+		// their nominators keep backing them once they stop validating.
+		assert_ok!(StakingMock::chill(&20));
+		assert_eq!(StakeTracker::approval_stake(20), 15);
+		assert!(targets().is_empty());
+
+		assert_ok!(StakingMock::chill(&1));
+		assert_eq!(StakeTracker::approval_stake(20), 0);
+		assert_eq!(ApprovalStake::<Test>::iter().count(), 0);
+	});
+}
+
+/// The steps are taken by the accounts `1..=ACCOUNTS`.
+const ACCOUNTS: AccountId = 6;
+
+#[derive(Clone, Debug)]
+enum Step {
+	Bond { who: AccountId, value: Balance },
+	BondExtra { who: AccountId, extra: Balance },
+	Unbond { who: AccountId, value: Balance },
+	WithdrawUnbonded { who: AccountId },
+	Nominate { who: AccountId, targets: Vec<AccountId> },
+	Validate { who: AccountId },
+	Chill { who: AccountId },
+	Kick { validator: AccountId, nominator: AccountId },
+	Slash { who: AccountId, amount: Balance },
+	ForceUnstake { who: AccountId },
+}
+
+fn arbitrary_account(g: &mut Gen) -> AccountId {
+	1 + AccountId::arbitrary(g) % ACCOUNTS
+}
+
+fn arbitrary_amount(g: &mut Gen) -> Balance {
+	Balance::arbitrary(g) % 100
+}
+
+impl Arbitrary for Step {
+	fn arbitrary(g: &mut Gen) -> Self {
+		match u8::arbitrary(g) % 10 {
+			0 => Step::Bond { who: arbitrary_account(g), value: arbitrary_amount(g) },
+			1 => Step::BondExtra { who: arbitrary_account(g), extra: arbitrary_amount(g) },
+			2 => Step::Unbond { who: arbitrary_account(g), value: arbitrary_amount(g) },
+			3 => Step::WithdrawUnbonded { who: arbitrary_account(g) },
+			4 => Step::Nominate {
+				who: arbitrary_account(g),
+				targets: (0..u8::arbitrary(g) % 4).map(|_| arbitrary_account(g)).collect(),
+			},
+			5 => Step::Validate { who: arbitrary_account(g) },
+			6 => Step::Chill { who: arbitrary_account(g) },
+			7 => Step::Kick { validator: arbitrary_account(g), nominator: arbitrary_account(g) },
+			8 => Step::Slash { who: arbitrary_account(g), amount: arbitrary_amount(g) },
+			_ => Step::ForceUnstake { who: arbitrary_account(g) },
+		}
+	}
+}
+
+impl Step {
+	/// Apply the step to the staking system. It may well fail, in which case it must not have
+	/// reported anything.
+	fn apply(self) {
+		match self {
+			Step::Bond { who, value } => {
+				let _ = StakingMock::bond(&who, value, &who);
+			},
+			Step::BondExtra { who, extra } => {
+				let _ = StakingMock::bond_extra(&who, extra);
+			},
+			Step::Unbond { who, value } => {
+				let _ = StakingMock::unbond(&who, value);
+			},
+			Step::WithdrawUnbonded { who } => {
+				let _ = StakingMock::withdraw_unbonded(who, 0);
+			},
+			Step::Nominate { who, targets } => {
+				let _ = StakingMock::nominate(&who, targets);
+			},
+			Step::Validate { who } => {
+				let _ = StakingMock::validate(&who);
+			},
+			Step::Chill { who } => {
+				let _ = StakingMock::chill(&who);
+			},
+			Step::Kick { validator, nominator } => StakingMock::kick(&validator, &[nominator]),
+			Step::Slash { who, amount } =>
+				if StakingMock::stake(&who).is_ok() {
+					StakingMock::slash(&who, amount)
+				},
+			Step::ForceUnstake { who } => {
+				let _ = StakingMock::force_unstake(who);
+			},
+		}
+	}
+}
+
+fn approval_stakes_match(steps: Vec<Step>) -> TestResult {
+	ExtBuilder::default().build().execute_with(|| {
+		for (i, step) in steps.into_iter().enumerate() {
+			let description = format!("step {} ({:?})", i, step);
+			step.apply();
+			let tracked: BTreeMap<_, _> = ApprovalStake::<Test>::iter().collect();
+			let recomputed = approval_stakes();
+			if tracked != recomputed {
+				return TestResult::error(format!(
+					"after {} the approval stakes are {:?}, not {:?}",
+					description, tracked, recomputed
+				))
+			}
+		}
+		// the lists are scored from the same events.
+		assert_voter_list_in_sync();
+		assert_target_list_in_sync();
+		TestResult::passed()
+	})
+}
+
+#[test]
+fn random_steps_should_keep_approval_stakes_in_sync() {
+	QuickCheck::new()
+		.tests(200)
+		.quickcheck(approval_stakes_match as fn(Vec<Step>) -> TestResult)
+}
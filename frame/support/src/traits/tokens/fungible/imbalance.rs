@@ -21,7 +21,7 @@
 use super::{super::Imbalance as ImbalanceT, Balanced, *};
 use crate::traits::{
 	misc::{SameOrOther, TryDrop},
-	tokens::Balance,
+	tokens::{fungibles, AssetId, Balance},
 };
 use sp_runtime::{traits::Zero, RuntimeDebug};
 use sp_std::marker::PhantomData;
@@ -141,6 +141,44 @@ impl<B: Balance, OnDrop: HandleImbalanceDrop<B>, OppositeOnDrop: HandleImbalance
 	}
 }
 
+/// Converts a `fungibles` imbalance into a `fungible` imbalance of the same amount.
+///
+/// The original imbalance is forgotten, so its drop handler does not run and the supply is only
+/// adjusted once, when the returned imbalance is dropped.
+pub(crate) fn from_fungibles<
+	A: AssetId,
+	B: Balance,
+	OnDropIn: fungibles::HandleImbalanceDrop<A, B>,
+	OppositeIn: fungibles::HandleImbalanceDrop<A, B>,
+	OnDropOut: HandleImbalanceDrop<B>,
+	OppositeOut: HandleImbalanceDrop<B>,
+>(
+	imbalance: fungibles::Imbalance<A, B, OnDropIn, OppositeIn>,
+) -> Imbalance<B, OnDropOut, OppositeOut> {
+	let new = Imbalance::new(imbalance.peek());
+	sp_std::mem::forget(imbalance);
+	new
+}
+
+/// Converts a `fungible` imbalance into a `fungibles` imbalance of the same amount for `asset`.
+///
+/// The original imbalance is forgotten, so its drop handler does not run.
+pub(crate) fn to_fungibles<
+	A: AssetId,
+	B: Balance,
+	OnDropIn: HandleImbalanceDrop<B>,
+	OppositeIn: HandleImbalanceDrop<B>,
+	OnDropOut: fungibles::HandleImbalanceDrop<A, B>,
+	OppositeOut: fungibles::HandleImbalanceDrop<A, B>,
+>(
+	imbalance: Imbalance<B, OnDropIn, OppositeIn>,
+	asset: A,
+) -> fungibles::Imbalance<A, B, OnDropOut, OppositeOut> {
+	let new = fungibles::Imbalance::new(asset, imbalance.peek());
+	sp_std::mem::forget(imbalance);
+	new
+}
+
 /// Imbalance implying that the total_issuance value is less than the sum of all account balances.
 pub type Debt<AccountId, B> = Imbalance<
 	<B as Inspect<AccountId>>::Balance,
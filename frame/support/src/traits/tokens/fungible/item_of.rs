@@ -20,10 +20,10 @@
 use sp_core::Get;
 use sp_runtime::{DispatchError, DispatchResult};
 
-use super::*;
+use super::{imbalance, *};
 use crate::traits::tokens::{
-	fungibles, DepositConsequence, Fortitude, Imbalance as ImbalanceT, Precision, Preservation,
-	Provenance, Restriction, WithdrawConsequence,
+	fungibles, DepositConsequence, Fortitude, Precision, Preservation, Provenance, Restriction,
+	WithdrawConsequence,
 };
 
 /// Convert a `fungibles` trait implementation into a `fungible` trait implementation by identifying
@@ -381,35 +381,35 @@ impl<
 		precision: Precision,
 	) -> Result<Debt<AccountId, Self>, DispatchError> {
 		<F as fungibles::Balanced<AccountId>>::deposit(A::get(), who, value, precision)
-			.map(|debt| Imbalance::new(debt.peek()))
+			.map(imbalance::from_fungibles)
 	}
 	fn issue(amount: Self::Balance) -> Credit<AccountId, Self> {
-		Imbalance::new(<F as fungibles::Balanced<AccountId>>::issue(A::get(), amount).peek())
+		imbalance::from_fungibles(<F as fungibles::Balanced<AccountId>>::issue(A::get(), amount))
 	}
 	fn pair(amount: Self::Balance) -> (Debt<AccountId, Self>, Credit<AccountId, Self>) {
 		let (a, b) = <F as fungibles::Balanced<AccountId>>::pair(A::get(), amount);
-		(Imbalance::new(a.peek()), Imbalance::new(b.peek()))
+		(imbalance::from_fungibles(a), imbalance::from_fungibles(b))
 	}
 	fn rescind(amount: Self::Balance) -> Debt<AccountId, Self> {
-		Imbalance::new(<F as fungibles::Balanced<AccountId>>::rescind(A::get(), amount).peek())
+		imbalance::from_fungibles(<F as fungibles::Balanced<AccountId>>::rescind(A::get(), amount))
 	}
 	fn resolve(
 		who: &AccountId,
 		credit: Credit<AccountId, Self>,
 	) -> Result<(), Credit<AccountId, Self>> {
-		let credit = fungibles::Imbalance::new(A::get(), credit.peek());
+		let credit = imbalance::to_fungibles(credit, A::get());
 		<F as fungibles::Balanced<AccountId>>::resolve(who, credit)
-			.map_err(|credit| Imbalance::new(credit.peek()))
+			.map_err(imbalance::from_fungibles)
 	}
 	fn settle(
 		who: &AccountId,
 		debt: Debt<AccountId, Self>,
 		preservation: Preservation,
 	) -> Result<Credit<AccountId, Self>, Debt<AccountId, Self>> {
-		let debt = fungibles::Imbalance::new(A::get(), debt.peek());
+		let debt = imbalance::to_fungibles(debt, A::get());
 		<F as fungibles::Balanced<AccountId>>::settle(who, debt, preservation)
-			.map(|credit| Imbalance::new(credit.peek()))
-			.map_err(|debt| Imbalance::new(debt.peek()))
+			.map(imbalance::from_fungibles)
+			.map_err(imbalance::from_fungibles)
 	}
 	fn withdraw(
 		who: &AccountId,
@@ -426,7 +426,7 @@ impl<
 			preservation,
 			force,
 		)
-		.map(|credit| Imbalance::new(credit.peek()))
+		.map(imbalance::from_fungibles)
 	}
 }
 
@@ -443,7 +443,7 @@ impl<
 	) -> (Credit<AccountId, Self>, Self::Balance) {
 		let (credit, amount) =
 			<F as fungibles::BalancedHold<AccountId>>::slash(A::get(), reason, who, amount);
-		(Imbalance::new(credit.peek()), amount)
+		(imbalance::from_fungibles(credit), amount)
 	}
 }
 
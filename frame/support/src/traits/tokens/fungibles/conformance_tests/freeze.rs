@@ -0,0 +1,269 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::traits::{
+	fungibles::{Inspect, Mutate, MutateFreeze},
+	tokens::{Fortitude, Preservation, WithdrawConsequence},
+};
+use core::fmt::Debug;
+use sp_arithmetic::traits::AtLeast8BitUnsigned;
+use sp_runtime::traits::Zero;
+
+/// Test the `set_freeze` function for successfully freezing part of a balance.
+///
+/// This test checks that a freeze is recorded under its identifier, leaves the balance itself
+/// alone, and that the frozen funds can no longer be withdrawn.
+///
+/// # Type Parameters
+///
+/// ```text
+/// - `T`: Implements `MutateFreeze<AccountId>` and `Mutate<AccountId>`.
+/// - `AccountId`: Account identifier implementing `AtLeast8BitUnsigned`.
+/// ```
+pub fn set_freeze_success<T, AccountId>(asset: T::AssetId, id: &T::Id)
+where
+	T: MutateFreeze<AccountId> + Mutate<AccountId>,
+	<T as Inspect<AccountId>>::Balance: AtLeast8BitUnsigned + Debug,
+	AccountId: AtLeast8BitUnsigned,
+{
+	let account = AccountId::from(10);
+	let initial_balance = T::minimum_balance(asset.clone()) + 10.into();
+	T::mint_into(asset.clone(), &account, initial_balance).unwrap();
+	let initial_total_issuance = T::total_issuance(asset.clone());
+
+	// Test: Freeze part of the balance
+	let frozen: T::Balance = 5.into();
+	assert!(T::can_freeze(asset.clone(), id, &account));
+	T::set_freeze(asset.clone(), id, &account, frozen).unwrap();
+
+	// Verify: The freeze is recorded, and neither the balance nor the issuance change
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), frozen);
+	assert_eq!(T::balance(asset.clone(), &account), initial_balance);
+	assert_eq!(T::total_balance(asset.clone(), &account), initial_balance);
+	assert_eq!(T::total_issuance(asset.clone()), initial_total_issuance);
+
+	// Verify: The frozen funds are not reducible, whatever the preservation
+	let reducible =
+		T::reducible_balance(asset.clone(), &account, Preservation::Expendable, Fortitude::Polite);
+	assert!(reducible <= initial_balance - frozen);
+
+	// Verify: Withdrawing into the frozen funds does not succeed
+	let ret = T::can_withdraw(asset.clone(), &account, initial_balance - frozen + 1.into());
+	assert_ne!(ret, WithdrawConsequence::Success);
+}
+
+/// Test that a freeze limits the amount which may be transferred out of an account.
+///
+/// This test checks that a transfer reaching into the frozen funds fails without changing any
+/// balance, and that the reducible balance can still be transferred.
+///
+/// # Type Parameters
+///
+/// ```text
+/// - `T`: Implements `MutateFreeze<AccountId>` and `Mutate<AccountId>`.
+/// - `AccountId`: Account identifier implementing `AtLeast8BitUnsigned`.
+/// ```
+pub fn set_freeze_limits_transfer<T, AccountId>(asset: T::AssetId, id: &T::Id)
+where
+	T: MutateFreeze<AccountId> + Mutate<AccountId>,
+	<T as Inspect<AccountId>>::Balance: AtLeast8BitUnsigned + Debug,
+	AccountId: AtLeast8BitUnsigned,
+{
+	let account_0 = AccountId::from(10);
+	let account_1 = AccountId::from(20);
+	let initial_balance = T::minimum_balance(asset.clone()) + 10.into();
+	T::set_balance(asset.clone(), &account_0, initial_balance);
+	T::set_balance(asset.clone(), &account_1, initial_balance);
+	let frozen: T::Balance = 5.into();
+	T::set_freeze(asset.clone(), id, &account_0, frozen).unwrap();
+
+	// Verify: Transferring into the frozen funds should Err, even if the account may die
+	let transfer_amount = initial_balance - frozen + 1.into();
+	T::transfer(asset.clone(), &account_0, &account_1, transfer_amount, Preservation::Expendable)
+		.unwrap_err();
+
+	// Verify: Noop
+	assert_eq!(T::balance(asset.clone(), &account_0), initial_balance);
+	assert_eq!(T::balance(asset.clone(), &account_1), initial_balance);
+
+	// Test: Transfer the reducible balance
+	let reducible = T::reducible_balance(
+		asset.clone(),
+		&account_0,
+		Preservation::Expendable,
+		Fortitude::Polite,
+	);
+	T::transfer(asset.clone(), &account_0, &account_1, reducible, Preservation::Expendable)
+		.unwrap();
+
+	// Verify: The frozen funds stay behind
+	assert_eq!(T::balance(asset.clone(), &account_0), initial_balance - reducible);
+	assert!(T::balance(asset.clone(), &account_0) >= frozen);
+	assert_eq!(T::balance(asset.clone(), &account_1), initial_balance + reducible);
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account_0), frozen);
+}
+
+/// Test that `set_freeze` replaces a freeze under the same identifier.
+///
+/// This test checks that setting a freeze may both raise and lower it, and that setting it to zero
+/// removes it.
+///
+/// # Type Parameters
+///
+/// ```text
+/// - `T`: Implements `MutateFreeze<AccountId>` and `Mutate<AccountId>`.
+/// - `AccountId`: Account identifier implementing `AtLeast8BitUnsigned`.
+/// ```
+pub fn set_freeze_overwrite<T, AccountId>(asset: T::AssetId, id: &T::Id)
+where
+	T: MutateFreeze<AccountId> + Mutate<AccountId>,
+	<T as Inspect<AccountId>>::Balance: AtLeast8BitUnsigned + Debug,
+	AccountId: AtLeast8BitUnsigned,
+{
+	let account = AccountId::from(10);
+	let initial_balance = T::minimum_balance(asset.clone()) + 10.into();
+	T::mint_into(asset.clone(), &account, initial_balance).unwrap();
+
+	// Test: Raise the freeze
+	T::set_freeze(asset.clone(), id, &account, 3.into()).unwrap();
+	T::set_freeze(asset.clone(), id, &account, 8.into()).unwrap();
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 8.into());
+
+	// Test: Lower the freeze
+	T::set_freeze(asset.clone(), id, &account, 5.into()).unwrap();
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 5.into());
+
+	// Test: Remove the freeze
+	T::set_freeze(asset.clone(), id, &account, T::Balance::zero()).unwrap();
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), T::Balance::zero());
+	let reducible =
+		T::reducible_balance(asset.clone(), &account, Preservation::Expendable, Fortitude::Polite);
+	assert_eq!(reducible, initial_balance);
+}
+
+/// Test that `extend_freeze` never lowers a freeze.
+///
+/// # Type Parameters
+///
+/// ```text
+/// - `T`: Implements `MutateFreeze<AccountId>` and `Mutate<AccountId>`.
+/// - `AccountId`: Account identifier implementing `AtLeast8BitUnsigned`.
+/// ```
+pub fn extend_freeze_success<T, AccountId>(asset: T::AssetId, id: &T::Id)
+where
+	T: MutateFreeze<AccountId> + Mutate<AccountId>,
+	<T as Inspect<AccountId>>::Balance: AtLeast8BitUnsigned + Debug,
+	AccountId: AtLeast8BitUnsigned,
+{
+	let account = AccountId::from(10);
+	let initial_balance = T::minimum_balance(asset.clone()) + 10.into();
+	T::mint_into(asset.clone(), &account, initial_balance).unwrap();
+
+	// Test: Extend a freeze which does not exist yet
+	T::extend_freeze(asset.clone(), id, &account, 5.into()).unwrap();
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 5.into());
+
+	// Verify: Extending to a lower amount is a noop
+	T::extend_freeze(asset.clone(), id, &account, 3.into()).unwrap();
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 5.into());
+
+	// Verify: Extending to a higher amount raises the freeze
+	T::extend_freeze(asset.clone(), id, &account, 8.into()).unwrap();
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 8.into());
+}
+
+/// Test the `thaw` function for removing a freeze.
+///
+/// This test checks that once thawed, the whole balance is reducible again.
+///
+/// # Type Parameters
+///
+/// ```text
+/// - `T`: Implements `MutateFreeze<AccountId>` and `Mutate<AccountId>`.
+/// - `AccountId`: Account identifier implementing `AtLeast8BitUnsigned`.
+/// ```
+pub fn thaw_success<T, AccountId>(asset: T::AssetId, id: &T::Id)
+where
+	T: MutateFreeze<AccountId> + Mutate<AccountId>,
+	<T as Inspect<AccountId>>::Balance: AtLeast8BitUnsigned + Debug,
+	AccountId: AtLeast8BitUnsigned,
+{
+	let account = AccountId::from(10);
+	let initial_balance = T::minimum_balance(asset.clone()) + 10.into();
+	T::mint_into(asset.clone(), &account, initial_balance).unwrap();
+	T::set_freeze(asset.clone(), id, &account, 5.into()).unwrap();
+
+	// Test: Thaw the freeze
+	T::thaw(asset.clone(), id, &account).unwrap();
+
+	// Verify: Nothing is frozen and the whole balance is reducible
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), T::Balance::zero());
+	let reducible =
+		T::reducible_balance(asset.clone(), &account, Preservation::Expendable, Fortitude::Polite);
+	assert_eq!(reducible, initial_balance);
+	let reducible =
+		T::reducible_balance(asset.clone(), &account, Preservation::Preserve, Fortitude::Polite);
+	assert_eq!(reducible, initial_balance - T::minimum_balance(asset.clone()));
+}
+
+/// Test that freezes under different identifiers overlap rather than add up.
+///
+/// This test checks that each freeze is recorded on its own, that the largest one limits what may
+/// be withdrawn, and that thawing it leaves the other in place.
+///
+/// # Type Parameters
+///
+/// ```text
+/// - `T`: Implements `MutateFreeze<AccountId>` and `Mutate<AccountId>`.
+/// - `AccountId`: Account identifier implementing `AtLeast8BitUnsigned`.
+/// ```
+pub fn set_freeze_overlapping<T, AccountId>(asset: T::AssetId, id: &T::Id, other_id: &T::Id)
+where
+	T: MutateFreeze<AccountId> + Mutate<AccountId>,
+	<T as Inspect<AccountId>>::Balance: AtLeast8BitUnsigned + Debug,
+	AccountId: AtLeast8BitUnsigned,
+{
+	let account = AccountId::from(10);
+	let initial_balance = T::minimum_balance(asset.clone()) + 10.into();
+	T::mint_into(asset.clone(), &account, initial_balance).unwrap();
+
+	// Test: Freeze under both identifiers
+	T::set_freeze(asset.clone(), id, &account, 5.into()).unwrap();
+	T::set_freeze(asset.clone(), other_id, &account, 8.into()).unwrap();
+
+	// Verify: Each freeze is recorded on its own
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 5.into());
+	assert_eq!(T::balance_frozen(asset.clone(), other_id, &account), 8.into());
+
+	// Verify: The freezes overlap, so the account is not frozen beyond the largest one
+	let reducible =
+		T::reducible_balance(asset.clone(), &account, Preservation::Expendable, Fortitude::Polite);
+	assert!(reducible <= initial_balance - 8.into());
+	let ret = T::can_withdraw(asset.clone(), &account, initial_balance - 7.into());
+	assert_ne!(ret, WithdrawConsequence::Success);
+
+	// Test: Thaw the largest freeze
+	T::thaw(asset.clone(), other_id, &account).unwrap();
+
+	// Verify: The other freeze is left alone, and less of the balance is frozen
+	assert_eq!(T::balance_frozen(asset.clone(), id, &account), 5.into());
+	assert_eq!(T::balance_frozen(asset.clone(), other_id, &account), T::Balance::zero());
+	let thawed =
+		T::reducible_balance(asset.clone(), &account, Preservation::Expendable, Fortitude::Polite);
+	assert!(thawed >= reducible);
+	assert!(thawed <= initial_balance - 5.into());
+}
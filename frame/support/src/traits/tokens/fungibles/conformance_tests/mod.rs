@@ -0,0 +1,7 @@
+//! Tests which any implementation of the `fungibles` traits should pass.
+//!
+//! Each test takes the asset to test with, which must exist and be held by none of the accounts
+//! the test uses.
+
+pub mod freeze;
+pub mod inspect_mutate;
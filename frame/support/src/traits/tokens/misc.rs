@@ -77,7 +77,9 @@ pub enum Precision {
 }
 
 /// One of a number of consequences of withdrawing a fungible from an account.
-#[derive(Copy, Clone, RuntimeDebug, Eq, PartialEq)]
+#[derive(
+	Copy, Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
 pub enum WithdrawConsequence<Balance> {
 	/// Withdraw could not happen since the amount to be withdrawn is less than the total funds in
 	/// the account.
@@ -123,7 +125,9 @@ impl<Balance: Zero> WithdrawConsequence<Balance> {
 }
 
 /// One of a number of consequences of withdrawing a fungible from an account.
-#[derive(Copy, Clone, RuntimeDebug, Eq, PartialEq)]
+#[derive(
+	Copy, Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
 pub enum DepositConsequence {
 	/// Deposit couldn't happen due to the amount being too low. This is usually because the
 	/// account doesn't yet exist and the deposit wouldn't bring it to at least the minimum needed
@@ -180,17 +180,36 @@ impl pallet_assets::Config for Runtime {
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = frame_support::traits::Nothing;
 	type AssetDeposit = ConstU64<2>;
 	type AssetAccountDeposit = ConstU64<2>;
 	type MetadataDepositBase = ConstU64<0>;
 	type MetadataDepositPerByte = ConstU64<0>;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Runtime>;
 	type ApprovalDeposit = ConstU64<0>;
+	type MaxApprovalsPerOwner = ConstU32<10>;
+	type MaxApprovalsPerDelegate = ConstU32<10>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<0>;
 	type StringLimit = ConstU32<20>;
+	type MetadataPolicy = ();
 	type Freezer = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = frame_support::traits::ConstBool<false>;
+	type AccountMigration = ();
 	type WeightInfo = ();
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
@@ -199,22 +218,41 @@ impl pallet_assets::Config for Runtime {
 impl pallet_assets::Config<Instance2> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
-	type RemoveItemsLimit = ConstU32<1000>;
+	type RemoveAccountsLimit = ConstU32<1000>;
+	type RemoveApprovalsLimit = ConstU32<1000>;
+	type AssetIdHasher = Blake2_128Concat;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, u64>>;
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type RoleOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ReservedAssetIds = frame_support::traits::Nothing;
 	type AssetDeposit = ConstU64<0>;
 	type AssetAccountDeposit = ConstU64<0>;
 	type MetadataDepositBase = ConstU64<0>;
 	type MetadataDepositPerByte = ConstU64<0>;
+	type DepositPolicy = pallet_assets::ConfiguredDeposits<Runtime, Instance2>;
 	type ApprovalDeposit = ConstU64<0>;
+	type MaxApprovalsPerOwner = ConstU32<10>;
+	type MaxApprovalsPerDelegate = ConstU32<10>;
+	type MaxOwnedAssets = ConstU32<0>;
+	type MaxHoldingsPerAccount = ConstU32<0>;
 	type StringLimit = ConstU32<50>;
+	type MetadataPolicy = ();
 	type Freezer = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type Extra = ();
+	type ExtraDepositor = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type TransferFilter = ();
+	type OnDust = ();
+	type TrackStats = frame_support::traits::ConstBool<false>;
+	type AccountMigration = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}